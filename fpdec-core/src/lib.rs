@@ -45,7 +45,6 @@
 #![warn(clippy::print_stdout)]
 #![warn(clippy::semicolon_if_nothing_returned)]
 #![warn(clippy::str_to_string)]
-#![warn(clippy::string_to_string)]
 #![warn(clippy::undocumented_unsafe_blocks)]
 #![warn(clippy::unicode_not_nfc)]
 #![warn(clippy::unimplemented)]
@@ -62,7 +61,7 @@ pub use parser::{str_to_dec, ParseDecimalError};
 pub use powers_of_ten::{checked_mul_pow_ten, mul_pow_ten, ten_pow};
 pub use rounding::{
     i128_div_rounded, i128_mul_div_ten_pow_rounded, i128_shifted_div_rounded,
-    Round, RoundingMode,
+    u128_shifted_div_rounded, Round, RoundingMode,
 };
 
 mod parser;
@@ -431,16 +430,15 @@ pub fn i128_shifted_div_mod_floor(
     let mut q = xl as i128;
     // r < y, so r as i128 is safe.
     let mut r = r as i128;
-    if x.is_negative() {
+    if x.is_negative() == y.is_negative() {
         if y.is_negative() {
             r = r.neg();
-        } else {
-            q = q.neg() - 1;
-            r = y - r;
         }
-    } else if y.is_negative() {
+    } else if r == 0 {
+        q = q.neg();
+    } else {
         q = q.neg() - 1;
-        r -= y;
+        r = if y.is_negative() { r + y } else { y - r };
     }
     Some((q, r))
 }
@@ -468,8 +466,147 @@ pub fn i256_div_mod_floor(
     // r < y, so r as i128 is safe.
     let mut r = r as i128;
     if x1.is_negative() != x2.is_negative() {
-        q = q.neg() - 1;
-        r = y - r;
+        if r == 0 {
+            q = q.neg();
+        } else {
+            q = q.neg() - 1;
+            r = y - r;
+        }
     }
     Some((q, r))
 }
+
+/// Return `(q, r)` with `q = (x * 10^p) / y` and `r = (x * 10^p) % y`,
+/// where q is `None` if it exceeds `u128::MAX`.
+#[doc(hidden)]
+#[must_use]
+#[allow(clippy::cast_sign_loss)]
+pub fn u128_shifted_div_mod(x: u128, p: u8, y: u128) -> (Option<u128>, u128) {
+    debug_assert!(y > 0);
+    let (mut xh, mut xl) = u128_mul_u128(x, ten_pow(p) as u128);
+    let r = u256_idiv_u128(&mut xh, &mut xl, y);
+    ((xh == 0).then_some(xl), r)
+}
+
+/// Return `(q, r)` with `q = (x1 * y1 + x2 * y2) / z` and
+/// `r = (x1 * y1 + x2 * y2) % z`, where q is truncated towards zero, so
+/// that r, if non-zero, has the same sign as the dividend, and q is `None`
+/// if |q| > i128::MAX. The dividend is calculated without overflow.
+#[doc(hidden)]
+#[must_use]
+#[allow(clippy::cast_possible_wrap)]
+pub fn i256_sum_of_prods_div_mod(
+    x1: i128,
+    y1: i128,
+    x2: i128,
+    y2: i128,
+    z: i128,
+) -> (Option<i128>, i128) {
+    debug_assert!(z > 0);
+    let (h1, l1) = u128_mul_u128(x1.unsigned_abs(), y1.unsigned_abs());
+    let (h2, l2) = u128_mul_u128(x2.unsigned_abs(), y2.unsigned_abs());
+    let neg1 = x1.is_negative() != y1.is_negative();
+    let neg2 = x2.is_negative() != y2.is_negative();
+    // |xi * yi| <= 2^254, so |x1 * y1 + x2 * y2| < 2^256
+    let (is_negative, mut xh, mut xl) = if neg1 == neg2 {
+        let (xl, carry) = l1.overflowing_add(l2);
+        (neg1, h1 + h2 + u128::from(carry), xl)
+    } else if (h1, l1) >= (h2, l2) {
+        let (xl, borrow) = l1.overflowing_sub(l2);
+        (neg1, h1 - h2 - u128::from(borrow), xl)
+    } else {
+        let (xl, borrow) = l2.overflowing_sub(l1);
+        (neg2, h2 - h1 - u128::from(borrow), xl)
+    };
+    // r < z, so r as i128 is safe.
+    let r = u256_idiv_u128(&mut xh, &mut xl, z.unsigned_abs()) as i128;
+    // xl <= i128::MAX, so xl as i128 is safe.
+    let q = (xh == 0 && xl <= i128::MAX as u128).then_some(xl as i128);
+    if is_negative {
+        (q.map(Neg::neg), -r)
+    } else {
+        (q, r)
+    }
+}
+
+#[cfg(test)]
+mod div_mod_floor_tests {
+    use super::*;
+
+    #[test]
+    fn test_i128_shifted_div_mod_floor() {
+        let test_data = [
+            (7_i128, 0_u8, 3_i128, 2_i128, 1_i128),
+            (-7, 0, 3, -3, 2),
+            (7, 0, -3, -3, -2),
+            (-7, 0, -3, 2, -1),
+            (6, 0, 3, 2, 0),
+            (-6, 0, 3, -2, 0),
+            (6, 0, -3, -2, 0),
+            (-6, 0, -3, 2, 0),
+            (-13, 2, 4, -325, 0),
+            (-13, 2, 7, -186, 2),
+            (i128::MIN, 0, -3, 56713727820156410577229101238628035242, -2),
+            (1, 0, i128::MIN, -1, i128::MIN + 1),
+        ];
+        for (x, p, y, q, r) in test_data {
+            assert_eq!(i128_shifted_div_mod_floor(x, p, y), Some((q, r)));
+        }
+    }
+
+    #[test]
+    fn test_i256_div_mod_floor() {
+        let test_data = [
+            (7_i128, 1_i128, 3_i128, 2_i128, 1_i128),
+            (-7, 1, 3, -3, 2),
+            (7, -1, 3, -3, 2),
+            (-7, -1, 3, 2, 1),
+            (-6, 1, 3, -2, 0),
+            (6, -1, 3, -2, 0),
+            (-3, i128::MAX, i128::MAX, -3, 0),
+        ];
+        for (x1, x2, y, q, r) in test_data {
+            assert_eq!(i256_div_mod_floor(x1, x2, y), Some((q, r)));
+        }
+    }
+
+    #[test]
+    fn test_u128_shifted_div_mod() {
+        let test_data = [
+            (7_u128, 0_u8, 3_u128, Some(2_u128), 1_u128),
+            (7, 2, 3, Some(233), 1),
+            (u128::MAX, 0, u128::MAX, Some(1), 0),
+            (u128::MAX, 1, u128::MAX - 1, Some(10), 10),
+            (u128::MAX, 38, 3, None, 0),
+            (u128::MAX, 38, 7, None, 6),
+            (1, 18, u128::MAX, Some(0), 1000000000000000000),
+        ];
+        for (x, p, y, q, r) in test_data {
+            assert_eq!(u128_shifted_div_mod(x, p, y), (q, r));
+        }
+    }
+
+    #[test]
+    fn test_i256_sum_of_prods_div_mod() {
+        let test_data = [
+            (7_i128, 3_i128, 1_i128, 1_i128, 4_i128, Some(5_i128), 2_i128),
+            (-7, 3, 1, 1, 4, Some(-5), 0),
+            (-7, 3, -1, 1, 4, Some(-5), -2),
+            (7, -3, 22, 1, 4, Some(0), 1),
+            (i128::MAX, 2, i128::MAX, -2, 3, Some(0), 0),
+            (i128::MAX, i128::MAX, 1, 1, i128::MAX, Some(i128::MAX), 1),
+            (i128::MAX, 2, 1, 1, 2, Some(i128::MAX), 1),
+            (i128::MAX, 2, 2, 1, 2, None, 0),
+            (i128::MIN, 1, i128::MIN, 1, 4, Some(-(1 << 126)), 0),
+            (i128::MIN, 1, i128::MIN, 1, 1, None, 0),
+            (i128::MIN, i128::MIN, i128::MIN, i128::MIN, 1, None, 0),
+        ];
+        for (x1, y1, x2, y2, z, q, r) in test_data {
+            assert_eq!(
+                i256_sum_of_prods_div_mod(x1, y1, x2, y2, z),
+                (q, r),
+                "{x1} {y1} {x2} {y2} {z}"
+            );
+        }
+    }
+}
@@ -12,7 +12,7 @@ use core::cell::RefCell;
 
 use crate::{
     i128_div_mod_floor, i128_shifted_div_mod_floor, i256_div_mod_floor,
-    ten_pow,
+    ten_pow, u128_shifted_div_mod,
 };
 
 /// Enum representing the different methods used when rounding a number.
@@ -40,7 +40,7 @@ pub enum RoundingMode {
 #[cfg(feature = "std")]
 thread_local!(
     static DFLT_ROUNDING_MODE: RefCell<RoundingMode> =
-        RefCell::new(RoundingMode::RoundHalfEven)
+        const { RefCell::new(RoundingMode::RoundHalfEven) }
 );
 
 #[cfg(feature = "std")]
@@ -108,12 +108,10 @@ fn round_quot(
         // no need for rounding
         return quot;
     }
-    // here: |divisor| >= 2 => rem <= |divident| / 2,
-    // therefor it's safe to use rem << 1
-    let mode = match mode {
-        None => RoundingMode::default(),
-        Some(mode) => mode,
-    };
+    // compare rem with divisor - rem instead of rem << 1 with divisor, so
+    // that divisor may exceed i128::MAX
+    let rem_compl = divisor - rem;
+    let mode = mode.unwrap_or_default();
     match mode {
         RoundingMode::Round05Up => {
             // Round down unless last digit is 0 or 5:
@@ -146,8 +144,7 @@ fn round_quot(
             // remainder > |divisor| / 2 or
             // remainder = |divisor| / 2 and quotient < 0
             // => add 1
-            let rem_doubled = rem << 1;
-            if rem_doubled > divisor || rem_doubled == divisor && quot < 0 {
+            if rem > rem_compl || rem == rem_compl && quot < 0 {
                 return quot + 1;
             }
         }
@@ -156,10 +153,7 @@ fn round_quot(
             // remainder > |divisor| / 2 or
             // remainder = |divisor| / 2 and quotient not even
             // => add 1
-            let rem_doubled = rem << 1;
-            if rem_doubled > divisor
-                || rem_doubled == divisor && quot % 2 != 0
-            {
+            if rem > rem_compl || rem == rem_compl && quot % 2 != 0 {
                 return quot + 1;
             }
         }
@@ -168,8 +162,7 @@ fn round_quot(
             // remainder > |divisor| / 2 or
             // remainder = |divisor| / 2 and quotient >= 0
             // => add 1
-            let rem_doubled = rem << 1;
-            if rem_doubled > divisor || rem_doubled == divisor && quot >= 0 {
+            if rem > rem_compl || rem == rem_compl && quot >= 0 {
                 return quot + 1;
             }
         }
@@ -207,18 +200,49 @@ pub fn i128_div_rounded(
 #[doc(hidden)]
 #[must_use]
 pub fn i128_shifted_div_rounded(
-    mut divident: i128,
+    divident: i128,
     p: u8,
-    mut divisor: i128,
+    divisor: i128,
     mode: Option<RoundingMode>,
 ) -> Option<i128> {
-    if divisor < 0 {
-        divident = -divident;
-        divisor = -divisor;
-    }
     let (quot, rem) = i128_shifted_div_mod_floor(divident, p, divisor)?;
-    // div_mod_floor with divisor > 0 => rem >= 0
-    Some(round_quot(quot, rem as u128, divisor as u128, mode))
+    // div_mod_floor => rem has the same sign as divisor, so that
+    // rem / divisor = |rem| / |divisor| >= 0
+    Some(round_quot(
+        quot,
+        rem.unsigned_abs(),
+        divisor.unsigned_abs(),
+        mode,
+    ))
+}
+
+/// Divide 'divident * 10^p' by 'divisor', round result according to 'mode'
+/// and return it negated if 'negative' is true.
+#[doc(hidden)]
+#[must_use]
+pub fn u128_shifted_div_rounded(
+    divident: u128,
+    p: u8,
+    divisor: u128,
+    negative: bool,
+    mode: Option<RoundingMode>,
+) -> Option<i128> {
+    let (quot, rem) = u128_shifted_div_mod(divident, p, divisor);
+    let quot = i128::try_from(quot?).ok()?;
+    if rem == 0 {
+        return Some(if negative { -quot } else { quot });
+    }
+    if negative {
+        // floor of the negated quotient, remainder relative to it
+        Some(round_quot(-quot - 1, divisor - rem, divisor, mode))
+    } else if quot == i128::MAX {
+        // Rounding may add 1 to the quotient, so check that on a value
+        // which is congruent modulo 10.
+        (round_quot(quot - 10, rem, divisor, mode) == quot - 10)
+            .then_some(quot)
+    } else {
+        Some(round_quot(quot, rem, divisor, mode))
+    }
 }
 
 /// Divide 'x * y' by '10^p' and round result according to 'mode'.
@@ -313,4 +337,91 @@ mod helper_tests {
             assert_eq!(quot, result);
         }
     }
+
+    #[test]
+    fn test_shifted_div_rounded() {
+        for (divident, divisor, rnd_mode, result) in TESTDATA {
+            let quot = i128_shifted_div_rounded(
+                divident,
+                0,
+                divisor,
+                Some(rnd_mode),
+            );
+            assert_eq!(quot, Some(result));
+        }
+        let quot = i128_shifted_div_rounded(
+            -6,
+            0,
+            3,
+            Some(RoundingMode::RoundFloor),
+        );
+        assert_eq!(quot, Some(-2));
+        let quot = i128_shifted_div_rounded(
+            i128::MIN,
+            0,
+            -3,
+            Some(RoundingMode::RoundCeiling),
+        );
+        assert_eq!(quot, Some(56713727820156410577229101238628035243));
+    }
+
+    #[test]
+    fn test_u128_shifted_div_rounded() {
+        for (divident, divisor, rnd_mode, result) in TESTDATA {
+            let negative = divident.is_negative() != divisor.is_negative();
+            let quot = u128_shifted_div_rounded(
+                divident.unsigned_abs(),
+                0,
+                divisor.unsigned_abs(),
+                negative,
+                Some(rnd_mode),
+            );
+            assert_eq!(quot, Some(result));
+        }
+        let divisor = u128::MAX - 1;
+        let test_data = [
+            ((u128::MAX >> 1), RoundingMode::RoundHalfEven, false, 0),
+            ((u128::MAX >> 1), RoundingMode::RoundHalfUp, false, 1),
+            ((u128::MAX >> 1), RoundingMode::RoundHalfUp, true, -1),
+            ((u128::MAX >> 1), RoundingMode::RoundHalfDown, true, 0),
+            ((u128::MAX >> 1) + 1, RoundingMode::RoundHalfDown, false, 1),
+            ((u128::MAX >> 1) - 1, RoundingMode::RoundHalfUp, false, 0),
+            (1, RoundingMode::RoundFloor, true, -1),
+            (1, RoundingMode::RoundCeiling, true, 0),
+        ];
+        for (divident, rnd_mode, negative, result) in test_data {
+            let quot = u128_shifted_div_rounded(
+                divident,
+                0,
+                divisor,
+                negative,
+                Some(rnd_mode),
+            );
+            assert_eq!(quot, Some(result));
+        }
+        let quot = u128_shifted_div_rounded(
+            u128::MAX,
+            0,
+            2,
+            false,
+            Some(RoundingMode::RoundDown),
+        );
+        assert_eq!(quot, Some(i128::MAX));
+        let quot = u128_shifted_div_rounded(
+            u128::MAX,
+            0,
+            2,
+            false,
+            Some(RoundingMode::RoundUp),
+        );
+        assert_eq!(quot, None);
+        let quot = u128_shifted_div_rounded(
+            u128::MAX,
+            1,
+            2,
+            false,
+            Some(RoundingMode::RoundDown),
+        );
+        assert_eq!(quot, None);
+    }
 }
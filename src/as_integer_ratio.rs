@@ -7,7 +7,7 @@
 // $Source$
 // $Revision$
 
-use core::{cmp::min, mem};
+use core::mem;
 
 use fpdec_core::ten_pow;
 
@@ -72,10 +72,6 @@ mod test_int_as_ratio {
     }
 }
 
-// The following algorithm is a special adaptation of the binary "greatest
-// common divisor" algorithm devised by Josef Stein, presented as "Algorithm
-// B" in D. E. Knuth, The Art of Computer Programming, Vol. 2, Ch. 4.5.2.
-
 /// Returns the greatest common divisor of `numer` and `10 ^ denom_exp`.
 ///
 /// Preconditions: `numer != 0`, `denom_exp <= 38`
@@ -83,13 +79,27 @@ mod test_int_as_ratio {
 fn gcd_special(numer: i128, denom_exp: u32) -> i128 {
     assert_ne!(numer, 0);
     assert!(denom_exp <= 38);
-    // Set u = |numer| with trailing zeros stripped off
-    let mut u = numer.abs();
-    let utz = u.trailing_zeros();
-    u >>= utz;
-    // Set v = denom with trailing zeros stripped off
     #[allow(clippy::cast_possible_truncation)]
-    let mut v = ten_pow(denom_exp as u8) >> denom_exp;
+    let denom = ten_pow(denom_exp as u8);
+    // gcd <= 10 ^ denom_exp <= i128::MAX, so the cast is lossless
+    #[allow(clippy::cast_possible_wrap)]
+    let gcd = gcd(numer.unsigned_abs(), denom.unsigned_abs()) as i128;
+    gcd
+}
+
+// The following algorithm is the binary "greatest common divisor" algorithm
+// devised by Josef Stein, presented as "Algorithm B" in D. E. Knuth, The Art
+// of Computer Programming, Vol. 2, Ch. 4.5.2.
+
+/// Returns the greatest common divisor of `u` and `v`.
+///
+/// Preconditions: `u != 0`, `v != 0`
+#[inline]
+pub(crate) fn gcd(mut u: u128, mut v: u128) -> u128 {
+    debug_assert!(u != 0 && v != 0);
+    // Common factors of 2
+    let shift = (u | v).trailing_zeros();
+    u >>= u.trailing_zeros();
     while v != 0 {
         v >>= v.trailing_zeros();
         if u > v {
@@ -98,7 +108,7 @@ fn gcd_special(numer: i128, denom_exp: u32) -> i128 {
         // here v >= u
         v -= u;
     }
-    u << min(utz, denom_exp)
+    u << shift
 }
 
 impl AsIntegerRatio for Decimal {
@@ -207,6 +217,19 @@ mod test_decimal_as_ratio {
         assert_eq!(d.numerator(), 12345678 / 2);
     }
 
+    #[test]
+    fn test_gcd() {
+        assert_eq!(gcd(1, 1), 1);
+        assert_eq!(gcd(12, 18), 6);
+        assert_eq!(gcd(18, 12), 6);
+        assert_eq!(gcd(17, 5), 1);
+        assert_eq!(gcd(1 << 100, 3 << 90), 1 << 90);
+        assert_eq!(gcd(u128::MAX, 3), 3);
+        assert_eq!(gcd_special(-1250, 3), 250);
+        assert_eq!(gcd_special(7, 5), 1);
+        assert_eq!(gcd_special(ten_pow(38), 38), ten_pow(38));
+    }
+
     #[test]
     fn test_decimal_denominator() {
         let d = Decimal::new_raw(0, MAX_N_FRAC_DIGITS);
@@ -0,0 +1,197 @@
+// ---------------------------------------------------------------------------
+// Copyright:   (c) 2026 ff. Michael Amrhein (michael@adrhinum.de)
+// License:     This program is part of a larger application. For license
+//              details please read the file LICENSE.TXT provided together
+//              with the application.
+// ---------------------------------------------------------------------------
+// $Source$
+// $Revision$
+
+use bigdecimal::{num_bigint::BigInt, BigDecimal, Zero};
+use fpdec_core::{checked_mul_pow_ten, MAX_N_FRAC_DIGITS};
+
+use crate::{from_float::MAGN_I128_MAX, Decimal, DecimalError};
+
+impl TryFrom<&BigDecimal> for Decimal {
+    type Error = DecimalError;
+
+    /// Tries to convert a `BigDecimal` value `d` into a `Decimal`.
+    ///
+    /// Returns the value as `Decimal`, wrapped in Result::Ok, if it can be
+    /// represented with at most `MAX_N_FRAC_DIGITS` fractional digits and a
+    /// coefficient in the range of `Decimal` coefficients. Trailing zeros
+    /// beyond `MAX_N_FRAC_DIGITS` are removed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error (wrapped in Result::Err) in the following cases:
+    /// * the value of `d` needs more than `MAX_N_FRAC_DIGITS` fractional
+    ///   digits => `DecimalError::MaxNFracDigitsExceeded`,
+    /// * the value of `d` exceeds the range of `Decimal` values =>
+    ///   `DecimalError::InternalOverflow`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use bigdecimal::BigDecimal;
+    /// # use core::str::FromStr;
+    /// # use fpdec::{Dec, Decimal, DecimalError};
+    /// # fn main() -> Result<(), DecimalError> {
+    /// let b = BigDecimal::from_str("-12.34560000000000000000").unwrap();
+    /// let d = Decimal::try_from(&b)?;
+    /// assert_eq!(d, Dec!(-12.3456));
+    /// # Ok(()) }
+    /// ```
+    #[allow(clippy::cast_possible_truncation)]
+    #[allow(clippy::cast_sign_loss)]
+    fn try_from(d: &BigDecimal) -> Result<Self, Self::Error> {
+        let (mut digits, scale) = d.as_bigint_and_exponent();
+        if digits.is_zero() {
+            return Ok(Self::ZERO);
+        }
+        if scale < 0 {
+            // value = digits * 10 ^ -scale
+            if scale < -i64::from(MAGN_I128_MAX) {
+                return Err(DecimalError::InternalOverflow);
+            }
+            return i128::try_from(&digits)
+                .ok()
+                .and_then(|coeff| checked_mul_pow_ten(coeff, (-scale) as u8))
+                .filter(|coeff| *coeff != i128::MIN)
+                .map(|coeff| Self {
+                    coeff,
+                    n_frac_digits: 0,
+                })
+                .ok_or(DecimalError::InternalOverflow);
+        }
+        let mut n_frac_digits = scale;
+        let excess = scale - i64::from(MAX_N_FRAC_DIGITS);
+        if excess > 0 {
+            // 10 ^ excess > 2 ^ excess > |digits| for excess > n bits
+            if excess as u64 > digits.bits() {
+                return Err(DecimalError::MaxNFracDigitsExceeded);
+            }
+            let divisor = BigInt::from(10_u8).pow(excess as u32);
+            if !(&digits % &divisor).is_zero() {
+                return Err(DecimalError::MaxNFracDigitsExceeded);
+            }
+            digits /= divisor;
+            n_frac_digits = i64::from(MAX_N_FRAC_DIGITS);
+        }
+        match i128::try_from(&digits) {
+            Ok(coeff) if coeff != i128::MIN => Ok(Self {
+                coeff,
+                n_frac_digits: n_frac_digits as u8,
+            }),
+            _ => Err(DecimalError::InternalOverflow),
+        }
+    }
+}
+
+impl TryFrom<BigDecimal> for Decimal {
+    type Error = DecimalError;
+
+    /// Tries to convert a `BigDecimal` value `d` into a `Decimal`.
+    ///
+    /// See `TryFrom<&BigDecimal>` for details.
+    #[inline(always)]
+    fn try_from(d: BigDecimal) -> Result<Self, Self::Error> {
+        Self::try_from(&d)
+    }
+}
+
+impl From<Decimal> for BigDecimal {
+    /// Converts a `Decimal` value `d` into a `BigDecimal`, keeping its
+    /// number of fractional digits.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use bigdecimal::BigDecimal;
+    /// # use fpdec::{Dec, Decimal};
+    /// let b = BigDecimal::from(Dec!(-12.3450));
+    /// assert_eq!(b.to_string(), "-12.3450");
+    /// ```
+    #[inline]
+    fn from(d: Decimal) -> Self {
+        Self::new(BigInt::from(d.coeff), i64::from(d.n_frac_digits))
+    }
+}
+
+#[cfg(test)]
+mod bigdecimal_tests {
+    use core::str::FromStr;
+
+    use super::*;
+
+    #[test]
+    fn test_from_bigdecimal() {
+        let test_data = [
+            ("0", 0_i128, 0_u8),
+            ("-0.000", 0, 0),
+            ("-1", -1, 0),
+            ("17.500", 17500, 3),
+            ("1.2E+5", 120000, 0),
+            ("-0.000000000000000001", -1, 18),
+            ("1.2340000000000000000000000", 1234000000000000000, 18),
+            ("170141183460469231731687303715884105727", i128::MAX, 0),
+            (
+                "-1.70141183460469231731687303715884105727E+38",
+                -i128::MAX,
+                0,
+            ),
+            ("170141183460469231731.687303715884105727", i128::MAX, 18),
+        ];
+        for (s, coeff, n_frac_digits) in test_data {
+            let b = BigDecimal::from_str(s).unwrap();
+            let d = Decimal::try_from(&b).unwrap();
+            assert_eq!(d.coefficient(), coeff, "{s}");
+            assert_eq!(d.n_frac_digits(), n_frac_digits, "{s}");
+            assert_eq!(Decimal::try_from(b).unwrap(), d);
+        }
+    }
+
+    #[test]
+    fn test_from_bigdecimal_errors() {
+        for s in
+            ["0.0000000000000000001", "-1.2345678901234567891", "1E-1000"]
+        {
+            let b = BigDecimal::from_str(s).unwrap();
+            assert_eq!(
+                Decimal::try_from(b).unwrap_err(),
+                DecimalError::MaxNFracDigitsExceeded,
+                "{s}"
+            );
+        }
+        for s in [
+            "170141183460469231731687303715884105728",
+            "-170141183460469231731687303715884105728",
+            "1.8E+38",
+            "1E+39",
+            "1E+1000",
+            "1701411834604692317316.873037158841057271",
+        ] {
+            let b = BigDecimal::from_str(s).unwrap();
+            assert_eq!(
+                Decimal::try_from(b).unwrap_err(),
+                DecimalError::InternalOverflow,
+                "{s}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_into_bigdecimal() {
+        let test_data = [
+            (Decimal::ZERO, "0"),
+            (Decimal::new_raw(-17500, 3), "-17.500"),
+            (Decimal::MAX, "170141183460469231731687303715884105727"),
+            (Decimal::new_raw(-1, 18), "-0.000000000000000001"),
+        ];
+        for (d, s) in test_data {
+            let b = BigDecimal::from(d);
+            assert_eq!(b, BigDecimal::from_str(s).unwrap());
+            assert_eq!(Decimal::try_from(b).unwrap(), d);
+        }
+    }
+}
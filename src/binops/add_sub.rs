@@ -14,7 +14,10 @@ use core::{
 
 use fpdec_core::mul_pow_ten;
 
-use crate::Decimal;
+use crate::{
+    binops::checked_add_sub::{CheckedAdd, CheckedSub},
+    Decimal, DecimalError,
+};
 
 macro_rules! impl_add_sub_decimal {
     (impl $imp:ident, $method:ident) => {
@@ -248,6 +251,70 @@ forward_ref_binop_decimal_int!(impl Add, add);
 impl_add_sub_decimal_and_int!(impl Sub, sub);
 forward_ref_binop_decimal_int!(impl Sub, sub);
 
+macro_rules! impl_add_sub_decimal_and_u128 {
+    (impl $imp:ident, $method:ident, $checked_imp:ident, $checked_method:ident) => {
+        impl $imp<u128> for Decimal {
+            type Output = Decimal;
+
+            fn $method(self, rhs: u128) -> Self::Output {
+                $checked_imp::$checked_method(self, rhs).unwrap_or_else(
+                    || panic!("{}", DecimalError::InternalOverflow),
+                )
+            }
+        }
+
+        impl $imp<Decimal> for u128 {
+            type Output = Decimal;
+
+            fn $method(self, rhs: Decimal) -> Self::Output {
+                $checked_imp::$checked_method(self, rhs).unwrap_or_else(
+                    || panic!("{}", DecimalError::InternalOverflow),
+                )
+            }
+        }
+    };
+}
+
+impl_add_sub_decimal_and_u128!(impl Add, add, CheckedAdd, checked_add);
+impl_add_sub_decimal_and_u128!(impl Sub, sub, CheckedSub, checked_sub);
+
+#[cfg(test)]
+mod add_sub_decimal_u128_tests {
+    use super::*;
+
+    const BIG: u128 = i128::MAX as u128 + 5;
+
+    #[test]
+    fn test_add_sub_u128() {
+        let x = Decimal::new_raw(-(i128::MAX - 70), 1);
+        assert_eq!(x + 7_u128, Decimal::new_raw(-(i128::MAX - 140), 1));
+        assert_eq!(7_u128 - x, Decimal::new_raw(i128::MAX, 1));
+        let x = Decimal::new_raw(-(i128::MAX - 7), 0);
+        assert_eq!(x + BIG, Decimal::new_raw(12, 0));
+        assert_eq!(BIG + x, Decimal::new_raw(12, 0));
+        assert_eq!(-x - BIG, Decimal::new_raw(-12, 0));
+        assert_eq!(BIG - -x, Decimal::new_raw(12, 0));
+        let mut y = x;
+        y += BIG;
+        assert_eq!(y, Decimal::new_raw(12, 0));
+        y -= &BIG;
+        assert_eq!(y, x);
+        assert_eq!(&x + &BIG, BIG + &x);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_add_u128_overflow() {
+        let _ = Decimal::ONE + BIG;
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_sub_u128_overflow() {
+        let _ = BIG - Decimal::NEG_ONE;
+    }
+}
+
 #[cfg(test)]
 mod add_sub_integer_tests {
     use fpdec_core::ten_pow;
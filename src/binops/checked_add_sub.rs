@@ -9,7 +9,7 @@
 
 use core::cmp::Ordering;
 
-use fpdec_core::checked_mul_pow_ten;
+use fpdec_core::{checked_mul_pow_ten, ten_pow};
 
 use crate::Decimal;
 
@@ -236,6 +236,130 @@ forward_ref_binop_decimal_int!(impl CheckedAdd, checked_add);
 impl_checked_add_sub_decimal_and_int!(impl CheckedSub, checked_sub);
 forward_ref_binop_decimal_int!(impl CheckedSub, checked_sub);
 
+// An u128 value > i128::MAX can only be combined with a decimal of the
+// right sign to give a result which can be represented by a `Decimal`.
+
+impl CheckedAdd<u128> for Decimal {
+    type Output = Option<Self>;
+
+    fn checked_add(self, rhs: u128) -> Self::Output {
+        if let Ok(rhs) = i128::try_from(rhs) {
+            return self.checked_add(rhs);
+        }
+        // self + rhs = rhs - |self|
+        if !self.is_negative() {
+            return None;
+        }
+        let rhs =
+            rhs.checked_mul(ten_pow(self.n_frac_digits).unsigned_abs())?;
+        Some(Self {
+            coeff: i128::try_from(rhs - self.coeff.unsigned_abs()).ok()?,
+            n_frac_digits: self.n_frac_digits,
+        })
+    }
+}
+
+impl CheckedAdd<Decimal> for u128 {
+    type Output = Option<Decimal>;
+
+    #[inline(always)]
+    fn checked_add(self, rhs: Decimal) -> Self::Output {
+        rhs.checked_add(self)
+    }
+}
+
+impl CheckedSub<u128> for Decimal {
+    type Output = Option<Self>;
+
+    fn checked_sub(self, rhs: u128) -> Self::Output {
+        if let Ok(rhs) = i128::try_from(rhs) {
+            return self.checked_sub(rhs);
+        }
+        // self - rhs = -(rhs - self)
+        if self.is_negative() {
+            return None;
+        }
+        let rhs =
+            rhs.checked_mul(ten_pow(self.n_frac_digits).unsigned_abs())?;
+        Some(Self {
+            coeff: -i128::try_from(rhs - self.coeff.unsigned_abs()).ok()?,
+            n_frac_digits: self.n_frac_digits,
+        })
+    }
+}
+
+impl CheckedSub<Decimal> for u128 {
+    type Output = Option<Decimal>;
+
+    fn checked_sub(self, rhs: Decimal) -> Self::Output {
+        if let Ok(lhs) = i128::try_from(self) {
+            return CheckedSub::checked_sub(lhs, rhs);
+        }
+        // self - rhs = self - |rhs|
+        if !rhs.is_positive() {
+            return None;
+        }
+        let lhs =
+            self.checked_mul(ten_pow(rhs.n_frac_digits).unsigned_abs())?;
+        Some(Decimal {
+            coeff: i128::try_from(lhs - rhs.coeff.unsigned_abs()).ok()?,
+            n_frac_digits: rhs.n_frac_digits,
+        })
+    }
+}
+
+#[cfg(test)]
+mod checked_add_sub_decimal_u128_tests {
+    use super::*;
+
+    const BIG: u128 = i128::MAX as u128 + 5;
+
+    #[test]
+    fn test_checked_add_u128() {
+        let x = Decimal::new_raw(-1234567, 3);
+        let y = x.checked_add(7_u128).unwrap();
+        assert_eq!(y, Decimal::new_raw(-1227567, 3));
+        assert_eq!(CheckedAdd::checked_add(7_u128, x), Some(y));
+        let x = Decimal::new_raw(-(i128::MAX - 7), 0);
+        assert_eq!(x.checked_add(BIG), Some(Decimal::new_raw(12, 0)));
+        assert_eq!(
+            CheckedAdd::checked_add(BIG, x),
+            Some(Decimal::new_raw(12, 0))
+        );
+        assert_eq!(Decimal::ZERO.checked_add(BIG), None);
+        assert_eq!(Decimal::new_raw(-1, 1).checked_add(BIG), None);
+        assert_eq!(
+            CheckedAdd::checked_add(BIG, Decimal::MIN),
+            Some(Decimal::new_raw(5, 0))
+        );
+    }
+
+    #[test]
+    fn test_checked_sub_u128() {
+        let x = Decimal::new_raw(1234567, 3);
+        let y = x.checked_sub(7_u128).unwrap();
+        assert_eq!(y, Decimal::new_raw(1227567, 3));
+        assert_eq!(CheckedSub::checked_sub(7_u128, x), Some(-y));
+        let x = Decimal::new_raw(i128::MAX - 7, 0);
+        assert_eq!(x.checked_sub(BIG), Some(Decimal::new_raw(-12, 0)));
+        assert_eq!(
+            CheckedSub::checked_sub(BIG, x),
+            Some(Decimal::new_raw(12, 0))
+        );
+        assert_eq!(Decimal::ZERO.checked_sub(BIG), None);
+        assert_eq!(Decimal::NEG_ONE.checked_sub(BIG), None);
+        assert_eq!(CheckedSub::checked_sub(BIG, Decimal::ZERO), None);
+        assert_eq!(
+            CheckedSub::checked_sub(BIG, Decimal::new_raw(-1, 1)),
+            None
+        );
+        assert_eq!(
+            CheckedSub::checked_sub(BIG, Decimal::MAX),
+            Some(Decimal::new_raw(5, 0))
+        );
+    }
+}
+
 #[cfg(test)]
 mod checked_add_sub_integer_tests {
     use fpdec_core::ten_pow;
@@ -7,7 +7,7 @@
 // $Source$
 // $Revision$
 
-use fpdec_core::MAX_N_FRAC_DIGITS;
+use fpdec_core::{u128_shifted_div_rounded, MAX_N_FRAC_DIGITS};
 
 use crate::{binops::div_rounded::checked_div_rounded, normalize, Decimal};
 
@@ -42,6 +42,7 @@ impl CheckedDiv<Self> for Decimal {
             rhs.coeff,
             rhs.n_frac_digits,
             n_frac_digits,
+            None,
         )?;
         normalize(&mut coeff, &mut n_frac_digits);
         Some(Self {
@@ -170,6 +171,7 @@ macro_rules! impl_div_decimal_and_int {
                     i128::from(rhs),
                     0,
                     n_frac_digits,
+                    None,
                 )?;
                 normalize(&mut coeff, &mut n_frac_digits);
                 Some(Self {
@@ -202,6 +204,7 @@ macro_rules! impl_div_decimal_and_int {
                     rhs.coeff,
                     rhs.n_frac_digits,
                     n_frac_digits,
+                    None,
                 )?;
                 normalize(&mut coeff, &mut n_frac_digits);
                 Some(Decimal {
@@ -217,6 +220,108 @@ macro_rules! impl_div_decimal_and_int {
 impl_div_decimal_and_int!();
 forward_ref_binop_decimal_int!(impl CheckedDiv, checked_div);
 
+impl CheckedDiv<u128> for Decimal {
+    type Output = Option<Self>;
+
+    fn checked_div(self, rhs: u128) -> Self::Output {
+        if let Ok(rhs) = i128::try_from(rhs) {
+            return self.checked_div(rhs);
+        }
+        let mut n_frac_digits = MAX_N_FRAC_DIGITS;
+        let mut coeff = u128_shifted_div_rounded(
+            self.coeff.unsigned_abs(),
+            n_frac_digits - self.n_frac_digits,
+            rhs,
+            self.is_negative(),
+            None,
+        )?;
+        normalize(&mut coeff, &mut n_frac_digits);
+        Some(Self {
+            coeff,
+            n_frac_digits,
+        })
+    }
+}
+
+impl CheckedDiv<Decimal> for u128 {
+    type Output = Option<Decimal>;
+
+    fn checked_div(self, rhs: Decimal) -> Self::Output {
+        if let Ok(lhs) = i128::try_from(self) {
+            return CheckedDiv::checked_div(lhs, rhs);
+        }
+        if rhs.eq_zero() {
+            return None;
+        }
+        let mut n_frac_digits = MAX_N_FRAC_DIGITS;
+        let mut coeff = u128_shifted_div_rounded(
+            self,
+            n_frac_digits + rhs.n_frac_digits,
+            rhs.coeff.unsigned_abs(),
+            rhs.is_negative(),
+            None,
+        )?;
+        normalize(&mut coeff, &mut n_frac_digits);
+        Some(Decimal {
+            coeff,
+            n_frac_digits,
+        })
+    }
+}
+
+#[cfg(test)]
+mod checked_div_decimal_u128_tests {
+    use super::*;
+
+    const BIG: u128 = 1_u128 << 127;
+
+    #[test]
+    fn test_checked_div_u128() {
+        let x = Decimal::new_raw(-1234567, 3);
+        let y = x.checked_div(8_u128).unwrap();
+        assert_eq!(y, Decimal::new_raw(-154320875, 6));
+        let y = CheckedDiv::checked_div(5_u128, x).unwrap();
+        assert_eq!(y, Decimal::new_raw(-4050002956502158, 18));
+        assert_eq!(x.checked_div(0_u128), None);
+        let x = Decimal::new_raw(1 << 100, 0);
+        assert_eq!(
+            x.checked_div(BIG),
+            Some(Decimal::new_raw(7450580597, 18))
+        );
+        let x = Decimal::new_raw(-(1 << 100), 0);
+        assert_eq!(
+            x.checked_div(u128::MAX),
+            Some(Decimal::new_raw(-3725290298, 18))
+        );
+        assert_eq!(Decimal::ZERO.checked_div(BIG), Some(Decimal::ZERO));
+    }
+
+    #[test]
+    fn test_checked_div_by_decimal_u128() {
+        let x = Decimal::new_raw(1 << 120, 0);
+        assert_eq!(
+            CheckedDiv::checked_div(BIG, x),
+            Some(Decimal::new_raw(128, 0))
+        );
+        let x = Decimal::new_raw(-(1 << 126), 18);
+        assert_eq!(
+            CheckedDiv::checked_div(BIG, x),
+            Some(Decimal::new_raw(-2_000_000_000_000_000_000, 0))
+        );
+        let x = Decimal::new_raw(3 << 125, 0);
+        assert_eq!(
+            CheckedDiv::checked_div(BIG + 1, x),
+            Some(Decimal::new_raw(1_333_333_333_333_333_333, 18))
+        );
+        assert_eq!(CheckedDiv::checked_div(BIG, Decimal::ONE), None);
+        assert_eq!(
+            CheckedDiv::checked_div(BIG, Decimal::new_raw(-4, 0)),
+            None
+        );
+        assert_eq!(CheckedDiv::checked_div(BIG, Decimal::ZERO), None);
+    }
+}
+
 #[cfg(test)]
 #[allow(clippy::neg_multiply)]
 mod checked_div_integer_tests {
@@ -143,6 +143,48 @@ macro_rules! impl_checked_mul_decimal_and_int {
 impl_checked_mul_decimal_and_int!();
 forward_ref_binop_decimal_int!(impl CheckedMul, checked_mul);
 
+impl CheckedMul<u128> for Decimal {
+    type Output = Option<Self>;
+
+    #[inline]
+    fn checked_mul(self, rhs: u128) -> Self::Output {
+        match i128::try_from(rhs) {
+            Ok(rhs) => self.checked_mul(rhs),
+            // |self * rhs| > i128::MAX, unless self == 0
+            Err(_) => self.eq_zero().then_some(self),
+        }
+    }
+}
+
+impl CheckedMul<Decimal> for u128 {
+    type Output = Option<Decimal>;
+
+    #[inline(always)]
+    fn checked_mul(self, rhs: Decimal) -> Self::Output {
+        rhs.checked_mul(self)
+    }
+}
+
+#[cfg(test)]
+mod checked_mul_decimal_u128_tests {
+    use super::*;
+
+    #[test]
+    fn test_checked_mul_u128() {
+        let x = Decimal::new_raw(-1234567, 3);
+        let y = x.checked_mul(7_u128).unwrap();
+        assert_eq!(y, Decimal::new_raw(-8641969, 3));
+        assert_eq!(CheckedMul::checked_mul(7_u128, x), Some(y));
+        assert_eq!(x.checked_mul(u128::MAX), None);
+        assert_eq!(CheckedMul::checked_mul(u128::MAX, x), None);
+        assert_eq!(Decimal::ZERO.checked_mul(u128::MAX), Some(Decimal::ZERO));
+        assert_eq!(
+            Decimal::ONE.checked_mul(i128::MAX as u128),
+            Some(Decimal::MAX)
+        );
+    }
+}
+
 #[cfg(test)]
 #[allow(clippy::neg_multiply)]
 mod checked_mul_integer_tests {
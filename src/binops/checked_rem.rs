@@ -7,6 +7,8 @@
 // $Source$
 // $Revision$
 
+use fpdec_core::u128_shifted_div_mod;
+
 use crate::{binops::rem::rem, Decimal};
 
 /// Checked remainder.
@@ -159,6 +161,74 @@ macro_rules! impl_checked_rem_decimal_and_int {
 impl_checked_rem_decimal_and_int!();
 forward_ref_binop_decimal_int!(impl CheckedRem, checked_rem);
 
+impl CheckedRem<u128> for Decimal {
+    type Output = Option<Self>;
+
+    fn checked_rem(self, rhs: u128) -> Self::Output {
+        match i128::try_from(rhs) {
+            Ok(rhs) => self.checked_rem(rhs),
+            // |self| < rhs
+            Err(_) => Some(self),
+        }
+    }
+}
+
+impl CheckedRem<Decimal> for u128 {
+    type Output = Option<Decimal>;
+
+    fn checked_rem(self, rhs: Decimal) -> Self::Output {
+        if let Ok(lhs) = i128::try_from(self) {
+            return CheckedRem::checked_rem(lhs, rhs);
+        }
+        if rhs.eq_zero() {
+            return None;
+        }
+        let (_, rem) = u128_shifted_div_mod(
+            self,
+            rhs.n_frac_digits,
+            rhs.coeff.unsigned_abs(),
+        );
+        Some(Decimal {
+            // rem < |rhs.coeff|
+            coeff: i128::try_from(rem).ok()?,
+            n_frac_digits: rhs.n_frac_digits,
+        })
+    }
+}
+
+#[cfg(test)]
+mod checked_rem_decimal_u128_tests {
+    use super::*;
+
+    const BIG: u128 = 1_u128 << 127;
+
+    #[test]
+    fn test_checked_rem_u128() {
+        let x = Decimal::new_raw(-1234567, 3);
+        assert_eq!(x.checked_rem(7_u128), Some(Decimal::new_raw(-2567, 3)));
+        assert_eq!(
+            CheckedRem::checked_rem(1235_u128, x),
+            Some(Decimal::new_raw(433, 3))
+        );
+        assert_eq!(x.checked_rem(0_u128), None);
+        assert_eq!(x.checked_rem(BIG), Some(x));
+        assert_eq!(Decimal::MIN.checked_rem(BIG), Some(Decimal::MIN));
+        assert_eq!(
+            CheckedRem::checked_rem(BIG, x),
+            Some(Decimal::new_raw(524859, 3))
+        );
+        assert_eq!(
+            CheckedRem::checked_rem(BIG, Decimal::new_raw(3, 0)),
+            Some(Decimal::new_raw(2, 0))
+        );
+        assert_eq!(
+            CheckedRem::checked_rem(BIG, Decimal::new_raw(1, 18)),
+            Some(Decimal::new_raw(0, 18))
+        );
+        assert_eq!(CheckedRem::checked_rem(BIG, Decimal::ZERO), None);
+    }
+}
+
 #[cfg(test)]
 #[allow(clippy::neg_multiply)]
 mod checked_rem_integer_tests {
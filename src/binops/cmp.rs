@@ -7,13 +7,18 @@
 // $Source$
 // $Revision$
 
-use core::cmp::Ordering;
+use core::cmp::{min, Ordering};
 
-use fpdec_core::{checked_adjust_coeffs, checked_mul_pow_ten, ten_pow};
+use fpdec_core::{
+    checked_adjust_coeffs, checked_mul_pow_ten, ten_pow, MAX_N_FRAC_DIGITS,
+};
 
-#[cfg(all(feature = "rkyv"))]
+#[cfg(feature = "rkyv")]
 use crate::ArchivedDecimal;
-use crate::Decimal;
+use crate::{
+    from_float::{f32_decode, f64_decode},
+    Decimal,
+};
 
 macro_rules! impl_partial_eq {
     ($t:ty, $target:ty) => {
@@ -34,12 +39,12 @@ macro_rules! impl_partial_eq {
 }
 
 impl_partial_eq!(Decimal, Decimal);
-#[cfg(all(feature = "rkyv"))]
+#[cfg(feature = "rkyv")]
 impl_partial_eq!(ArchivedDecimal, ArchivedDecimal);
-#[cfg(all(feature = "rkyv"))]
+#[cfg(feature = "rkyv")]
 impl_partial_eq!(Decimal, ArchivedDecimal);
 
-#[cfg(all(feature = "rkyv"))]
+#[cfg(feature = "rkyv")]
 impl PartialEq<ArchivedDecimal> for Decimal {
     fn eq(&self, other: &ArchivedDecimal) -> bool {
         other.eq(self)
@@ -53,6 +58,7 @@ impl Eq for ArchivedDecimal {}
 
 macro_rules! impl_partial_ord {
     ($t:ty, $target:ty) => {
+        #[allow(clippy::non_canonical_partial_ord_impl)]
         impl PartialOrd<$t> for $target {
             fn partial_cmp(&self, other: &$t) -> Option<Ordering> {
                 match checked_adjust_coeffs(
@@ -85,12 +91,12 @@ macro_rules! impl_partial_ord {
 }
 
 impl_partial_ord!(Decimal, Decimal);
-#[cfg(all(feature = "rkyv"))]
+#[cfg(feature = "rkyv")]
 impl_partial_ord!(ArchivedDecimal, ArchivedDecimal);
-#[cfg(all(feature = "rkyv"))]
+#[cfg(feature = "rkyv")]
 impl_partial_ord!(Decimal, ArchivedDecimal);
 
-#[cfg(all(feature = "rkyv"))]
+#[cfg(feature = "rkyv")]
 impl PartialOrd<ArchivedDecimal> for Decimal {
     fn partial_cmp(&self, other: &ArchivedDecimal) -> Option<Ordering> {
         other.partial_cmp(self).map(|o| o.reverse())
@@ -104,7 +110,7 @@ impl Ord for Decimal {
     }
 }
 
-#[cfg(all(feature = "rkyv"))]
+#[cfg(feature = "rkyv")]
 impl Ord for ArchivedDecimal {
     #[inline]
     fn cmp(&self, other: &Self) -> Ordering {
@@ -315,7 +321,9 @@ impl_decimal_eq_signed_int!();
 
 macro_rules! impl_int_eq_decimal {
     () => {
-        impl_int_eq_decimal!(u8, i8, u16, i16, u32, i32, u64, i64, i128);
+        impl_int_eq_decimal!(
+            u8, i8, u16, i16, u32, i32, u64, i64, i128, u128
+        );
     };
     ($($t:ty),*) => {
         $(
@@ -440,6 +448,35 @@ macro_rules! impl_uint_cmp_decimal {
 
 impl_uint_cmp_decimal!();
 
+// An u128 value > i128::MAX is greater than any decimal.
+
+impl PartialEq<u128> for Decimal {
+    #[inline]
+    fn eq(&self, other: &u128) -> bool {
+        i128::try_from(*other).is_ok_and(|other| self.eq(&other))
+    }
+}
+
+impl PartialOrd<u128> for Decimal {
+    #[inline]
+    fn partial_cmp(&self, other: &u128) -> Option<Ordering> {
+        match i128::try_from(*other) {
+            Ok(other) => self.partial_cmp(&other),
+            Err(_) => Some(Ordering::Less),
+        }
+    }
+}
+
+impl PartialOrd<Decimal> for u128 {
+    #[inline]
+    fn partial_cmp(&self, other: &Decimal) -> Option<Ordering> {
+        match i128::try_from(*self) {
+            Ok(i) => i.partial_cmp(other),
+            Err(_) => Some(Ordering::Greater),
+        }
+    }
+}
+
 #[cfg(test)]
 mod cmp_decimals_and_ints_tests {
     use core::cmp::Ordering;
@@ -482,6 +519,295 @@ mod cmp_decimals_and_ints_tests {
     }
 }
 
+#[cfg(test)]
+mod cmp_decimals_and_u128_tests {
+    use core::cmp::Ordering;
+
+    use crate::Decimal;
+
+    #[test]
+    fn test_eq_u128() {
+        let x = Decimal::new_raw(170, 1);
+        assert_eq!(x, 17_u128);
+        assert_eq!(17_u128, x);
+        assert_ne!(x, 18_u128);
+        assert_ne!(-x, 17_u128);
+        assert_eq!(Decimal::MAX, i128::MAX as u128);
+        assert_ne!(Decimal::MAX, u128::MAX);
+        assert_ne!(u128::MAX, Decimal::MAX);
+    }
+
+    #[test]
+    fn test_cmp_u128() {
+        let x = Decimal::new_raw(-170, 1);
+        assert!(x < 17_u128);
+        assert!(17_u128 > x);
+        assert_eq!(x.abs().partial_cmp(&17_u128), Some(Ordering::Equal));
+        assert!(Decimal::new_raw(171, 1) > 17_u128);
+        assert!(Decimal::MAX < i128::MAX as u128 + 1);
+        assert!(u128::MAX > Decimal::MAX);
+        assert!(Decimal::MIN < u128::MAX);
+        assert!(i128::MAX as u128 >= Decimal::MAX);
+    }
+}
+
+// Compares |coeff| / 10 ^ n_frac_digits with significand * 2 ^ exponent.
+#[allow(clippy::cast_possible_truncation)]
+#[allow(clippy::cast_sign_loss)]
+#[allow(clippy::integer_division)]
+fn cmp_abs_decimal_float(
+    abs_coeff: u128,
+    n_frac_digits: u8,
+    mut significand: u64,
+    mut exponent: i16,
+) -> Ordering {
+    // here: significand != 0
+    let divisor = ten_pow(n_frac_digits) as u128;
+    let (int, frac) = (abs_coeff / divisor, abs_coeff % divisor);
+    if exponent >= 0 {
+        // |float| >= 2 ^ 127 > |decimal| ?
+        if 64 - significand.leading_zeros() as i16 + exponent > 127 {
+            return Ordering::Less;
+        }
+        return match int.cmp(&(u128::from(significand) << exponent)) {
+            Ordering::Equal if frac != 0 => Ordering::Greater,
+            ord => ord,
+        };
+    }
+    // strip trailing zero bits, so that the fractional part of the float
+    // has exactly as many binary places as its denominator
+    let shift = min(significand.trailing_zeros() as i16, -exponent);
+    significand >>= shift;
+    exponent += shift;
+    let n_bin_places = (-exponent) as u32;
+    let (f_int, f_frac) = if n_bin_places < 64 {
+        (
+            significand >> n_bin_places,
+            significand % (1 << n_bin_places),
+        )
+    } else {
+        (0, significand)
+    };
+    match int.cmp(&u128::from(f_int)) {
+        Ordering::Equal => {}
+        ord => return ord,
+    }
+    // Compare the fractional parts based on MAX_N_FRAC_DIGITS decimal
+    // places. As f_frac < 2 ^ 53, the following can't overflow.
+    let frac = frac * ten_pow(MAX_N_FRAC_DIGITS - n_frac_digits) as u128;
+    let f_frac = u128::from(f_frac) * ten_pow(MAX_N_FRAC_DIGITS) as u128;
+    let (f_frac_trunc, exact) = if n_bin_places < 128 {
+        (
+            f_frac >> n_bin_places,
+            f_frac.trailing_zeros() >= n_bin_places,
+        )
+    } else {
+        (0, false)
+    };
+    match frac.cmp(&f_frac_trunc) {
+        // float has more than MAX_N_FRAC_DIGITS decimal places
+        Ordering::Equal if !exact => Ordering::Less,
+        ord => ord,
+    }
+}
+
+// Compares the decimal with the float given by its decoded parts.
+fn cmp_decimal_float(
+    coeff: i128,
+    n_frac_digits: u8,
+    (significand, exponent, sign): (u64, i16, i8),
+    is_positive: bool,
+) -> Ordering {
+    if sign == 0 {
+        // float is subnormal, i.e. less in magnitude than any non-zero
+        // decimal
+        return match coeff.cmp(&0) {
+            Ordering::Equal if is_positive => Ordering::Less,
+            Ordering::Equal => Ordering::Greater,
+            ord => ord,
+        };
+    }
+    match coeff.signum().cmp(&i128::from(sign)) {
+        Ordering::Equal => {}
+        ord => return ord,
+    }
+    let ord = cmp_abs_decimal_float(
+        coeff.unsigned_abs(),
+        n_frac_digits,
+        significand,
+        exponent,
+    );
+    if sign < 0 {
+        ord.reverse()
+    } else {
+        ord
+    }
+}
+
+macro_rules! impl_decimal_cmp_float {
+    ($($t:ty, $decode:ident),*) => {
+        $(
+        impl PartialEq<$t> for Decimal {
+            #[inline]
+            fn eq(&self, other: &$t) -> bool {
+                self.partial_cmp(other) == Some(Ordering::Equal)
+            }
+        }
+
+        impl PartialEq<Decimal> for $t {
+            #[inline]
+            fn eq(&self, other: &Decimal) -> bool {
+                PartialEq::eq(other, self)
+            }
+        }
+
+        impl PartialOrd<$t> for Decimal {
+            /// Compares `self` exactly with the binary value of `other`.
+            ///
+            /// Returns `None` if `other` is NaN. Positive / negative
+            /// infinity is greater / less than any `Decimal`.
+            fn partial_cmp(&self, other: &$t) -> Option<Ordering> {
+                if other.is_nan() {
+                    return None;
+                }
+                if other.is_infinite() {
+                    return if other.is_sign_positive() {
+                        Some(Ordering::Less)
+                    } else {
+                        Some(Ordering::Greater)
+                    };
+                }
+                if *other == 0.0 {
+                    return Some({ self.coeff }.cmp(&0));
+                }
+                Some(cmp_decimal_float(
+                    self.coeff,
+                    self.n_frac_digits,
+                    $decode(*other),
+                    other.is_sign_positive(),
+                ))
+            }
+        }
+
+        impl PartialOrd<Decimal> for $t {
+            /// Compares the binary value of `self` exactly with `other`.
+            ///
+            /// Returns `None` if `self` is NaN. Positive / negative
+            /// infinity is greater / less than any `Decimal`.
+            #[inline]
+            fn partial_cmp(&self, other: &Decimal) -> Option<Ordering> {
+                other.partial_cmp(self).map(Ordering::reverse)
+            }
+        }
+        )*
+    }
+}
+
+impl_decimal_cmp_float!(f32, f32_decode, f64, f64_decode);
+
+#[cfg(test)]
+mod cmp_decimals_and_floats_tests {
+    use core::cmp::Ordering;
+
+    use crate::Decimal;
+
+    #[test]
+    fn test_eq() {
+        let test_data = [
+            (Decimal::ZERO, 0.0_f64),
+            (Decimal::ZERO, -0.0_f64),
+            (Decimal::new_raw(-175, 1), -17.5),
+            (Decimal::new_raw(125, 3), 0.125),
+            (Decimal::new_raw(30517578125, 15), 0.000030517578125),
+            (Decimal::new_raw(3814697265625, 18), 0.000003814697265625),
+            (Decimal::new_raw(1 << 120, 0), (1_u128 << 120) as f64),
+            (Decimal::new_raw(-(1 << 126), 0), -((1_u128 << 126) as f64)),
+        ];
+        for (d, f) in test_data {
+            assert_eq!(d, f, "{d:?} != {f}");
+            assert_eq!(f, d, "{f} != {d:?}");
+            assert_eq!(d.partial_cmp(&f), Some(Ordering::Equal));
+        }
+        assert_eq!(Decimal::new_raw(-175, 1), -17.5_f32);
+        assert_eq!(-17.5_f32, Decimal::new_raw(-175, 1));
+        assert_eq!(
+            Decimal::new_raw(-(1 << 100), 0),
+            -((1_u128 << 100) as f32)
+        );
+    }
+
+    #[test]
+    fn test_ne() {
+        // 0.1 is not exactly representable in binary
+        let d = Decimal::new_raw(1, 1);
+        assert_ne!(d, 0.1_f64);
+        assert_eq!(d.partial_cmp(&0.1_f64), Some(Ordering::Less));
+        assert!(d < 0.1_f64);
+        assert!(0.1_f64 > d);
+        // 0.1_f32 = 0.100000001490116119384765625
+        assert!(d < 0.1_f32);
+        assert!(0.1_f32 > d);
+        let d = Decimal::new_raw(-1, 1);
+        assert!(d > -0.1_f64);
+        assert!(d > -0.1_f32);
+        // 0.1_f64 = 0.1000000000000000055511151231257827...
+        assert!(Decimal::new_raw(100000000000000005, 18) < 0.1_f64);
+        assert!(Decimal::new_raw(100000000000000006, 18) > 0.1_f64);
+        // 1e-18 has no exact binary representation either
+        assert_ne!(Decimal::new_raw(1, 18), 1e-18_f64);
+    }
+
+    #[test]
+    fn test_cmp() {
+        let d = Decimal::new_raw(-175, 1);
+        assert!(d < -17.25_f64);
+        assert!(d > -17.75_f64);
+        assert!(d < 0.0_f64);
+        assert!(d < 1e-300_f64);
+        assert!(d > -1e300_f64);
+        assert!(-17.25_f64 > d);
+        assert!(-17.75_f64 < d);
+        let d = Decimal::MAX;
+        assert!(d < (1_u128 << 127) as f64);
+        assert!(d > (1_u128 << 126) as f64);
+        assert!(d < f64::MAX);
+        assert!(Decimal::MIN > f64::MIN);
+        let d = Decimal::new_raw(1, 18);
+        assert!(d > 1e-19_f64);
+        assert!(d < 1.0000000000000001e-18_f64);
+        assert!(Decimal::ZERO < 1e-19_f64);
+        assert!(Decimal::ZERO > -1e-19_f64);
+        let d = Decimal::new_raw(17, 0);
+        assert!(d < 17.000000000000004_f64);
+        assert!(d > 16.999999999999996_f64);
+    }
+
+    #[test]
+    fn test_subnormal() {
+        let f = f64::MIN_POSITIVE / 4.0;
+        assert!(Decimal::ZERO < f);
+        assert!(Decimal::ZERO > -f);
+        assert_ne!(Decimal::ZERO, f);
+        assert!(Decimal::new_raw(1, 18) > f);
+        assert!(Decimal::new_raw(-1, 18) < f);
+        let f = f32::MIN_POSITIVE / 4.0;
+        assert!(Decimal::ZERO < f);
+        assert!(f > Decimal::ZERO);
+    }
+
+    #[test]
+    fn test_inf_nan() {
+        let d = Decimal::MAX;
+        assert!(d < f64::INFINITY);
+        assert!(d > f64::NEG_INFINITY);
+        assert!(f32::INFINITY > d);
+        assert!(f32::NEG_INFINITY < Decimal::MIN);
+        assert_eq!(d.partial_cmp(&f64::NAN), None);
+        assert_eq!(f32::NAN.partial_cmp(&d), None);
+        assert_ne!(d, f64::NAN);
+    }
+}
+
 #[cfg(feature = "rkyv")]
 #[cfg(test)]
 mod rkyv_cmp_decimals_tests {
@@ -10,8 +10,8 @@
 use core::ops::{Div, DivAssign};
 
 use crate::{
-    binops::div_rounded::checked_div_rounded, normalize, Decimal,
-    DecimalError, MAX_N_FRAC_DIGITS,
+    binops::{checked_div::CheckedDiv, div_rounded::checked_div_rounded},
+    normalize, Decimal, DecimalError, MAX_N_FRAC_DIGITS,
 };
 
 impl Div<Self> for Decimal {
@@ -35,6 +35,7 @@ impl Div<Self> for Decimal {
             rhs.coeff,
             rhs.n_frac_digits,
             n_frac_digits,
+            None,
         ) {
             normalize(&mut coeff, &mut n_frac_digits);
             Self::Output {
@@ -180,6 +181,7 @@ macro_rules! impl_div_decimal_and_int {
                     i128::from(rhs),
                     0,
                     n_frac_digits,
+                    None,
                 ) {
                     normalize(&mut coeff, &mut n_frac_digits);
                     Self::Output {
@@ -215,6 +217,7 @@ macro_rules! impl_div_decimal_and_int {
                     rhs.coeff,
                     rhs.n_frac_digits,
                     n_frac_digits,
+                    None,
                 ) {
                     normalize(&mut coeff, &mut n_frac_digits);
                     Self::Output {
@@ -233,6 +236,64 @@ macro_rules! impl_div_decimal_and_int {
 impl_div_decimal_and_int!();
 forward_ref_binop_decimal_int!(impl Div, div);
 
+impl Div<u128> for Decimal {
+    type Output = Self;
+
+    fn div(self, rhs: u128) -> Self::Output {
+        #[allow(clippy::manual_assert)]
+        if rhs == 0 {
+            panic!("{}", DecimalError::DivisionByZero);
+        }
+        CheckedDiv::checked_div(self, rhs)
+            .unwrap_or_else(|| panic!("{}", DecimalError::InternalOverflow))
+    }
+}
+
+impl Div<Decimal> for u128 {
+    type Output = Decimal;
+
+    fn div(self, rhs: Decimal) -> Self::Output {
+        #[allow(clippy::manual_assert)]
+        if rhs.eq_zero() {
+            panic!("{}", DecimalError::DivisionByZero);
+        }
+        CheckedDiv::checked_div(self, rhs)
+            .unwrap_or_else(|| panic!("{}", DecimalError::InternalOverflow))
+    }
+}
+
+#[cfg(test)]
+mod div_decimal_u128_tests {
+    use super::*;
+
+    const BIG: u128 = 1_u128 << 127;
+
+    #[test]
+    fn test_div_u128() {
+        let x = Decimal::new_raw(-1234567, 3);
+        assert_eq!(x / 8_u128, Decimal::new_raw(-154320875, 6));
+        assert_eq!(
+            &BIG / Decimal::new_raw(1 << 120, 0),
+            Decimal::new_raw(128, 0)
+        );
+        let mut y = Decimal::new_raw(1 << 100, 0);
+        y /= BIG;
+        assert_eq!(y, Decimal::new_raw(7450580597, 18));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_div_u128_by_zero() {
+        let _ = Decimal::ONE / 0_u128;
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_div_u128_overflow() {
+        let _ = BIG / Decimal::ONE;
+    }
+}
+
 #[cfg(test)]
 #[allow(clippy::neg_multiply)]
 mod div_integer_tests {
@@ -11,10 +11,10 @@ use core::cmp::Ordering;
 
 use fpdec_core::{
     checked_mul_pow_ten, i128_div_rounded, i128_shifted_div_rounded, ten_pow,
-    MAX_N_FRAC_DIGITS,
+    u128_shifted_div_rounded, MAX_N_FRAC_DIGITS,
 };
 
-use crate::{Decimal, DecimalError};
+use crate::{Decimal, DecimalError, RoundingMode};
 
 const MAGN_I128_MAX: u8 = 38;
 
@@ -58,11 +58,12 @@ pub(crate) fn checked_div_rounded(
     divisor_coeff: i128,
     divisor_n_frac_digits: u8,
     n_frac_digits: u8,
+    mode: Option<RoundingMode>,
 ) -> Option<i128> {
     let mut shift = n_frac_digits + divisor_n_frac_digits;
     match divident_n_frac_digits.cmp(&shift) {
         Ordering::Equal => {
-            Some(i128_div_rounded(divident_coeff, divisor_coeff, None))
+            Some(i128_div_rounded(divident_coeff, divisor_coeff, mode))
         }
         Ordering::Less => {
             // divident coeff needs to be shifted
@@ -71,31 +72,42 @@ pub(crate) fn checked_div_rounded(
             if let Some(shifted_divident) =
                 checked_mul_pow_ten(divident_coeff, shift)
             {
-                Some(i128_div_rounded(shifted_divident, divisor_coeff, None))
+                Some(i128_div_rounded(shifted_divident, divisor_coeff, mode))
             } else {
                 i128_shifted_div_rounded(
                     divident_coeff,
                     shift,
                     divisor_coeff,
-                    None,
+                    mode,
                 )
             }
         }
         Ordering::Greater => {
-            // divisor coeff needs to be shifted, but instead of calculating
-            // divident / (divisor * 10 ^ shift)
-            // we can calculate
-            // (divident / divisor) / 10 ^ shift
-            // thus avoiding i128 overflow.
+            // divisor coeff needs to be shifted
             // divident_n_frac_digits > shift
             shift = divident_n_frac_digits - shift;
             // shift < divident_n_frac_digits => shift < 18 => ten_pow(shift)
             // is safe
-            Some(i128_div_rounded(
-                divident_coeff / divisor_coeff,
-                ten_pow(shift),
-                None,
-            ))
+            if let Some(shifted_divisor) =
+                checked_mul_pow_ten(divisor_coeff, shift)
+            {
+                Some(i128_div_rounded(divident_coeff, shifted_divisor, mode))
+            } else {
+                // |divisor * 10 ^ shift| > |divident|, so the truncated
+                // quotient q = divident / divisor is less than 10 ^ shift in
+                // magnitude and we can calculate
+                // (10 * q + signum(divident / divisor - q)) / 10 ^ (shift +
+                // 1) which keeps the information needed for
+                // rounding, thus avoiding i128 overflow.
+                let quot = divident_coeff / divisor_coeff;
+                let sticky = (divident_coeff % divisor_coeff).signum()
+                    * divisor_coeff.signum();
+                Some(i128_div_rounded(
+                    quot * 10 + sticky,
+                    ten_pow(shift + 1),
+                    mode,
+                ))
+            }
         }
     }
 }
@@ -121,6 +133,7 @@ impl DivRounded<Self> for Decimal {
             rhs.coeff,
             rhs.n_frac_digits,
             n_frac_digits,
+            None,
         ) {
             Self::Output {
                 coeff,
@@ -216,6 +229,58 @@ mod div_rounded_decimal_tests {
         assert_eq!(z.n_frac_digits(), 10);
     }
 
+    #[test]
+    fn test_div_rounded_shifted_divisor() {
+        // 0.12501 / 0.05 = 2.5002
+        let coeff = checked_div_rounded(
+            12501,
+            5,
+            5,
+            2,
+            0,
+            Some(RoundingMode::RoundHalfEven),
+        );
+        assert_eq!(coeff, Some(3));
+        // divisor * 10 ^ 18 exceeds i128::MAX:
+        // +/- 100000000000000000000.000000000000000001 / +/- 2E20
+        let divident = mul_pow_ten(1, 38) + 1;
+        let divisor = mul_pow_ten(2, 20);
+        for (x, y, q) in [
+            (divident, divisor, 1),
+            (-divident, -divisor, 1),
+            (-divident, divisor, -1),
+            (divident, -divisor, -1),
+        ] {
+            let coeff = checked_div_rounded(
+                x,
+                18,
+                y,
+                0,
+                0,
+                Some(RoundingMode::RoundHalfEven),
+            );
+            assert_eq!(coeff, Some(q));
+        }
+        let coeff = checked_div_rounded(
+            divident - 1,
+            18,
+            divisor,
+            0,
+            0,
+            Some(RoundingMode::RoundHalfEven),
+        );
+        assert_eq!(coeff, Some(0));
+        let coeff = checked_div_rounded(
+            divident - 1,
+            18,
+            divisor,
+            0,
+            0,
+            Some(RoundingMode::RoundHalfUp),
+        );
+        assert_eq!(coeff, Some(1));
+    }
+
     #[test]
     #[should_panic]
     fn test_div_rounded_by_zero() {
@@ -270,6 +335,7 @@ macro_rules! impl_div_rounded_decimal_and_int {
                     i128::from(rhs),
                     0_u8,
                     n_frac_digits,
+                    None,
                 ) {
                     Self::Output {
                         coeff,
@@ -281,6 +347,133 @@ macro_rules! impl_div_rounded_decimal_and_int {
             }
         }
 
+        impl DivRounded<Decimal> for $t {
+            type Output = Decimal;
+
+            fn div_rounded(self, rhs: Decimal, n_frac_digits: u8) -> Self::Output {
+                if rhs.eq_zero() {
+                    panic!("{}", DecimalError::DivisionByZero);
+                }
+                if self == 0 {
+                    return Decimal::ZERO;
+                }
+                if let Some(coeff) = checked_div_rounded(
+                    i128::from(self),
+                    0_u8,
+                    rhs.coeff,
+                    rhs.n_frac_digits,
+                    n_frac_digits,
+                    None,
+                ) {
+                    Self::Output {
+                        coeff,
+                        n_frac_digits,
+                    }
+                } else {
+                    panic!("{}", DecimalError::InternalOverflow);
+                }
+            }
+        }
+
+        )*
+    }
+}
+
+impl_div_rounded_decimal_and_int!();
+
+impl DivRounded<u128> for Decimal {
+    type Output = Self;
+
+    fn div_rounded(self, rhs: u128, n_frac_digits: u8) -> Self::Output {
+        if let Ok(rhs) = i128::try_from(rhs) {
+            return DivRounded::div_rounded(self, rhs, n_frac_digits);
+        }
+        #[allow(clippy::manual_assert)]
+        if n_frac_digits > MAX_N_FRAC_DIGITS {
+            panic!("{}", DecimalError::MaxNFracDigitsExceeded);
+        }
+        // here: rhs > i128::MAX >= |self|
+        let coeff = if self.n_frac_digits <= n_frac_digits {
+            u128_shifted_div_rounded(
+                self.coeff.unsigned_abs(),
+                n_frac_digits - self.n_frac_digits,
+                rhs,
+                self.is_negative(),
+                None,
+            )
+        } else {
+            let shift = self.n_frac_digits - n_frac_digits;
+            match rhs.checked_mul(ten_pow(shift).unsigned_abs()) {
+                Some(divisor) => u128_shifted_div_rounded(
+                    self.coeff.unsigned_abs(),
+                    0,
+                    divisor,
+                    self.is_negative(),
+                    None,
+                ),
+                // |self.coeff| / divisor < 1 / 2, so the result is
+                // the same as for any other such ratio
+                None => u128_shifted_div_rounded(
+                    u128::from(!self.eq_zero()),
+                    0,
+                    3,
+                    self.is_negative(),
+                    None,
+                ),
+            }
+        };
+        match coeff {
+            Some(coeff) => Self::Output {
+                coeff,
+                n_frac_digits,
+            },
+            None => panic!("{}", DecimalError::InternalOverflow),
+        }
+    }
+}
+
+impl DivRounded<Decimal> for u128 {
+    type Output = Decimal;
+
+    fn div_rounded(self, rhs: Decimal, n_frac_digits: u8) -> Self::Output {
+        if let Ok(lhs) = i128::try_from(self) {
+            return DivRounded::div_rounded(lhs, rhs, n_frac_digits);
+        }
+        #[allow(clippy::manual_assert)]
+        if n_frac_digits > MAX_N_FRAC_DIGITS {
+            panic!("{}", DecimalError::MaxNFracDigitsExceeded);
+        }
+        #[allow(clippy::manual_assert)]
+        if rhs.eq_zero() {
+            panic!("{}", DecimalError::DivisionByZero);
+        }
+        match u128_shifted_div_rounded(
+            self,
+            n_frac_digits + rhs.n_frac_digits,
+            rhs.coeff.unsigned_abs(),
+            rhs.is_negative(),
+            None,
+        ) {
+            Some(coeff) => Self::Output {
+                coeff,
+                n_frac_digits,
+            },
+            None => panic!("{}", DecimalError::InternalOverflow),
+        }
+    }
+}
+
+// Implements "&Decimal.div_rounded(T)", "Decimal.div_rounded(&T)", ...
+// based on "Decimal.div_rounded(T)" and "T.div_rounded(Decimal)" where T is
+// a native int
+macro_rules! forward_ref_div_rounded_decimal_int {
+    () => {
+        forward_ref_div_rounded_decimal_int!(
+            u8, i8, u16, i16, u32, i32, u64, i64, i128, u128
+        );
+    };
+    ($($t:ty),*) => {
+        $(
         impl<'a> DivRounded<$t> for &'a Decimal
         where
             Decimal: DivRounded<$t>,
@@ -317,33 +510,6 @@ macro_rules! impl_div_rounded_decimal_and_int {
             }
         }
 
-        impl DivRounded<Decimal> for $t {
-            type Output = Decimal;
-
-            fn div_rounded(self, rhs: Decimal, n_frac_digits: u8) -> Self::Output {
-                if rhs.eq_zero() {
-                    panic!("{}", DecimalError::DivisionByZero);
-                }
-                if self == 0 {
-                    return Decimal::ZERO;
-                }
-                if let Some(coeff) = checked_div_rounded(
-                    i128::from(self),
-                    0_u8,
-                    rhs.coeff,
-                    rhs.n_frac_digits,
-                    n_frac_digits,
-                ) {
-                    Self::Output {
-                        coeff,
-                        n_frac_digits,
-                    }
-                } else {
-                    panic!("{}", DecimalError::InternalOverflow);
-                }
-            }
-        }
-
         impl<'a> DivRounded<Decimal> for &'a $t
         where
             $t: DivRounded<Decimal>,
@@ -383,7 +549,7 @@ macro_rules! impl_div_rounded_decimal_and_int {
     }
 }
 
-impl_div_rounded_decimal_and_int!();
+forward_ref_div_rounded_decimal_int!();
 
 #[cfg(test)]
 #[allow(clippy::neg_multiply)]
@@ -626,6 +792,7 @@ macro_rules! impl_div_rounded_int_and_int {
                     i128::from(rhs),
                     0_u8,
                     n_frac_digits,
+                    None,
                 ) {
                     Self::Output {
                         coeff,
@@ -637,6 +804,45 @@ macro_rules! impl_div_rounded_int_and_int {
             }
         }
 
+        )*
+    }
+}
+
+impl_div_rounded_int_and_int!();
+
+impl DivRounded<Self> for u128 {
+    type Output = Decimal;
+
+    fn div_rounded(self, rhs: Self, n_frac_digits: u8) -> Self::Output {
+        #[allow(clippy::manual_assert)]
+        if n_frac_digits > MAX_N_FRAC_DIGITS {
+            panic!("{}", DecimalError::MaxNFracDigitsExceeded);
+        }
+        #[allow(clippy::manual_assert)]
+        if rhs == 0 {
+            panic!("{}", DecimalError::DivisionByZero);
+        }
+        match u128_shifted_div_rounded(self, n_frac_digits, rhs, false, None)
+        {
+            Some(coeff) => Self::Output {
+                coeff,
+                n_frac_digits,
+            },
+            None => panic!("{}", DecimalError::InternalOverflow),
+        }
+    }
+}
+
+// Implements "&T.div_rounded(T)", "T.div_rounded(&T)" and
+// "&T.div_rounded(&T)" based on "T.div_rounded(T)" where T is a native int
+macro_rules! forward_ref_div_rounded_int_int {
+    () => {
+        forward_ref_div_rounded_int_int!(
+            u8, i8, u16, i16, u32, i32, u64, i64, i128, u128
+        );
+    };
+    ($($t:ty),*) => {
+        $(
         impl<'a> DivRounded<$t> for &'a $t
         where
             $t: DivRounded<$t>,
@@ -676,7 +882,7 @@ macro_rules! impl_div_rounded_int_and_int {
     }
 }
 
-impl_div_rounded_int_and_int!();
+forward_ref_div_rounded_int_int!();
 
 #[cfg(test)]
 #[allow(clippy::neg_multiply)]
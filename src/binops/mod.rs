@@ -91,7 +91,8 @@ macro_rules! forward_ref_binop_rounded {
 macro_rules! forward_ref_binop_decimal_int {
     (impl $imp:ident, $method:ident) => {
         forward_ref_binop_decimal_int!(
-            impl $imp, $method, u8, i8, u16, i16, u32, i32, u64, i64, i128
+            impl $imp, $method, u8, i8, u16, i16, u32, i32, u64, i64, i128,
+            u128
         );
     };
     (impl $imp:ident, $method:ident, $($t:ty),*) => {
@@ -10,8 +10,8 @@
 use core::ops::{Mul, MulAssign};
 
 use crate::{
-    binops::mul_rounded::checked_mul_rounded, Decimal, DecimalError,
-    MAX_N_FRAC_DIGITS,
+    binops::{checked_mul::CheckedMul, mul_rounded::checked_mul_rounded},
+    Decimal, DecimalError, MAX_N_FRAC_DIGITS,
 };
 
 impl Mul<Self> for Decimal {
@@ -151,6 +151,46 @@ macro_rules! impl_mul_decimal_and_int {
 impl_mul_decimal_and_int!();
 forward_ref_binop_decimal_int!(impl Mul, mul);
 
+impl Mul<u128> for Decimal {
+    type Output = Self;
+
+    fn mul(self, rhs: u128) -> Self::Output {
+        CheckedMul::checked_mul(self, rhs)
+            .unwrap_or_else(|| panic!("{}", DecimalError::InternalOverflow))
+    }
+}
+
+impl Mul<Decimal> for u128 {
+    type Output = Decimal;
+
+    #[inline(always)]
+    fn mul(self, rhs: Decimal) -> Self::Output {
+        rhs * self
+    }
+}
+
+#[cfg(test)]
+mod mul_decimal_u128_tests {
+    use super::*;
+
+    #[test]
+    fn test_mul_u128() {
+        let x = Decimal::new_raw(-1234567, 3);
+        assert_eq!(x * 7_u128, Decimal::new_raw(-8641969, 3));
+        assert_eq!(&7_u128 * x, Decimal::new_raw(-8641969, 3));
+        assert_eq!(Decimal::ZERO * u128::MAX, Decimal::ZERO);
+        let mut y = Decimal::ONE;
+        y *= i128::MAX as u128;
+        assert_eq!(y, Decimal::MAX);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_mul_u128_overflow() {
+        let _ = u128::MAX * Decimal::new_raw(1, 18);
+    }
+}
+
 #[cfg(test)]
 #[allow(clippy::neg_multiply)]
 mod mul_integer_tests {
@@ -14,7 +14,7 @@ use core::{
 
 use fpdec_core::checked_mul_pow_ten;
 
-use crate::{Decimal, DecimalError};
+use crate::{binops::checked_rem::CheckedRem, Decimal, DecimalError};
 
 #[inline]
 pub(crate) fn rem(
@@ -240,6 +240,56 @@ macro_rules! impl_rem_decimal_and_int {
 impl_rem_decimal_and_int!();
 forward_ref_binop_decimal_int!(impl Rem, rem);
 
+impl Rem<u128> for Decimal {
+    type Output = Self;
+
+    fn rem(self, rhs: u128) -> Self::Output {
+        #[allow(clippy::manual_assert)]
+        if rhs == 0 {
+            panic!("{}", DecimalError::DivisionByZero);
+        }
+        CheckedRem::checked_rem(self, rhs)
+            .unwrap_or_else(|| panic!("{}", DecimalError::InternalOverflow))
+    }
+}
+
+impl Rem<Decimal> for u128 {
+    type Output = Decimal;
+
+    fn rem(self, rhs: Decimal) -> Self::Output {
+        #[allow(clippy::manual_assert)]
+        if rhs.eq_zero() {
+            panic!("{}", DecimalError::DivisionByZero);
+        }
+        CheckedRem::checked_rem(self, rhs)
+            .unwrap_or_else(|| panic!("{}", DecimalError::InternalOverflow))
+    }
+}
+
+#[cfg(test)]
+mod rem_decimal_u128_tests {
+    use super::*;
+
+    const BIG: u128 = 1_u128 << 127;
+
+    #[test]
+    fn test_rem_u128() {
+        let x = Decimal::new_raw(-1234567, 3);
+        assert_eq!(x % 7_u128, Decimal::new_raw(-2567, 3));
+        assert_eq!(x % BIG, x);
+        assert_eq!(&BIG % x, Decimal::new_raw(524859, 3));
+        let mut y = Decimal::new_raw(-1234567, 3);
+        y %= &BIG;
+        assert_eq!(y, x);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_rem_u128_by_zero() {
+        let _ = BIG % Decimal::ZERO;
+    }
+}
+
 #[cfg(test)]
 #[allow(clippy::neg_multiply)]
 mod rem_integer_tests {
@@ -0,0 +1,646 @@
+// ---------------------------------------------------------------------------
+// Copyright:   (c) 2026 ff. Michael Amrhein (michael@adrhinum.de)
+// License:     This program is part of a larger application. For license
+//              details please read the file LICENSE.TXT provided together
+//              with the application.
+// ---------------------------------------------------------------------------
+// $Source$
+// $Revision$
+
+use alloc::{vec, vec::Vec};
+
+use fpdec_core::{
+    checked_mul_pow_ten, i128_div_rounded, ten_pow, MAX_N_FRAC_DIGITS,
+};
+
+use crate::{Decimal, FieldCodecError};
+
+const SIGN_POSITIVE: u8 = 0xc;
+const SIGN_NEGATIVE: u8 = 0xd;
+const SIGN_UNSIGNED: u8 = 0xf;
+
+const EBCDIC_ZONE_UNSIGNED: u8 = 0xf0;
+
+const ASCII_POSITIVE_ZERO: u8 = b'{';
+const ASCII_NEGATIVE_ZERO: u8 = b'}';
+
+/// Returns the absolute value of the coefficient of `d` adjusted to
+/// `n_frac_digits` and a flag indicating whether `d` is negative, provided
+/// the value fits into `n_digits` digits.
+pub(crate) fn scaled_abs_coeff(
+    d: Decimal,
+    n_frac_digits: u8,
+    n_digits: usize,
+) -> Result<(bool, u128), FieldCodecError> {
+    if n_frac_digits > MAX_N_FRAC_DIGITS {
+        return Err(FieldCodecError::MaxNFracDigitsExceeded);
+    }
+    let coeff = if n_frac_digits >= d.n_frac_digits {
+        checked_mul_pow_ten(d.coeff, n_frac_digits - d.n_frac_digits)
+            .ok_or(FieldCodecError::FieldOverflow)?
+    } else {
+        i128_div_rounded(
+            d.coeff,
+            ten_pow(d.n_frac_digits - n_frac_digits),
+            None,
+        )
+    };
+    let abs_coeff = coeff.unsigned_abs();
+    // any coefficient fits into more than 38 digits
+    if let Ok(n_digits @ 0..=38) = u8::try_from(n_digits) {
+        if abs_coeff >= ten_pow(n_digits).unsigned_abs() {
+            return Err(FieldCodecError::FieldOverflow);
+        }
+    }
+    Ok((coeff < 0, abs_coeff))
+}
+
+/// Accumulates the given digits into a `Decimal` with `n_frac_digits`
+/// fractional digits.
+pub(crate) fn decimal_from_digits(
+    digits: impl Iterator<Item = u8>,
+    is_negative: bool,
+    n_frac_digits: u8,
+) -> Result<Decimal, FieldCodecError> {
+    let mut coeff = 0_i128;
+    for digit in digits {
+        coeff = coeff
+            .checked_mul(10)
+            .and_then(|coeff| coeff.checked_add(i128::from(digit)))
+            .ok_or(FieldCodecError::ValueOutOfRange)?;
+    }
+    if is_negative {
+        coeff = -coeff;
+    }
+    Ok(Decimal::new_raw(coeff, n_frac_digits))
+}
+
+#[inline]
+const fn check_digit(digit: u8) -> Result<u8, FieldCodecError> {
+    if digit <= 9 {
+        Ok(digit)
+    } else {
+        Err(FieldCodecError::InvalidDigit)
+    }
+}
+
+/// Splits `bytes` into the leading bytes and the last byte, checking the
+/// number of fractional digits.
+const fn split_field(
+    bytes: &[u8],
+    n_frac_digits: u8,
+) -> Result<(&[u8], u8), FieldCodecError> {
+    if n_frac_digits > MAX_N_FRAC_DIGITS {
+        return Err(FieldCodecError::MaxNFracDigitsExceeded);
+    }
+    match bytes.split_last() {
+        Some((last, leading)) => Ok((leading, *last)),
+        None => Err(FieldCodecError::InvalidLength),
+    }
+}
+
+/// Returns the zoned representation of the absolute value `abs_coeff` with
+/// `len` digits, each digit being or'ed with `zone`. The sign is not set.
+#[allow(clippy::cast_possible_truncation)]
+pub(crate) fn zoned_digits(
+    mut abs_coeff: u128,
+    len: usize,
+    zone: u8,
+) -> Vec<u8> {
+    let mut bytes = vec![zone; len];
+    for byte in bytes.iter_mut().rev() {
+        // abs_coeff % 10 < 10, so the cast is safe
+        *byte |= (abs_coeff % 10) as u8;
+        abs_coeff /= 10;
+    }
+    bytes
+}
+
+impl Decimal {
+    /// Converts the packed decimal (COMP-3) field `bytes` into a `Decimal`
+    /// with `n_frac_digits` fractional digits.
+    ///
+    /// Each byte holds two decimal digits, one per nibble, except the last
+    /// byte, whose lower nibble holds the sign: 0xC (positive), 0xD
+    /// (negative) or 0xF (unsigned).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error (wrapped in Result::Err) in the following cases:
+    /// * `bytes` is empty => `FieldCodecError::InvalidLength`,
+    /// * a digit nibble is not in the range 0..=9 =>
+    ///   `FieldCodecError::InvalidDigit`,
+    /// * the sign nibble is not one of 0xC, 0xD, 0xF =>
+    ///   `FieldCodecError::InvalidSign`,
+    /// * `n_frac_digits` > `MAX_N_FRAC_DIGITS` =>
+    ///   `FieldCodecError::MaxNFracDigitsExceeded`,
+    /// * the value exceeds the range of `Decimal` values =>
+    ///   `FieldCodecError::ValueOutOfRange`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fpdec::{Dec, Decimal, FieldCodecError};
+    /// # fn main() -> Result<(), FieldCodecError> {
+    /// let d = Decimal::from_packed_bcd(&[0x01, 0x23, 0x45, 0x6d], 2)?;
+    /// assert_eq!(d, Dec!(-1234.56));
+    /// # Ok(()) }
+    /// ```
+    pub fn from_packed_bcd(
+        bytes: &[u8],
+        n_frac_digits: u8,
+    ) -> Result<Self, FieldCodecError> {
+        let (leading, last) = split_field(bytes, n_frac_digits)?;
+        let is_negative = match last & 0x0f {
+            SIGN_POSITIVE | SIGN_UNSIGNED => false,
+            SIGN_NEGATIVE => true,
+            _ => return Err(FieldCodecError::InvalidSign),
+        };
+        let mut digits = Vec::with_capacity(2 * bytes.len() - 1);
+        for byte in leading {
+            digits.push(check_digit(byte >> 4)?);
+            digits.push(check_digit(byte & 0x0f)?);
+        }
+        digits.push(check_digit(last >> 4)?);
+        decimal_from_digits(digits.into_iter(), is_negative, n_frac_digits)
+    }
+
+    /// Converts `self` into a packed decimal (COMP-3) field of `len` bytes,
+    /// holding `2 * len - 1` digits, `n_frac_digits` of them being
+    /// fractional digits.
+    ///
+    /// If `self` has more than `n_frac_digits` fractional digits, it is
+    /// rounded according to the current default rounding mode. The sign is
+    /// encoded as 0xC (positive or zero) or 0xD (negative).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error (wrapped in Result::Err) in the following cases:
+    /// * `len` == 0 => `FieldCodecError::InvalidLength`,
+    /// * `n_frac_digits` > `MAX_N_FRAC_DIGITS` =>
+    ///   `FieldCodecError::MaxNFracDigitsExceeded`,
+    /// * the value does not fit into the field =>
+    ///   `FieldCodecError::FieldOverflow`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fpdec::{Dec, Decimal, FieldCodecError};
+    /// # fn main() -> Result<(), FieldCodecError> {
+    /// let bytes = Dec!(-1234.56).to_packed_bcd(4, 2)?;
+    /// assert_eq!(bytes, [0x01, 0x23, 0x45, 0x6d]);
+    /// # Ok(()) }
+    /// ```
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn to_packed_bcd(
+        self,
+        len: usize,
+        n_frac_digits: u8,
+    ) -> Result<Vec<u8>, FieldCodecError> {
+        if len == 0 {
+            return Err(FieldCodecError::InvalidLength);
+        }
+        let (is_negative, mut abs_coeff) =
+            scaled_abs_coeff(self, n_frac_digits, 2 * len - 1)?;
+        let sign = if is_negative && abs_coeff != 0 {
+            SIGN_NEGATIVE
+        } else {
+            SIGN_POSITIVE
+        };
+        let mut bytes = vec![0_u8; len];
+        // abs_coeff % 10 < 10, so the casts are safe
+        bytes[len - 1] = (((abs_coeff % 10) as u8) << 4) | sign;
+        abs_coeff /= 10;
+        for byte in bytes[..len - 1].iter_mut().rev() {
+            *byte = (abs_coeff % 10) as u8;
+            abs_coeff /= 10;
+            *byte |= ((abs_coeff % 10) as u8) << 4;
+            abs_coeff /= 10;
+        }
+        Ok(bytes)
+    }
+
+    /// Converts the EBCDIC zoned decimal field `bytes` into a `Decimal` with
+    /// `n_frac_digits` fractional digits.
+    ///
+    /// Each byte holds one decimal digit in its lower nibble, the upper
+    /// nibble (zone) being 0xF, except for the last byte, whose zone holds
+    /// the sign: 0xC (positive), 0xD (negative) or 0xF (unsigned).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error (wrapped in Result::Err) in the following cases:
+    /// * `bytes` is empty => `FieldCodecError::InvalidLength`,
+    /// * a byte does not encode a digit => `FieldCodecError::InvalidDigit`,
+    /// * the zone of the last byte is not one of 0xC, 0xD, 0xF =>
+    ///   `FieldCodecError::InvalidSign`,
+    /// * `n_frac_digits` > `MAX_N_FRAC_DIGITS` =>
+    ///   `FieldCodecError::MaxNFracDigitsExceeded`,
+    /// * the value exceeds the range of `Decimal` values =>
+    ///   `FieldCodecError::ValueOutOfRange`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fpdec::{Dec, Decimal, FieldCodecError};
+    /// # fn main() -> Result<(), FieldCodecError> {
+    /// let d = Decimal::from_zoned_ebcdic(&[0xf0, 0xf1, 0xf2, 0xd5], 1)?;
+    /// assert_eq!(d, Dec!(-12.5));
+    /// # Ok(()) }
+    /// ```
+    pub fn from_zoned_ebcdic(
+        bytes: &[u8],
+        n_frac_digits: u8,
+    ) -> Result<Self, FieldCodecError> {
+        let (leading, last) = split_field(bytes, n_frac_digits)?;
+        let is_negative = match last >> 4 {
+            SIGN_POSITIVE | SIGN_UNSIGNED => false,
+            SIGN_NEGATIVE => true,
+            _ => return Err(FieldCodecError::InvalidSign),
+        };
+        let mut digits = Vec::with_capacity(bytes.len());
+        for byte in leading {
+            if byte & 0xf0 != EBCDIC_ZONE_UNSIGNED {
+                return Err(FieldCodecError::InvalidDigit);
+            }
+            digits.push(check_digit(byte & 0x0f)?);
+        }
+        digits.push(check_digit(last & 0x0f)?);
+        decimal_from_digits(digits.into_iter(), is_negative, n_frac_digits)
+    }
+
+    /// Converts `self` into an EBCDIC zoned decimal field of `len` bytes,
+    /// holding `len` digits, `n_frac_digits` of them being fractional
+    /// digits.
+    ///
+    /// If `self` has more than `n_frac_digits` fractional digits, it is
+    /// rounded according to the current default rounding mode. The sign is
+    /// encoded in the zone of the last byte as 0xC (positive or zero) or
+    /// 0xD (negative).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error (wrapped in Result::Err) in the following cases:
+    /// * `len` == 0 => `FieldCodecError::InvalidLength`,
+    /// * `n_frac_digits` > `MAX_N_FRAC_DIGITS` =>
+    ///   `FieldCodecError::MaxNFracDigitsExceeded`,
+    /// * the value does not fit into the field =>
+    ///   `FieldCodecError::FieldOverflow`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fpdec::{Dec, Decimal, FieldCodecError};
+    /// # fn main() -> Result<(), FieldCodecError> {
+    /// let bytes = Dec!(-12.5).to_zoned_ebcdic(4, 1)?;
+    /// assert_eq!(bytes, [0xf0, 0xf1, 0xf2, 0xd5]);
+    /// # Ok(()) }
+    /// ```
+    pub fn to_zoned_ebcdic(
+        self,
+        len: usize,
+        n_frac_digits: u8,
+    ) -> Result<Vec<u8>, FieldCodecError> {
+        if len == 0 {
+            return Err(FieldCodecError::InvalidLength);
+        }
+        let (is_negative, abs_coeff) =
+            scaled_abs_coeff(self, n_frac_digits, len)?;
+        let mut bytes = zoned_digits(abs_coeff, len, EBCDIC_ZONE_UNSIGNED);
+        let sign = if is_negative && abs_coeff != 0 {
+            SIGN_NEGATIVE
+        } else {
+            SIGN_POSITIVE
+        };
+        bytes[len - 1] = (sign << 4) | (bytes[len - 1] & 0x0f);
+        Ok(bytes)
+    }
+
+    /// Converts the ASCII zoned decimal field `bytes` into a `Decimal` with
+    /// `n_frac_digits` fractional digits.
+    ///
+    /// Each byte holds one decimal digit as ASCII character, except for the
+    /// last byte, which may hold the sign overpunched: '{' and 'A' to 'I'
+    /// denote the positive digits 0 to 9, '}' and 'J' to 'R' the negative
+    /// digits 0 to 9. A plain digit as last byte denotes an unsigned value.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error (wrapped in Result::Err) in the following cases:
+    /// * `bytes` is empty => `FieldCodecError::InvalidLength`,
+    /// * a byte other than the last one is not an ASCII digit =>
+    ///   `FieldCodecError::InvalidDigit`,
+    /// * the last byte is neither an ASCII digit nor an overpunched digit =>
+    ///   `FieldCodecError::InvalidSign`,
+    /// * `n_frac_digits` > `MAX_N_FRAC_DIGITS` =>
+    ///   `FieldCodecError::MaxNFracDigitsExceeded`,
+    /// * the value exceeds the range of `Decimal` values =>
+    ///   `FieldCodecError::ValueOutOfRange`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fpdec::{Dec, Decimal, FieldCodecError};
+    /// # fn main() -> Result<(), FieldCodecError> {
+    /// let d = Decimal::from_zoned_ascii(b"0012N", 2)?;
+    /// assert_eq!(d, Dec!(-1.25));
+    /// # Ok(()) }
+    /// ```
+    pub fn from_zoned_ascii(
+        bytes: &[u8],
+        n_frac_digits: u8,
+    ) -> Result<Self, FieldCodecError> {
+        let (leading, last) = split_field(bytes, n_frac_digits)?;
+        let (is_negative, last_digit) = match last {
+            b'0'..=b'9' => (false, last - b'0'),
+            ASCII_POSITIVE_ZERO => (false, 0),
+            b'A'..=b'I' => (false, last - b'A' + 1),
+            ASCII_NEGATIVE_ZERO => (true, 0),
+            b'J'..=b'R' => (true, last - b'J' + 1),
+            _ => return Err(FieldCodecError::InvalidSign),
+        };
+        let mut digits = Vec::with_capacity(bytes.len());
+        for byte in leading {
+            if !byte.is_ascii_digit() {
+                return Err(FieldCodecError::InvalidDigit);
+            }
+            digits.push(byte - b'0');
+        }
+        digits.push(last_digit);
+        decimal_from_digits(digits.into_iter(), is_negative, n_frac_digits)
+    }
+
+    /// Converts `self` into an ASCII zoned decimal field of `len` bytes,
+    /// holding `len` digits, `n_frac_digits` of them being fractional
+    /// digits.
+    ///
+    /// If `self` has more than `n_frac_digits` fractional digits, it is
+    /// rounded according to the current default rounding mode. The sign is
+    /// overpunched on the last digit: '{' and 'A' to 'I' for positive
+    /// values or zero, '}' and 'J' to 'R' for negative values.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error (wrapped in Result::Err) in the following cases:
+    /// * `len` == 0 => `FieldCodecError::InvalidLength`,
+    /// * `n_frac_digits` > `MAX_N_FRAC_DIGITS` =>
+    ///   `FieldCodecError::MaxNFracDigitsExceeded`,
+    /// * the value does not fit into the field =>
+    ///   `FieldCodecError::FieldOverflow`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fpdec::{Dec, Decimal, FieldCodecError};
+    /// # fn main() -> Result<(), FieldCodecError> {
+    /// let bytes = Dec!(-1.25).to_zoned_ascii(5, 2)?;
+    /// assert_eq!(bytes, b"0012N");
+    /// # Ok(()) }
+    /// ```
+    pub fn to_zoned_ascii(
+        self,
+        len: usize,
+        n_frac_digits: u8,
+    ) -> Result<Vec<u8>, FieldCodecError> {
+        if len == 0 {
+            return Err(FieldCodecError::InvalidLength);
+        }
+        let (is_negative, abs_coeff) =
+            scaled_abs_coeff(self, n_frac_digits, len)?;
+        let mut bytes = zoned_digits(abs_coeff, len, b'0');
+        let last_digit = bytes[len - 1] - b'0';
+        bytes[len - 1] = match (is_negative && abs_coeff != 0, last_digit) {
+            (false, 0) => ASCII_POSITIVE_ZERO,
+            (false, digit) => b'A' + digit - 1,
+            (true, 0) => ASCII_NEGATIVE_ZERO,
+            (true, digit) => b'J' + digit - 1,
+        };
+        Ok(bytes)
+    }
+}
+
+#[cfg(test)]
+mod packed_bcd_tests {
+    use super::*;
+
+    #[test]
+    fn test_from_packed_bcd() {
+        let d = Decimal::from_packed_bcd(&[0x0c], 0).unwrap();
+        assert_eq!(d, Decimal::ZERO);
+        let d = Decimal::from_packed_bcd(&[0x12, 0x3c], 0).unwrap();
+        assert_eq!(d, Decimal::new_raw(123, 0));
+        let d = Decimal::from_packed_bcd(&[0x12, 0x3f], 3).unwrap();
+        assert_eq!(d.coefficient(), 123);
+        assert_eq!(d.n_frac_digits(), 3);
+        let d = Decimal::from_packed_bcd(&[0x00, 0x00, 0x5d], 2).unwrap();
+        assert_eq!(d.coefficient(), -5);
+        assert_eq!(d.n_frac_digits(), 2);
+        let d = Decimal::from_packed_bcd(&[0x99; 19], 0);
+        assert_eq!(d, Err(FieldCodecError::InvalidSign));
+        let mut bytes = [0x99_u8; 19];
+        bytes[18] = 0x9c;
+        let d = Decimal::from_packed_bcd(&bytes, 18).unwrap();
+        assert_eq!(d.coefficient(), 10_i128.pow(37) - 1);
+        assert_eq!(d.n_frac_digits(), 18);
+    }
+
+    #[test]
+    fn test_from_packed_bcd_invalid() {
+        assert_eq!(
+            Decimal::from_packed_bcd(&[], 0),
+            Err(FieldCodecError::InvalidLength)
+        );
+        assert_eq!(
+            Decimal::from_packed_bcd(&[0x1a, 0x2c], 0),
+            Err(FieldCodecError::InvalidDigit)
+        );
+        assert_eq!(
+            Decimal::from_packed_bcd(&[0x12, 0xfc], 0),
+            Err(FieldCodecError::InvalidDigit)
+        );
+        for sign in [0x0_u8, 0x9, 0xa, 0xb, 0xe] {
+            assert_eq!(
+                Decimal::from_packed_bcd(&[0x12, 0x30 | sign], 0),
+                Err(FieldCodecError::InvalidSign)
+            );
+        }
+        assert_eq!(
+            Decimal::from_packed_bcd(&[0x12, 0x3c], 19),
+            Err(FieldCodecError::MaxNFracDigitsExceeded)
+        );
+        let mut bytes = [0x99_u8; 20];
+        bytes[19] = 0x9d;
+        assert_eq!(
+            Decimal::from_packed_bcd(&bytes, 0),
+            Err(FieldCodecError::ValueOutOfRange)
+        );
+    }
+
+    #[test]
+    fn test_to_packed_bcd() {
+        let d = Decimal::ZERO;
+        assert_eq!(d.to_packed_bcd(1, 0).unwrap(), [0x0c]);
+        assert_eq!(d.to_packed_bcd(3, 2).unwrap(), [0x00, 0x00, 0x0c]);
+        let d = Decimal::new_raw(-123, 0);
+        assert_eq!(d.to_packed_bcd(2, 0).unwrap(), [0x12, 0x3d]);
+        let d = Decimal::new_raw(12_345, 3);
+        assert_eq!(d.to_packed_bcd(3, 3).unwrap(), [0x12, 0x34, 0x5c]);
+        assert_eq!(d.to_packed_bcd(4, 4).unwrap(), [0x01, 0x23, 0x45, 0x0c]);
+        // rounded half to even by default
+        assert_eq!(d.to_packed_bcd(3, 2).unwrap(), [0x01, 0x23, 0x4c]);
+        let d = Decimal::new_raw(-4, 3);
+        assert_eq!(d.to_packed_bcd(2, 2).unwrap(), [0x00, 0x0c]);
+        let d = Decimal::MAX;
+        let bytes = d.to_packed_bcd(20, 0).unwrap();
+        assert_eq!(Decimal::from_packed_bcd(&bytes, 0), Ok(d));
+    }
+
+    #[test]
+    fn test_to_packed_bcd_invalid() {
+        let d = Decimal::new_raw(12_345, 2);
+        assert_eq!(
+            d.to_packed_bcd(0, 0),
+            Err(FieldCodecError::InvalidLength)
+        );
+        assert_eq!(
+            d.to_packed_bcd(2, 1),
+            Err(FieldCodecError::FieldOverflow)
+        );
+        assert_eq!(
+            d.to_packed_bcd(3, 19),
+            Err(FieldCodecError::MaxNFracDigitsExceeded)
+        );
+        assert_eq!(
+            Decimal::MAX.to_packed_bcd(20, 1),
+            Err(FieldCodecError::FieldOverflow)
+        );
+        let d = Decimal::new_raw(99_995, 4);
+        assert_eq!(
+            d.to_packed_bcd(1, 0),
+            Err(FieldCodecError::FieldOverflow)
+        );
+        assert_eq!(
+            d.to_packed_bcd(2, 2),
+            Err(FieldCodecError::FieldOverflow)
+        );
+        assert_eq!(d.to_packed_bcd(3, 2).unwrap(), [0x01, 0x00, 0x0c]);
+    }
+}
+
+#[cfg(test)]
+mod zoned_tests {
+    use super::*;
+
+    #[test]
+    fn test_from_zoned_ebcdic() {
+        let d = Decimal::from_zoned_ebcdic(&[0xf1, 0xf2, 0xc3], 0).unwrap();
+        assert_eq!(d, Decimal::new_raw(123, 0));
+        let d = Decimal::from_zoned_ebcdic(&[0xf1, 0xf2, 0xf3], 1).unwrap();
+        assert_eq!(d, Decimal::new_raw(123, 1));
+        let d = Decimal::from_zoned_ebcdic(&[0xf0, 0xf0, 0xd7], 2).unwrap();
+        assert_eq!(d.coefficient(), -7);
+        assert_eq!(d.n_frac_digits(), 2);
+        assert_eq!(
+            Decimal::from_zoned_ebcdic(&[], 0),
+            Err(FieldCodecError::InvalidLength)
+        );
+        assert_eq!(
+            Decimal::from_zoned_ebcdic(&[0xc1, 0xc2], 0),
+            Err(FieldCodecError::InvalidDigit)
+        );
+        assert_eq!(
+            Decimal::from_zoned_ebcdic(&[0xfa, 0xc2], 0),
+            Err(FieldCodecError::InvalidDigit)
+        );
+        assert_eq!(
+            Decimal::from_zoned_ebcdic(&[0xf1, 0xcb], 0),
+            Err(FieldCodecError::InvalidDigit)
+        );
+        assert_eq!(
+            Decimal::from_zoned_ebcdic(&[0xf1, 0xe2], 0),
+            Err(FieldCodecError::InvalidSign)
+        );
+        assert_eq!(
+            Decimal::from_zoned_ebcdic(&[0xf1, 0x32], 0),
+            Err(FieldCodecError::InvalidSign)
+        );
+    }
+
+    #[test]
+    fn test_to_zoned_ebcdic() {
+        let d = Decimal::new_raw(-123, 0);
+        assert_eq!(d.to_zoned_ebcdic(3, 0).unwrap(), [0xf1, 0xf2, 0xd3]);
+        let d = Decimal::new_raw(45, 1);
+        assert_eq!(
+            d.to_zoned_ebcdic(5, 2).unwrap(),
+            [0xf0, 0xf0, 0xf4, 0xf5, 0xc0]
+        );
+        assert_eq!(d.to_zoned_ebcdic(1, 0).unwrap(), [0xc4]);
+        assert_eq!(
+            d.to_zoned_ebcdic(1, 1),
+            Err(FieldCodecError::FieldOverflow)
+        );
+        assert_eq!(
+            d.to_zoned_ebcdic(0, 0),
+            Err(FieldCodecError::InvalidLength)
+        );
+    }
+
+    #[test]
+    fn test_from_zoned_ascii() {
+        let d = Decimal::from_zoned_ascii(b"123", 0).unwrap();
+        assert_eq!(d, Decimal::new_raw(123, 0));
+        let d = Decimal::from_zoned_ascii(b"12{", 1).unwrap();
+        assert_eq!(d, Decimal::new_raw(120, 1));
+        let d = Decimal::from_zoned_ascii(b"12}", 1).unwrap();
+        assert_eq!(d, Decimal::new_raw(-120, 1));
+        for c in b'A'..=b'I' {
+            let d = Decimal::from_zoned_ascii(&[b'5', c], 0).unwrap();
+            assert_eq!(d, Decimal::new_raw(51 + i128::from(c - b'A'), 0));
+        }
+        for c in b'J'..=b'R' {
+            let d = Decimal::from_zoned_ascii(&[b'5', c], 0).unwrap();
+            assert_eq!(d, Decimal::new_raw(-51 - i128::from(c - b'J'), 0));
+        }
+        assert_eq!(
+            Decimal::from_zoned_ascii(b"", 0),
+            Err(FieldCodecError::InvalidLength)
+        );
+        assert_eq!(
+            Decimal::from_zoned_ascii(b"1A2", 0),
+            Err(FieldCodecError::InvalidDigit)
+        );
+        assert_eq!(
+            Decimal::from_zoned_ascii(b" 12", 0),
+            Err(FieldCodecError::InvalidDigit)
+        );
+        assert_eq!(
+            Decimal::from_zoned_ascii(b"12S", 0),
+            Err(FieldCodecError::InvalidSign)
+        );
+        assert_eq!(
+            Decimal::from_zoned_ascii(b"12-", 0),
+            Err(FieldCodecError::InvalidSign)
+        );
+    }
+
+    #[test]
+    fn test_to_zoned_ascii() {
+        let d = Decimal::new_raw(-120, 1);
+        assert_eq!(d.to_zoned_ascii(4, 1).unwrap(), b"012}");
+        assert_eq!(d.to_zoned_ascii(4, 2).unwrap(), b"120}");
+        let d = Decimal::new_raw(987, 2);
+        assert_eq!(d.to_zoned_ascii(3, 2).unwrap(), b"98G");
+        assert_eq!(
+            d.to_zoned_ascii(3, 3),
+            Err(FieldCodecError::FieldOverflow)
+        );
+        let d = Decimal::new_raw(-1, 3);
+        assert_eq!(d.to_zoned_ascii(2, 2).unwrap(), b"0{");
+        for (coeff, s) in [(1_i128, b"0A"), (-29, b"2R"), (-30, b"3}")] {
+            let d = Decimal::new_raw(coeff, 0);
+            assert_eq!(d.to_zoned_ascii(2, 0).unwrap(), s);
+            assert_eq!(Decimal::from_zoned_ascii(s, 0), Ok(d));
+        }
+    }
+}
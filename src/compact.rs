@@ -0,0 +1,327 @@
+// ---------------------------------------------------------------------------
+// Copyright:   (c) 2026 ff. Michael Amrhein (michael@adrhinum.de)
+// License:     This program is part of a larger application. For license
+//              details please read the file LICENSE.TXT provided together
+//              with the application.
+// ---------------------------------------------------------------------------
+// $Source$
+// $Revision$
+
+use core::fmt;
+
+use fpdec_core::i128_magnitude;
+
+use crate::{
+    format::{
+        buf_as_str, pad_integral_parts, write_abs_to_buf, Part, MAX_STR_LEN,
+    },
+    Decimal, RoundingMode,
+};
+
+/// Number of digits to be shown in compact notation.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Digits {
+    Significant(u8),
+    Fractional(u8),
+}
+
+/// Wrapper formatting a `Decimal` in compact notation, i.e. scaled down by
+/// a power of 1000 and followed by a suffix like "K" or "M".
+///
+/// Created by [Decimal::compact]. The value is rounded to the configured
+/// number of significant (default: 3) or fractional digits, according to
+/// the current or an explicitly given [RoundingMode]. Trailing fractional
+/// zeros are removed. If rounding carries over into the next power of 1000,
+/// the next suffix is used.
+///
+/// # Examples:
+///
+/// ```rust
+/// # use fpdec::{Dec, Decimal, RoundingMode};
+/// assert_eq!(Dec!(1234).compact().to_string(), "1.23K");
+/// assert_eq!(Dec!(-3456789).compact().to_string(), "-3.46M");
+/// assert_eq!(Dec!(7000000000).compact().to_string(), "7B");
+/// assert_eq!(Dec!(999950).compact().frac_digits(1).to_string(), "1M");
+/// let c = Dec!(1250).compact().significant_digits(2);
+/// assert_eq!(c.to_string(), "1.2K");
+/// assert_eq!(c.rounding_mode(RoundingMode::RoundHalfUp).to_string(), "1.3K");
+/// const DE: &[&str] = &["", " Tsd.", " Mio.", " Mrd."];
+/// assert_eq!(Dec!(12500000).compact().suffixes(DE).to_string(), "12.5 Mio.");
+/// ```
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct CompactNotation {
+    value: Decimal,
+    suffixes: &'static [&'static str],
+    digits: Digits,
+    rounding_mode: Option<RoundingMode>,
+}
+
+impl CompactNotation {
+    /// Suffixes for powers of 1000 up to 10 ^ 12 in short scale.
+    pub const SHORT_SCALE: &'static [&'static str] =
+        &["", "K", "M", "B", "T"];
+
+    /// Returns `self` with the value rounded to `n` significant digits.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` equals zero!
+    #[must_use]
+    pub const fn significant_digits(mut self, n: u8) -> Self {
+        assert!(n > 0, "Number of significant digits must be > 0.");
+        self.digits = Digits::Significant(n);
+        self
+    }
+
+    /// Returns `self` with the scaled value rounded to `n` fractional
+    /// digits.
+    #[must_use]
+    pub const fn frac_digits(mut self, n: u8) -> Self {
+        self.digits = Digits::Fractional(n);
+        self
+    }
+
+    /// Returns `self` with the value rounded according to `mode` instead of
+    /// the current [RoundingMode].
+    #[must_use]
+    pub const fn rounding_mode(mut self, mode: RoundingMode) -> Self {
+        self.rounding_mode = Some(mode);
+        self
+    }
+
+    /// Returns `self` using `suffixes`, where the suffix at index `i` is
+    /// used for values scaled down by 1000 ^ `i`, instead of
+    /// [CompactNotation::SHORT_SCALE].
+    ///
+    /// Values exceeding the range covered by `suffixes` are scaled down by
+    /// the largest power of 1000 available.
+    #[must_use]
+    pub const fn suffixes(
+        mut self,
+        suffixes: &'static [&'static str],
+    ) -> Self {
+        self.suffixes = suffixes;
+        self
+    }
+
+    /// Returns the index of the suffix to be used for a value with the
+    /// given magnitude.
+    fn tier(&self, magnitude: i32) -> i32 {
+        let max_tier = i32::try_from(self.suffixes.len()).unwrap_or(i32::MAX);
+        magnitude.div_euclid(3).clamp(0, (max_tier - 1).max(0))
+    }
+}
+
+impl fmt::Display for CompactNotation {
+    fn fmt(&self, form: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let magnitude = i32::from(self.value.magnitude());
+        let mut tier = self.tier(magnitude);
+        let n_frac_digits = match self.digits {
+            Digits::Significant(n) => i32::from(n) - 1 - magnitude,
+            Digits::Fractional(n) => i32::from(n) - 3 * tier,
+        };
+        // -38 <= magnitude <= 38, so n_frac_digits can only exceed the range
+        // of i8 when more than MAX_N_FRAC_DIGITS fractional digits are
+        // requested, which then has no effect
+        let n_frac_digits = i8::try_from(n_frac_digits).unwrap_or(i8::MAX);
+        let (mut coeff, mut exp) =
+            self.value.rounded_coeff(n_frac_digits, self.rounding_mode);
+        if coeff == 0 {
+            return form.pad_integral(true, "", "0");
+        }
+        // rounding may have carried over into the next power of 1000
+        tier = self.tier(i32::from(i128_magnitude(coeff)) - i32::from(exp));
+        while coeff % 10 == 0 {
+            #[allow(clippy::integer_division)]
+            {
+                coeff /= 10;
+            }
+            exp -= 1;
+        }
+        let mut buf = [0_u8; MAX_STR_LEN];
+        let start = write_abs_to_buf(coeff, 0, 0, None, &mut buf);
+        let digits = buf_as_str(&buf, start);
+        let suffix = usize::try_from(tier)
+            .ok()
+            .and_then(|idx| self.suffixes.get(idx))
+            .unwrap_or(&"");
+        let n_frac_digits = i32::from(exp) + 3 * tier;
+        let parts = match usize::try_from(n_frac_digits) {
+            Ok(0) | Err(_) => [
+                Part::Str(digits),
+                // n_frac_digits <= 0
+                Part::Zeros(n_frac_digits.unsigned_abs() as usize),
+                Part::Str(""),
+                Part::Str(suffix),
+            ],
+            Ok(n) if n >= digits.len() => [
+                Part::Str("0."),
+                Part::Zeros(n - digits.len()),
+                Part::Str(digits),
+                Part::Str(suffix),
+            ],
+            Ok(n) => {
+                let (int, frac) = digits.split_at(digits.len() - n);
+                [
+                    Part::Str(int),
+                    Part::Str("."),
+                    Part::Str(frac),
+                    Part::Str(suffix),
+                ]
+            }
+        };
+        pad_integral_parts(form, coeff > 0, &parts)
+    }
+}
+
+impl Decimal {
+    /// Returns a wrapper formatting `self` in compact notation, e.g. "1.23K"
+    /// or "-3.46M".
+    ///
+    /// See [CompactNotation] for details.
+    #[must_use]
+    pub const fn compact(self) -> CompactNotation {
+        CompactNotation {
+            value: self,
+            suffixes: CompactNotation::SHORT_SCALE,
+            digits: Digits::Significant(3),
+            rounding_mode: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod compact_tests {
+    use alloc::{format, string::ToString};
+
+    use super::*;
+    use crate::Dec;
+
+    #[test]
+    fn test_significant_digits() {
+        for (d, s) in [
+            (Dec!(0), "0"),
+            (Dec!(0.000), "0"),
+            (Dec!(1), "1"),
+            (Dec!(-12.5), "-12.5"),
+            (Dec!(999), "999"),
+            (Dec!(999.5), "1K"),
+            (Dec!(1000), "1K"),
+            (Dec!(1234), "1.23K"),
+            (Dec!(-1235), "-1.24K"),
+            (Dec!(12345), "12.3K"),
+            (Dec!(123456), "123K"),
+            (Dec!(999500), "1M"),
+            (Dec!(3450000), "3.45M"),
+            (Dec!(7000000000), "7B"),
+            (Dec!(1234567890123), "1.23T"),
+            (Dec!(1234567890123456), "1230T"),
+            (Dec!(0.001234), "0.00123"),
+            (Dec!(-0.5), "-0.5"),
+        ] {
+            assert_eq!(d.compact().to_string(), s, "{d}");
+        }
+        for (d, n, s) in [
+            (Dec!(1234), 1, "1K"),
+            (Dec!(1534), 1, "2K"),
+            (Dec!(1234), 2, "1.2K"),
+            (Dec!(1234), 4, "1.234K"),
+            (Dec!(1234), 7, "1.234K"),
+            (Dec!(123456789), 5, "123.46M"),
+        ] {
+            assert_eq!(
+                d.compact().significant_digits(n).to_string(),
+                s,
+                "{d} {n}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_frac_digits() {
+        for (d, n, s) in [
+            (Dec!(1234), 0, "1K"),
+            (Dec!(1234), 1, "1.2K"),
+            (Dec!(1250), 1, "1.2K"),
+            (Dec!(1350), 1, "1.4K"),
+            (Dec!(999949), 1, "999.9K"),
+            (Dec!(999950), 1, "1M"),
+            (Dec!(-999950), 2, "-999.95K"),
+            (Dec!(999.96), 1, "1K"),
+            (Dec!(0.04), 1, "0"),
+            (Dec!(-0.04), 1, "0"),
+            (Dec!(12.345), 2, "12.34"),
+            (Dec!(12.345), 18, "12.345"),
+            (Dec!(123456789), 0, "123M"),
+        ] {
+            assert_eq!(d.compact().frac_digits(n).to_string(), s, "{d} {n}");
+        }
+    }
+
+    #[test]
+    fn test_rounding_mode() {
+        let d = Dec!(-1250);
+        for (mode, s) in [
+            (RoundingMode::RoundHalfEven, "-1.2K"),
+            (RoundingMode::RoundHalfUp, "-1.3K"),
+            (RoundingMode::RoundCeiling, "-1.2K"),
+            (RoundingMode::RoundFloor, "-1.3K"),
+            (RoundingMode::RoundDown, "-1.2K"),
+            (RoundingMode::RoundUp, "-1.3K"),
+        ] {
+            assert_eq!(
+                d.compact()
+                    .significant_digits(2)
+                    .rounding_mode(mode)
+                    .to_string(),
+                s,
+                "{mode:?}"
+            );
+        }
+        let c = Dec!(999001).compact().rounding_mode(RoundingMode::RoundUp);
+        assert_eq!(c.to_string(), "1M");
+    }
+
+    #[test]
+    fn test_suffixes() {
+        const DE: &[&str] = &["", " Tsd.", " Mio.", " Mrd."];
+        let c = Dec!(12500000).compact().suffixes(DE);
+        assert_eq!(c.to_string(), "12.5 Mio.");
+        let c = Dec!(999999999999).compact().suffixes(DE);
+        assert_eq!(c.to_string(), "1000 Mrd.");
+        let c = Dec!(12345).compact().suffixes(&[]);
+        assert_eq!(c.to_string(), "12300");
+        let c = Dec!(12345).compact().suffixes(&[""]);
+        assert_eq!(c.to_string(), "12300");
+    }
+
+    #[test]
+    fn test_limits() {
+        assert_eq!(
+            Decimal::MAX.compact().to_string(),
+            "170000000000000000000000000T"
+        );
+        assert_eq!(
+            Decimal::MIN
+                .compact()
+                .significant_digits(1)
+                .rounding_mode(RoundingMode::RoundUp)
+                .to_string(),
+            "-200000000000000000000000000T"
+        );
+        let d = Decimal::new_raw(i128::MAX, 18);
+        assert_eq!(d.compact().to_string(), "170000000T");
+        let d = Decimal::new_raw(1, 18);
+        assert_eq!(d.compact().to_string(), "0.000000000000000001");
+        assert_eq!(d.compact().frac_digits(2).to_string(), "0");
+    }
+
+    #[test]
+    fn test_formatter_flags() {
+        let c = Dec!(1234).compact();
+        assert_eq!(format!("{c:>8}"), "   1.23K");
+        assert_eq!(format!("{c:<8}|"), "1.23K   |");
+        assert_eq!(format!("{c:+}"), "+1.23K");
+        assert_eq!(format!("{:08}", Dec!(-1234).compact()), "-001.23K");
+    }
+}
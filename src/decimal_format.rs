@@ -0,0 +1,398 @@
+// ---------------------------------------------------------------------------
+// Copyright:   (c) 2026 ff. Michael Amrhein (michael@adrhinum.de)
+// License:     This program is part of a larger application. For license
+//              details please read the file LICENSE.TXT provided together
+//              with the application.
+// ---------------------------------------------------------------------------
+// $Source$
+// $Revision$
+
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+use core::{
+    cmp::{max, min},
+    fmt::{self, Write},
+};
+
+use crate::{
+    format::abs_int_and_frac, Decimal, RoundingMode, MAX_N_FRAC_DIGITS,
+};
+
+/// Pattern used to group the digits of the integral part of a number.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Grouping {
+    /// No grouping at all, e.g. `1234567`.
+    None,
+    /// Groups of three digits, e.g. `1,234,567`.
+    Thousands,
+    /// Indian grouping: the last three digits, then groups of two digits,
+    /// e.g. `12,34,567`.
+    Indian,
+}
+
+impl Grouping {
+    /// Returns true, if a separator has to be placed in front of a digit
+    /// followed by `n_remaining` digits.
+    #[inline]
+    const fn is_group_start(self, n_remaining: usize) -> bool {
+        match self {
+            Self::None => false,
+            Self::Thousands => n_remaining > 0 && n_remaining % 3 == 0,
+            Self::Indian => {
+                n_remaining == 3 || (n_remaining > 3 && n_remaining % 2 == 1)
+            }
+        }
+    }
+}
+
+/// Placement of the sign of a formatted number.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum SignPlacement {
+    /// Leading '-' for negative numbers, no sign for other numbers.
+    Leading,
+    /// Leading '-' for negative numbers and '+' for other numbers.
+    LeadingAlways,
+    /// Trailing '-' for negative numbers, no sign for other numbers.
+    Trailing,
+    /// Negative numbers are enclosed in parentheses.
+    Parentheses,
+}
+
+/// Builder for formatting `Decimal` values with a configurable decimal
+/// separator, grouping of the integral digits, number of fractional digits,
+/// rounding mode and placement of the sign.
+///
+/// The number of fractional digits shown is the number of fractional digits
+/// of the value to be formatted, but at least `min_frac_digits` and at most
+/// `max_frac_digits`. Values with more fractional digits are rounded
+/// according to the rounding mode set (or the default rounding mode, if none
+/// is set). A value which gets rounded to zero is formatted without sign.
+///
+/// # Examples
+///
+/// ```rust
+/// # #[cfg(feature = "alloc")]
+/// # {
+/// # use fpdec::{Dec, Decimal, DecimalFormat, Grouping, SignPlacement};
+/// let de = DecimalFormat::new()
+///     .decimal_separator(',')
+///     .grouping_separator('.')
+///     .grouping(Grouping::Thousands)
+///     .min_frac_digits(2)
+///     .max_frac_digits(2);
+/// assert_eq!(de.format(Dec!(1234567.891)), "1.234.567,89");
+/// let en_in = DecimalFormat::new()
+///     .grouping(Grouping::Indian)
+///     .sign_placement(SignPlacement::Parentheses);
+/// assert_eq!(en_in.format(Dec!(-1234567.89)), "(12,34,567.89)");
+/// # }
+/// ```
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct DecimalFormat {
+    decimal_separator: char,
+    grouping_separator: char,
+    grouping: Grouping,
+    min_frac_digits: u8,
+    max_frac_digits: u8,
+    rounding_mode: Option<RoundingMode>,
+    sign_placement: SignPlacement,
+}
+
+impl Default for DecimalFormat {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl DecimalFormat {
+    /// Returns a new `DecimalFormat` with '.' as decimal separator, ',' as
+    /// grouping separator, no grouping, 0 to `MAX_N_FRAC_DIGITS` fractional
+    /// digits, the default rounding mode and a leading sign.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            decimal_separator: '.',
+            grouping_separator: ',',
+            grouping: Grouping::None,
+            min_frac_digits: 0,
+            max_frac_digits: MAX_N_FRAC_DIGITS,
+            rounding_mode: None,
+            sign_placement: SignPlacement::Leading,
+        }
+    }
+
+    /// Sets the character separating the integral and the fractional part.
+    #[must_use]
+    pub const fn decimal_separator(mut self, sep: char) -> Self {
+        self.decimal_separator = sep;
+        self
+    }
+
+    /// Sets the character separating groups of integral digits.
+    #[must_use]
+    pub const fn grouping_separator(mut self, sep: char) -> Self {
+        self.grouping_separator = sep;
+        self
+    }
+
+    /// Sets the pattern used to group the integral digits.
+    #[must_use]
+    pub const fn grouping(mut self, grouping: Grouping) -> Self {
+        self.grouping = grouping;
+        self
+    }
+
+    /// Sets the minimum number of fractional digits to be shown.
+    ///
+    /// `n` is limited to `MAX_N_FRAC_DIGITS`. The maximum number of
+    /// fractional digits is raised to `n`, if necessary.
+    #[must_use]
+    pub const fn min_frac_digits(mut self, n: u8) -> Self {
+        let n = if n > MAX_N_FRAC_DIGITS {
+            MAX_N_FRAC_DIGITS
+        } else {
+            n
+        };
+        self.min_frac_digits = n;
+        if self.max_frac_digits < n {
+            self.max_frac_digits = n;
+        }
+        self
+    }
+
+    /// Sets the maximum number of fractional digits to be shown.
+    ///
+    /// `n` is limited to `MAX_N_FRAC_DIGITS`. The minimum number of
+    /// fractional digits is lowered to `n`, if necessary.
+    #[must_use]
+    pub const fn max_frac_digits(mut self, n: u8) -> Self {
+        let n = if n > MAX_N_FRAC_DIGITS {
+            MAX_N_FRAC_DIGITS
+        } else {
+            n
+        };
+        self.max_frac_digits = n;
+        if self.min_frac_digits > n {
+            self.min_frac_digits = n;
+        }
+        self
+    }
+
+    /// Sets the rounding mode used when the value to be formatted has more
+    /// than the maximum number of fractional digits.
+    #[must_use]
+    pub const fn rounding_mode(mut self, mode: RoundingMode) -> Self {
+        self.rounding_mode = Some(mode);
+        self
+    }
+
+    /// Sets the placement of the sign.
+    #[must_use]
+    pub const fn sign_placement(mut self, placement: SignPlacement) -> Self {
+        self.sign_placement = placement;
+        self
+    }
+
+    /// Writes `d`, formatted according to `self`, to `w`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if writing to `w` fails.
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn write_to<W: Write>(&self, d: Decimal, w: &mut W) -> fmt::Result {
+        let n_frac_digits = min(
+            max(d.n_frac_digits, self.min_frac_digits),
+            self.max_frac_digits,
+        );
+        let (int, frac) = abs_int_and_frac(
+            d.coeff,
+            d.n_frac_digits,
+            n_frac_digits,
+            self.rounding_mode,
+        );
+        let is_negative = d.coeff < 0 && (int != 0 || frac != 0);
+        match (self.sign_placement, is_negative) {
+            (SignPlacement::Leading, true)
+            | (SignPlacement::LeadingAlways, true) => w.write_char('-')?,
+            (SignPlacement::LeadingAlways, false) => w.write_char('+')?,
+            (SignPlacement::Parentheses, true) => w.write_char('(')?,
+            _ => {}
+        }
+        // int <= i128::MAX, so it has at most 39 digits
+        let mut buf = [0_u8; 39];
+        let mut start = buf.len();
+        let mut int = int.unsigned_abs();
+        loop {
+            start -= 1;
+            // int % 10 < 10, so the cast is safe
+            buf[start] = b'0' + (int % 10) as u8;
+            int /= 10;
+            if int == 0 {
+                break;
+            }
+        }
+        let n_int_digits = buf.len() - start;
+        for (i, digit) in buf[start..].iter().enumerate() {
+            if i > 0 && self.grouping.is_group_start(n_int_digits - i) {
+                w.write_char(self.grouping_separator)?;
+            }
+            w.write_char(char::from(*digit))?;
+        }
+        if n_frac_digits > 0 {
+            w.write_char(self.decimal_separator)?;
+            write!(w, "{:0width$}", frac, width = n_frac_digits as usize)?;
+        }
+        match (self.sign_placement, is_negative) {
+            (SignPlacement::Trailing, true) => w.write_char('-'),
+            (SignPlacement::Parentheses, true) => w.write_char(')'),
+            _ => Ok(()),
+        }
+    }
+
+    /// Returns `d` formatted according to `self`.
+    ///
+    /// # Panics
+    ///
+    /// Never panics, as writing to a `String` can't fail.
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    pub fn format(&self, d: Decimal) -> String {
+        let mut s = String::new();
+        self.write_to(d, &mut s)
+            .expect("Writing to a String can't fail.");
+        s
+    }
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod decimal_format_tests {
+    use alloc::string::ToString;
+
+    use super::*;
+    use crate::Dec;
+
+    #[test]
+    fn test_default() {
+        let f = DecimalFormat::default();
+        assert_eq!(f, DecimalFormat::new());
+        for d in [
+            Decimal::ZERO,
+            Dec!(1234567.89),
+            Dec!(-0.000000000000000001),
+            Decimal::MAX,
+            Decimal::MIN,
+        ] {
+            assert_eq!(f.format(d), d.to_string());
+        }
+    }
+
+    #[test]
+    fn test_locales() {
+        let d = Dec!(1234567.89);
+        let de_de = DecimalFormat::new()
+            .decimal_separator(',')
+            .grouping_separator('.')
+            .grouping(Grouping::Thousands);
+        assert_eq!(de_de.format(d), "1.234.567,89");
+        let fr_ch = DecimalFormat::new()
+            .grouping_separator('\u{202f}')
+            .grouping(Grouping::Thousands);
+        assert_eq!(fr_ch.format(d), "1\u{202f}234\u{202f}567.89");
+        let en_in = DecimalFormat::new().grouping(Grouping::Indian);
+        assert_eq!(en_in.format(d), "12,34,567.89");
+    }
+
+    #[test]
+    fn test_grouping() {
+        let thousands = DecimalFormat::new().grouping(Grouping::Thousands);
+        let indian = DecimalFormat::new().grouping(Grouping::Indian);
+        for (d, t, i) in [
+            (Dec!(0), "0", "0"),
+            (Dec!(12), "12", "12"),
+            (Dec!(123), "123", "123"),
+            (Dec!(-1234), "-1,234", "-1,234"),
+            (Dec!(12345), "12,345", "12,345"),
+            (Dec!(123456), "123,456", "1,23,456"),
+            (Dec!(1234567), "1,234,567", "12,34,567"),
+            (Dec!(12345678), "12,345,678", "1,23,45,678"),
+            (
+                Dec!(123456789.0123),
+                "123,456,789.0123",
+                "12,34,56,789.0123",
+            ),
+        ] {
+            assert_eq!(thousands.format(d), t);
+            assert_eq!(indian.format(d), i);
+        }
+        assert_eq!(
+            thousands.format(Decimal::MAX),
+            "170,141,183,460,469,231,731,687,303,715,884,105,727"
+        );
+    }
+
+    #[test]
+    fn test_frac_digits() {
+        let f = DecimalFormat::new().min_frac_digits(2).max_frac_digits(4);
+        assert_eq!(f.format(Dec!(5)), "5.00");
+        assert_eq!(f.format(Dec!(5.1)), "5.10");
+        assert_eq!(f.format(Dec!(5.123)), "5.123");
+        assert_eq!(f.format(Dec!(5.12300)), "5.1230");
+        // rounded half to even by default
+        assert_eq!(f.format(Dec!(5.12345)), "5.1234");
+        assert_eq!(f.format(Dec!(-5.12355)), "-5.1236");
+        let f = f.max_frac_digits(0);
+        assert_eq!(f.format(Dec!(2.5)), "2");
+        assert_eq!(f.format(Dec!(3.5)), "4");
+        let f = DecimalFormat::new().max_frac_digits(1).min_frac_digits(3);
+        assert_eq!(f.format(Dec!(0.5)), "0.500");
+        let f = DecimalFormat::new().min_frac_digits(25);
+        assert_eq!(f.format(Dec!(1)), "1.000000000000000000");
+    }
+
+    #[test]
+    fn test_rounding_mode() {
+        let f = DecimalFormat::new().max_frac_digits(2);
+        let d = Dec!(-1.005);
+        for (mode, s) in [
+            (RoundingMode::RoundCeiling, "-1.00"),
+            (RoundingMode::RoundFloor, "-1.01"),
+            (RoundingMode::RoundHalfUp, "-1.01"),
+            (RoundingMode::RoundHalfDown, "-1.00"),
+            (RoundingMode::RoundHalfEven, "-1.00"),
+        ] {
+            assert_eq!(f.rounding_mode(mode).format(d), s);
+        }
+    }
+
+    #[test]
+    fn test_sign_placement() {
+        let f = DecimalFormat::new()
+            .grouping(Grouping::Thousands)
+            .max_frac_digits(2);
+        for (placement, neg, pos, zero) in [
+            (SignPlacement::Leading, "-1,234.57", "1,234.57", "0.00"),
+            (
+                SignPlacement::LeadingAlways,
+                "-1,234.57",
+                "+1,234.57",
+                "+0.00",
+            ),
+            (SignPlacement::Trailing, "1,234.57-", "1,234.57", "0.00"),
+            (SignPlacement::Parentheses, "(1,234.57)", "1,234.57", "0.00"),
+        ] {
+            let f = f.sign_placement(placement);
+            assert_eq!(f.format(Dec!(-1234.567)), neg);
+            assert_eq!(f.format(Dec!(1234.567)), pos);
+            // negative value rounded to zero
+            assert_eq!(f.format(Dec!(-0.001)), zero);
+        }
+    }
+
+    #[test]
+    fn test_write_to() {
+        let f = DecimalFormat::new().grouping(Grouping::Thousands);
+        let mut s = String::from("Total: ");
+        f.write_to(Dec!(-98765.4), &mut s).unwrap();
+        assert_eq!(s, "Total: -98,765.4");
+    }
+}
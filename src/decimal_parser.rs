@@ -0,0 +1,402 @@
+// ---------------------------------------------------------------------------
+// Copyright:   (c) 2026 ff. Michael Amrhein (michael@adrhinum.de)
+// License:     This program is part of a larger application. For license
+//              details please read the file LICENSE.TXT provided together
+//              with the application.
+// ---------------------------------------------------------------------------
+// $Source$
+// $Revision$
+
+use alloc::string::String;
+use core::str::FromStr;
+
+use crate::{Decimal, ParseDecimalError};
+
+/// Options controlling the parsing of number literals by a
+/// [`DecimalParser`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct ParseOptions {
+    /// Character separating the integral and the fractional part.
+    pub decimal_separator: char,
+    /// Character allowed to separate groups of integral digits, if any.
+    pub grouping_separator: Option<char>,
+    /// If true, groups of integral digits must consist of three digits
+    /// (except the first one, which may have one to three digits).
+    pub strict_grouping: bool,
+    /// If true, negative numbers may be enclosed in parentheses, e.g.
+    /// `(1,234.56)`.
+    pub allow_parentheses: bool,
+    /// If true, the sign may follow the number, e.g. `1234.56-`.
+    pub allow_trailing_sign: bool,
+    /// If true, currency symbols (like '$', '€', '£', '¥' or '₹') preceding
+    /// or following the number are ignored.
+    pub strip_currency_symbols: bool,
+    /// If true, leading and trailing whitespace is ignored.
+    pub trim_whitespace: bool,
+}
+
+impl Default for ParseOptions {
+    /// Returns options with '.' as decimal separator, ',' as (non-strict)
+    /// grouping separator, only a leading sign, no currency symbols and
+    /// whitespace trimming.
+    fn default() -> Self {
+        Self {
+            decimal_separator: '.',
+            grouping_separator: Some(','),
+            strict_grouping: false,
+            allow_parentheses: false,
+            allow_trailing_sign: false,
+            strip_currency_symbols: false,
+            trim_whitespace: true,
+        }
+    }
+}
+
+/// Returns true, if `c` is a currency symbol.
+const fn is_currency_symbol(c: char) -> bool {
+    matches!(
+        c,
+        '$' | '¢'
+            | '£'
+            | '¤'
+            | '¥'
+            | '\u{58f}'
+            | '\u{60b}'
+            | '\u{9f2}'
+            | '\u{9f3}'
+            | '\u{e3f}'
+            | '\u{17db}'
+            | '\u{20a0}'
+            ..='\u{20c0}'
+                | '\u{fdfc}'
+                | '\u{fe69}'
+                | '\u{ff04}'
+                | '\u{ffe0}'
+                | '\u{ffe1}'
+                | '\u{ffe5}'
+                | '\u{ffe6}'
+    )
+}
+
+/// Parser for number literals formatted according to locale specific or
+/// accounting conventions, configured by [`ParseOptions`].
+///
+/// The literal must consist of integral digits, optionally divided into
+/// groups by the grouping separator, optionally followed by the decimal
+/// separator and fractional digits. It may be preceded by a sign ('+' or
+/// '-') and - depending on the options - enclosed in parentheses, followed
+/// by a sign, and preceded or followed by currency symbols and whitespace.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fpdec::{Dec, Decimal, DecimalParser, ParseDecimalError, ParseOptions};
+/// # fn main() -> Result<(), ParseDecimalError> {
+/// let parser = DecimalParser::new(ParseOptions {
+///     decimal_separator: ',',
+///     grouping_separator: Some('.'),
+///     strict_grouping: true,
+///     strip_currency_symbols: true,
+///     ..ParseOptions::default()
+/// });
+/// assert_eq!(parser.parse("1.234,56 €")?, Dec!(1234.56));
+/// assert_eq!(parser.parse("-€1.234.567")?, Dec!(-1234567));
+/// let parser = DecimalParser::new(ParseOptions {
+///     allow_parentheses: true,
+///     allow_trailing_sign: true,
+///     ..ParseOptions::default()
+/// });
+/// assert_eq!(parser.parse("(1,234.56)")?, Dec!(-1234.56));
+/// assert_eq!(parser.parse("1234.56-")?, Dec!(-1234.56));
+/// # Ok(()) }
+/// ```
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct DecimalParser {
+    options: ParseOptions,
+}
+
+impl DecimalParser {
+    /// Returns a new `DecimalParser` using the given options.
+    #[must_use]
+    pub const fn new(options: ParseOptions) -> Self {
+        Self { options }
+    }
+
+    /// Returns the options used by `self`.
+    #[must_use]
+    pub const fn options(&self) -> &ParseOptions {
+        &self.options
+    }
+
+    /// Strips whitespace and currency symbols (depending on the options)
+    /// from the start of `lit`.
+    fn strip_start<'a>(&self, lit: &'a str) -> &'a str {
+        lit.trim_start_matches(|c: char| {
+            (self.options.trim_whitespace && c.is_whitespace())
+                || (self.options.strip_currency_symbols
+                    && is_currency_symbol(c))
+        })
+    }
+
+    /// Strips whitespace and currency symbols (depending on the options)
+    /// from the end of `lit`.
+    fn strip_end<'a>(&self, lit: &'a str) -> &'a str {
+        lit.trim_end_matches(|c: char| {
+            (self.options.trim_whitespace && c.is_whitespace())
+                || (self.options.strip_currency_symbols
+                    && is_currency_symbol(c))
+        })
+    }
+
+    /// Appends the integral digits in `int` to `buf`, checking the grouping.
+    fn push_int_digits(
+        &self,
+        int: &str,
+        buf: &mut String,
+    ) -> Result<(), ParseDecimalError> {
+        let sep = match self.options.grouping_separator {
+            Some(sep) if int.contains(sep) => sep,
+            _ => return push_digits(int, buf),
+        };
+        for (i, group) in int.split(sep).enumerate() {
+            let is_valid = if self.options.strict_grouping {
+                group.len() == 3 || (i == 0 && (1..3).contains(&group.len()))
+            } else {
+                !group.is_empty()
+            };
+            if !is_valid {
+                return Err(ParseDecimalError::Invalid);
+            }
+            push_digits(group, buf)?;
+        }
+        Ok(())
+    }
+
+    /// Converts the number literal `lit` into a `Decimal`, according to the
+    /// options of `self`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error (wrapped in Result::Err) in the following cases:
+    /// * `lit` is empty (after stripping whitespace and currency symbols) =>
+    ///   `ParseDecimalError::Empty`,
+    /// * `lit` does not fit the format described above =>
+    ///   `ParseDecimalError::Invalid`,
+    /// * the number of fractional digits in `lit` exceeds
+    ///   [crate::MAX_N_FRAC_DIGITS] =>
+    ///   `ParseDecimalError::FracDigitLimitExceeded`,
+    /// * the value exceeds the internal representation of `Decimal` =>
+    ///   `ParseDecimalError::InternalOverflow`.
+    pub fn parse(&self, lit: &str) -> Result<Decimal, ParseDecimalError> {
+        let mut lit = self.strip_end(self.strip_start(lit));
+        if lit.is_empty() {
+            return Err(ParseDecimalError::Empty);
+        }
+        let mut is_negative = false;
+        if self.options.allow_parentheses {
+            if let Some(inner) =
+                lit.strip_prefix('(').and_then(|s| s.strip_suffix(')'))
+            {
+                is_negative = true;
+                lit = self.strip_end(self.strip_start(inner));
+            }
+        }
+        let mut sign = None;
+        if let Some(c @ ('+' | '-')) = lit.chars().next() {
+            sign = Some(c);
+            lit = self.strip_start(&lit[1..]);
+        } else if self.options.allow_trailing_sign {
+            if let Some(c @ ('+' | '-')) = lit.chars().next_back() {
+                sign = Some(c);
+                lit = self.strip_end(&lit[..lit.len() - 1]);
+            }
+        }
+        match (is_negative, sign) {
+            (true, Some(_)) => return Err(ParseDecimalError::Invalid),
+            (false, Some('-')) => is_negative = true,
+            _ => {}
+        }
+        let (int, frac) = match lit.split_once(self.options.decimal_separator)
+        {
+            Some((int, frac)) => (int, frac),
+            None => (lit, ""),
+        };
+        if int.is_empty() && frac.is_empty() {
+            return Err(ParseDecimalError::Invalid);
+        }
+        let mut buf = String::with_capacity(lit.len() + 2);
+        if is_negative {
+            buf.push('-');
+        }
+        self.push_int_digits(int, &mut buf)?;
+        if !frac.is_empty() {
+            buf.push('.');
+            push_digits(frac, &mut buf)?;
+        }
+        Decimal::from_str(&buf)
+    }
+}
+
+/// Appends `digits` to `buf`, provided it consists only of ASCII digits.
+fn push_digits(
+    digits: &str,
+    buf: &mut String,
+) -> Result<(), ParseDecimalError> {
+    if digits.bytes().all(|b| b.is_ascii_digit()) {
+        buf.push_str(digits);
+        Ok(())
+    } else {
+        Err(ParseDecimalError::Invalid)
+    }
+}
+
+#[cfg(test)]
+mod decimal_parser_tests {
+    use super::*;
+    use crate::Dec;
+
+    #[test]
+    fn test_default() {
+        let parser = DecimalParser::default();
+        for (lit, d) in [
+            ("0", Dec!(0)),
+            ("-17.50", Dec!(-17.50)),
+            ("+.5", Dec!(0.5)),
+            ("5.", Dec!(5)),
+            ("  1,234,567.891  ", Dec!(1234567.891)),
+            ("12,34,567", Dec!(1234567)),
+        ] {
+            assert_eq!(parser.parse(lit), Ok(d), "{lit}");
+        }
+        for lit in [
+            "-", ".", "1..2", "1.2.3", "1,,234", ",123", "123,", "1.2,3",
+            "1e5", "(5)", "5-", "$5", "- -5", "0x10",
+        ] {
+            assert_eq!(
+                parser.parse(lit),
+                Err(ParseDecimalError::Invalid),
+                "{lit}"
+            );
+        }
+        assert_eq!(parser.parse(""), Err(ParseDecimalError::Empty));
+        assert_eq!(parser.parse("   "), Err(ParseDecimalError::Empty));
+    }
+
+    #[test]
+    fn test_locales() {
+        let de_de = DecimalParser::new(ParseOptions {
+            decimal_separator: ',',
+            grouping_separator: Some('.'),
+            ..ParseOptions::default()
+        });
+        assert_eq!(de_de.parse("1.234.567,89"), Ok(Dec!(1234567.89)));
+        assert_eq!(de_de.parse("-0,5"), Ok(Dec!(-0.5)));
+        assert_eq!(de_de.parse("1,234.5"), Err(ParseDecimalError::Invalid));
+        let fr_ch = DecimalParser::new(ParseOptions {
+            grouping_separator: Some('\u{a0}'),
+            ..ParseOptions::default()
+        });
+        assert_eq!(
+            fr_ch.parse("1\u{a0}234\u{a0}567.89"),
+            Ok(Dec!(1234567.89))
+        );
+        assert_eq!(fr_ch.parse("1,234"), Err(ParseDecimalError::Invalid));
+        let no_grouping = DecimalParser::new(ParseOptions {
+            grouping_separator: None,
+            ..ParseOptions::default()
+        });
+        assert_eq!(no_grouping.parse("1234.5"), Ok(Dec!(1234.5)));
+        assert_eq!(
+            no_grouping.parse("1,234.5"),
+            Err(ParseDecimalError::Invalid)
+        );
+    }
+
+    #[test]
+    fn test_strict_grouping() {
+        let parser = DecimalParser::new(ParseOptions {
+            strict_grouping: true,
+            ..ParseOptions::default()
+        });
+        for (lit, d) in [
+            ("1234567", Dec!(1234567)),
+            ("1,234,567", Dec!(1234567)),
+            ("12,345.6", Dec!(12345.6)),
+            ("123,456", Dec!(123456)),
+            ("-0.25", Dec!(-0.25)),
+        ] {
+            assert_eq!(parser.parse(lit), Ok(d), "{lit}");
+        }
+        for lit in
+            ["12,34,567", "1234,567", "1,23", "1,2345", ",123", "1,234,"]
+        {
+            assert_eq!(
+                parser.parse(lit),
+                Err(ParseDecimalError::Invalid),
+                "{lit}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_accounting() {
+        let parser = DecimalParser::new(ParseOptions {
+            allow_parentheses: true,
+            allow_trailing_sign: true,
+            strip_currency_symbols: true,
+            ..ParseOptions::default()
+        });
+        for (lit, d) in [
+            ("(1,234.56)", Dec!(-1234.56)),
+            ("( 1,234.56 )", Dec!(-1234.56)),
+            ("($1,234.56)", Dec!(-1234.56)),
+            ("$(1,234.56)", Dec!(-1234.56)),
+            ("1234.56-", Dec!(-1234.56)),
+            ("1234.56 -", Dec!(-1234.56)),
+            ("1234.56+", Dec!(1234.56)),
+            ("-$1,234.56", Dec!(-1234.56)),
+            ("$-1,234.56", Dec!(-1234.56)),
+            ("1,234.56 €", Dec!(1234.56)),
+            ("£0.99", Dec!(0.99)),
+            ("₹12,34,567", Dec!(1234567)),
+            ("¥ -500", Dec!(-500)),
+        ] {
+            assert_eq!(parser.parse(lit), Ok(d), "{lit}");
+        }
+        for lit in ["(-5)", "(5", "5)", "-5-", "+5-", "()", "$"] {
+            assert!(parser.parse(lit).is_err(), "{lit}");
+        }
+    }
+
+    #[test]
+    fn test_no_trimming() {
+        let parser = DecimalParser::new(ParseOptions {
+            trim_whitespace: false,
+            strip_currency_symbols: true,
+            ..ParseOptions::default()
+        });
+        assert_eq!(parser.parse("$12.5"), Ok(Dec!(12.5)));
+        assert_eq!(parser.parse(" 12.5"), Err(ParseDecimalError::Invalid));
+        assert_eq!(parser.parse("$ 12.5"), Err(ParseDecimalError::Invalid));
+    }
+
+    #[test]
+    fn test_limits() {
+        let parser = DecimalParser::default();
+        assert_eq!(
+            parser.parse("1.0000000000000000001"),
+            Err(ParseDecimalError::FracDigitLimitExceeded)
+        );
+        assert_eq!(
+            parser.parse(
+                "-170,141,183,460,469,231,731,687,303,715,884,105,727"
+            ),
+            Ok(Decimal::MIN)
+        );
+        assert_eq!(
+            parser
+                .parse("170,141,183,460,469,231,731,687,303,715,884,105,728"),
+            Err(ParseDecimalError::InternalOverflow)
+        );
+    }
+}
@@ -0,0 +1,580 @@
+// ---------------------------------------------------------------------------
+// Copyright:   (c) 2026 ff. Michael Amrhein (michael@adrhinum.de)
+// License:     This program is part of a larger application. For license
+//              details please read the file LICENSE.TXT provided together
+//              with the application.
+// ---------------------------------------------------------------------------
+// $Source$
+// $Revision$
+
+use core::{
+    fmt,
+    ops::{Add, Div, Mul, Neg, Sub},
+};
+
+use fpdec_core::i256_sum_of_prods_div_mod;
+
+use crate::{
+    as_integer_ratio::gcd,
+    format::{buf_as_str, write_abs_to_buf, MAX_STR_LEN},
+    AsIntegerRatio, Decimal, DecimalError, RoundingMode,
+};
+
+/// Exact ratio of two integers, used to defer rounding in a chain of
+/// arithmetic operations on `Decimal` values.
+///
+/// A `DecimalRatio` is always held in reduced form, i.e. numerator and
+/// denominator have no common factor and the denominator is positive.
+/// Multiplication, division, addition and subtraction - with another
+/// `DecimalRatio` or with a `Decimal` - give exact results. The result can
+/// be rounded to a `Decimal` via [DecimalRatio::to_decimal_rounded].
+///
+/// The operators panic if the numerator or the denominator of the result
+/// exceeds the range of `i128` (or in case of a division by zero). Use
+/// the corresponding `try_*` methods to get these cases reported as
+/// `DecimalError`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fpdec::{Dec, Decimal, DecimalError, RoundingMode};
+/// # fn main() -> Result<(), DecimalError> {
+/// let a = Dec!(10);
+/// let b = Dec!(3);
+/// // a / b is rounded ...
+/// assert_ne!(a / b * b, a);
+/// // ... but a.ratio(b) is exact
+/// let r = a.ratio(b)? * b;
+/// let d = r.to_decimal_rounded(2, RoundingMode::RoundHalfEven)?;
+/// assert_eq!(d, a);
+/// # Ok(()) }
+/// ```
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct DecimalRatio {
+    numer: i128,
+    denom: i128,
+}
+
+impl DecimalRatio {
+    /// Additive identity
+    pub const ZERO: Self = Self { numer: 0, denom: 1 };
+    /// Multiplicative identity
+    pub const ONE: Self = Self { numer: 1, denom: 1 };
+
+    /// Returns the reduced form of the ratio `numer / denom`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error (wrapped in Result::Err) in the following cases:
+    /// * `denom` == 0 => `DecimalError::DivisionByZero`,
+    /// * `numer` or `denom` equals `i128::MIN` =>
+    ///   `DecimalError::InternalOverflow`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fpdec::{AsIntegerRatio, DecimalError, DecimalRatio};
+    /// # fn main() -> Result<(), DecimalError> {
+    /// let r = DecimalRatio::new(12, -18)?;
+    /// assert_eq!(r.as_integer_ratio(), (-2, 3));
+    /// # Ok(()) }
+    /// ```
+    pub fn new(numer: i128, denom: i128) -> Result<Self, DecimalError> {
+        if denom == 0 {
+            return Err(DecimalError::DivisionByZero);
+        }
+        if numer == i128::MIN || denom == i128::MIN {
+            return Err(DecimalError::InternalOverflow);
+        }
+        if denom < 0 {
+            Ok(Self::reduced(-numer, -denom))
+        } else {
+            Ok(Self::reduced(numer, denom))
+        }
+    }
+
+    // Preconditions: denom > 0, numer != i128::MIN
+    #[allow(clippy::cast_possible_wrap)]
+    #[allow(clippy::integer_division)]
+    fn reduced(numer: i128, denom: i128) -> Self {
+        if numer == 0 {
+            return Self::ZERO;
+        }
+        // gcd <= denom <= i128::MAX, so the cast is lossless
+        let gcd = gcd(numer.unsigned_abs(), denom.unsigned_abs()) as i128;
+        Self {
+            numer: numer / gcd,
+            denom: denom / gcd,
+        }
+    }
+
+    // Returns Self { numer, denom }, if both are in range.
+    // Precondition: numer / denom is reduced.
+    const fn checked_new_raw(
+        numer: Option<i128>,
+        denom: Option<i128>,
+    ) -> Result<Self, DecimalError> {
+        match (numer, denom) {
+            (Some(numer), Some(denom)) if numer != i128::MIN => {
+                Ok(Self { numer, denom })
+            }
+            _ => Err(DecimalError::InternalOverflow),
+        }
+    }
+
+    /// Returns `true` if `self` is equal to zero.
+    #[must_use]
+    #[inline(always)]
+    pub const fn eq_zero(&self) -> bool {
+        self.numer == 0
+    }
+
+    /// Returns `self * rhs`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DecimalError::InternalOverflow` if the numerator or the
+    /// denominator of the result exceeds the range of `i128`.
+    #[allow(clippy::cast_possible_wrap)]
+    #[allow(clippy::integer_division)]
+    pub fn try_mul(self, rhs: Self) -> Result<Self, DecimalError> {
+        if self.eq_zero() || rhs.eq_zero() {
+            return Ok(Self::ZERO);
+        }
+        // Cross-reducing the operands gives a reduced result.
+        let g1 =
+            gcd(self.numer.unsigned_abs(), rhs.denom.unsigned_abs()) as i128;
+        let g2 =
+            gcd(rhs.numer.unsigned_abs(), self.denom.unsigned_abs()) as i128;
+        Self::checked_new_raw(
+            (self.numer / g1).checked_mul(rhs.numer / g2),
+            (self.denom / g2).checked_mul(rhs.denom / g1),
+        )
+    }
+
+    /// Returns `self / rhs`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error (wrapped in Result::Err) in the following cases:
+    /// * `rhs` == 0 => `DecimalError::DivisionByZero`,
+    /// * the numerator or the denominator of the result exceeds the range of
+    ///   `i128` => `DecimalError::InternalOverflow`.
+    pub fn try_div(self, rhs: Self) -> Result<Self, DecimalError> {
+        self.try_mul(rhs.try_recip()?)
+    }
+
+    /// Returns `self + rhs`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DecimalError::InternalOverflow` if the numerator or the
+    /// denominator of the result exceeds the range of `i128`.
+    #[allow(clippy::cast_possible_wrap)]
+    #[allow(clippy::integer_division)]
+    pub fn try_add(self, rhs: Self) -> Result<Self, DecimalError> {
+        if self.eq_zero() {
+            return Ok(rhs);
+        }
+        if rhs.eq_zero() {
+            return Ok(self);
+        }
+        // a/b + c/d = (a * (d/g) + c * (b/g)) / (b * (d/g)) with
+        // g = gcd(b, d); the result can only be further reduced by a
+        // common factor of its numerator and g. The numerator is calculated
+        // with 256 bits, so that it may exceed the range of i128 before
+        // being reduced.
+        let g =
+            gcd(self.denom.unsigned_abs(), rhs.denom.unsigned_abs()) as i128;
+        let (b, d) = (self.denom / g, rhs.denom / g);
+        let (quot, rem) =
+            i256_sum_of_prods_div_mod(self.numer, d, rhs.numer, b, g);
+        if quot == Some(0) && rem == 0 {
+            return Ok(Self::ZERO);
+        }
+        let (g2, numer) = if rem == 0 {
+            (g, quot)
+        } else {
+            let g2 = gcd(rem.unsigned_abs(), g.unsigned_abs()) as i128;
+            (
+                g2,
+                i256_sum_of_prods_div_mod(self.numer, d, rhs.numer, b, g2).0,
+            )
+        };
+        Self::checked_new_raw(numer, b.checked_mul(rhs.denom / g2))
+    }
+
+    /// Returns `self - rhs`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DecimalError::InternalOverflow` if the numerator or the
+    /// denominator of the result exceeds the range of `i128`.
+    pub fn try_sub(self, rhs: Self) -> Result<Self, DecimalError> {
+        self.try_add(-rhs)
+    }
+
+    /// Returns `1 / self`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DecimalError::DivisionByZero` if `self` == 0.
+    pub const fn try_recip(self) -> Result<Self, DecimalError> {
+        match self.numer {
+            0 => Err(DecimalError::DivisionByZero),
+            numer if numer < 0 => Ok(Self {
+                numer: -self.denom,
+                denom: -numer,
+            }),
+            numer => Ok(Self {
+                numer: self.denom,
+                denom: numer,
+            }),
+        }
+    }
+
+    /// Returns the `Decimal` exactly equal to `self`, if such a value
+    /// exists.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error (wrapped in Result::Err) in the following cases:
+    /// * `self` can not be represented with at most
+    ///   [MAX_N_FRAC_DIGITS](crate::MAX_N_FRAC_DIGITS) fractional digits =>
+    ///   `DecimalError::MaxNFracDigitsExceeded`,
+    /// * the resulting coefficient exceeds the internal representation of
+    ///   `Decimal` => `DecimalError::InternalOverflow`.
+    pub fn to_decimal(self) -> Result<Decimal, DecimalError> {
+        Decimal::from_integer_ratio(self.numer, self.denom)
+    }
+
+    /// Returns the `Decimal` nearest to `self` with `n_frac_digits`
+    /// fractional digits, rounded according to `mode`.
+    ///
+    /// This is the only point where rounding happens, so the result is
+    /// exactly the rounded value of the whole computation which led to
+    /// `self`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error (wrapped in Result::Err) in the following cases:
+    /// * `n_frac_digits` > [MAX_N_FRAC_DIGITS](crate::MAX_N_FRAC_DIGITS) =>
+    ///   `DecimalError::MaxNFracDigitsExceeded`,
+    /// * the resulting coefficient exceeds the internal representation of
+    ///   `Decimal` => `DecimalError::InternalOverflow`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fpdec::{Dec, Decimal, DecimalError, RoundingMode};
+    /// # fn main() -> Result<(), DecimalError> {
+    /// let r = Dec!(2).ratio(Dec!(3))?;
+    /// let d = r.to_decimal_rounded(4, RoundingMode::RoundHalfUp)?;
+    /// assert_eq!(d.to_string(), "0.6667");
+    /// let d = r.to_decimal_rounded(4, RoundingMode::RoundDown)?;
+    /// assert_eq!(d.to_string(), "0.6666");
+    /// # Ok(()) }
+    /// ```
+    pub fn to_decimal_rounded(
+        self,
+        n_frac_digits: u8,
+        mode: RoundingMode,
+    ) -> Result<Decimal, DecimalError> {
+        Decimal::from_integer_ratio_rounded(
+            self.numer,
+            self.denom,
+            n_frac_digits,
+            mode,
+        )
+    }
+}
+
+impl AsIntegerRatio for DecimalRatio {
+    #[inline(always)]
+    fn as_integer_ratio(self) -> (i128, i128) {
+        (self.numer, self.denom)
+    }
+
+    #[inline(always)]
+    fn numerator(self) -> i128 {
+        self.numer
+    }
+
+    #[inline(always)]
+    fn denominator(self) -> i128 {
+        self.denom
+    }
+}
+
+impl From<Decimal> for DecimalRatio {
+    /// Converts `d` into the equivalent reduced ratio (see
+    /// [AsIntegerRatio::as_integer_ratio]).
+    #[inline]
+    fn from(d: Decimal) -> Self {
+        let (numer, denom) = d.as_integer_ratio();
+        Self { numer, denom }
+    }
+}
+
+impl fmt::Display for DecimalRatio {
+    /// Formats the ratio as "numerator/denominator", padded according to the
+    /// given formatter.
+    ///
+    /// # Examples:
+    ///
+    /// ```rust
+    /// # use fpdec::{DecimalError, DecimalRatio};
+    /// # fn main() -> Result<(), DecimalError> {
+    /// let r = DecimalRatio::new(-12, 18)?;
+    /// assert_eq!(format!("{r}"), "-2/3");
+    /// assert_eq!(format!("{r:>6}"), "  -2/3");
+    /// # Ok(()) }
+    /// ```
+    fn fmt(&self, form: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut buf = [0_u8; 2 * MAX_STR_LEN];
+        let mut start = write_abs_to_buf(self.denom, 0, 0, None, &mut buf);
+        start -= 1;
+        buf[start] = b'/';
+        start = write_abs_to_buf(self.numer, 0, 0, None, &mut buf[..start]);
+        if self.numer < 0 {
+            start -= 1;
+            buf[start] = b'-';
+        }
+        form.pad(buf_as_str(&buf, start))
+    }
+}
+
+impl Neg for DecimalRatio {
+    type Output = Self;
+
+    #[inline(always)]
+    fn neg(self) -> Self::Output {
+        Self {
+            numer: -self.numer,
+            denom: self.denom,
+        }
+    }
+}
+
+impl Decimal {
+    /// Returns the exact ratio `self / other`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error (wrapped in Result::Err) in the following cases:
+    /// * `other` == 0 => `DecimalError::DivisionByZero`,
+    /// * the numerator or the denominator of the result exceeds the range of
+    ///   `i128` => `DecimalError::InternalOverflow`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fpdec::{AsIntegerRatio, Dec, Decimal, DecimalError};
+    /// # fn main() -> Result<(), DecimalError> {
+    /// let r = Dec!(1.5).ratio(Dec!(-0.9))?;
+    /// assert_eq!(r.as_integer_ratio(), (-5, 3));
+    /// # Ok(()) }
+    /// ```
+    pub fn ratio(self, other: Self) -> Result<DecimalRatio, DecimalError> {
+        DecimalRatio::from(self).try_div(DecimalRatio::from(other))
+    }
+}
+
+macro_rules! impl_decimal_ratio_binop {
+    (impl $imp:ident, $method:ident, $try_method:ident) => {
+        impl $imp<Self> for DecimalRatio {
+            type Output = Self;
+
+            #[inline]
+            fn $method(self, rhs: Self) -> Self::Output {
+                match self.$try_method(rhs) {
+                    Ok(res) => res,
+                    Err(err) => panic!("{}", err),
+                }
+            }
+        }
+
+        impl $imp<Decimal> for DecimalRatio {
+            type Output = Self;
+
+            #[inline]
+            fn $method(self, rhs: Decimal) -> Self::Output {
+                $imp::$method(self, Self::from(rhs))
+            }
+        }
+
+        impl $imp<DecimalRatio> for Decimal {
+            type Output = DecimalRatio;
+
+            #[inline]
+            fn $method(self, rhs: DecimalRatio) -> Self::Output {
+                $imp::$method(DecimalRatio::from(self), rhs)
+            }
+        }
+    };
+}
+
+impl_decimal_ratio_binop!(impl Mul, mul, try_mul);
+impl_decimal_ratio_binop!(impl Div, div, try_div);
+impl_decimal_ratio_binop!(impl Add, add, try_add);
+impl_decimal_ratio_binop!(impl Sub, sub, try_sub);
+
+#[cfg(test)]
+mod decimal_ratio_tests {
+    use alloc::format;
+
+    use super::*;
+
+    #[test]
+    fn test_new() {
+        let r = DecimalRatio::new(-12, -18).unwrap();
+        assert_eq!(r.as_integer_ratio(), (2, 3));
+        let r = DecimalRatio::new(0, -18).unwrap();
+        assert_eq!(r, DecimalRatio::ZERO);
+        assert_eq!(
+            DecimalRatio::new(1, 0).unwrap_err(),
+            DecimalError::DivisionByZero
+        );
+        assert_eq!(
+            DecimalRatio::new(i128::MIN, 3).unwrap_err(),
+            DecimalError::InternalOverflow
+        );
+    }
+
+    #[test]
+    fn test_from_decimal() {
+        let r = DecimalRatio::from(Decimal::new_raw(-2827095, 5));
+        assert_eq!(r.as_integer_ratio(), (-565419, 20000));
+        assert_eq!(DecimalRatio::from(Decimal::ZERO), DecimalRatio::ZERO);
+        assert_eq!(DecimalRatio::from(Decimal::ONE), DecimalRatio::ONE);
+    }
+
+    #[test]
+    fn test_ratio() {
+        let a = Decimal::new_raw(15, 1);
+        let b = Decimal::new_raw(-9, 1);
+        assert_eq!(a.ratio(b).unwrap().as_integer_ratio(), (-5, 3));
+        assert_eq!(
+            a.ratio(Decimal::ZERO).unwrap_err(),
+            DecimalError::DivisionByZero
+        );
+    }
+
+    #[test]
+    fn test_arithmetic() {
+        let r = Decimal::ONE.ratio(Decimal::new_raw(3, 0)).unwrap();
+        let s = Decimal::new_raw(-5, 1)
+            .ratio(Decimal::new_raw(7, 0))
+            .unwrap();
+        assert_eq!((r * s).as_integer_ratio(), (-1, 42));
+        assert_eq!((r / s).as_integer_ratio(), (-14, 3));
+        assert_eq!((r + s).as_integer_ratio(), (11, 42));
+        assert_eq!((r - s).as_integer_ratio(), (17, 42));
+        assert_eq!((s - s), DecimalRatio::ZERO);
+        assert_eq!((s / s), DecimalRatio::ONE);
+        let d = Decimal::new_raw(15, 1);
+        assert_eq!((r * d).as_integer_ratio(), (1, 2));
+        assert_eq!((d * r).as_integer_ratio(), (1, 2));
+        assert_eq!((r / d).as_integer_ratio(), (2, 9));
+        assert_eq!((d / r).as_integer_ratio(), (9, 2));
+        assert_eq!((r + d).as_integer_ratio(), (11, 6));
+        assert_eq!((d - r).as_integer_ratio(), (7, 6));
+        assert_eq!((-r).as_integer_ratio(), (-1, 3));
+    }
+
+    #[test]
+    fn test_chain_rounded_once() {
+        let a = Decimal::new_raw(10, 0);
+        let b = Decimal::new_raw(3, 0);
+        let c = Decimal::new_raw(7, 0);
+        let r = a.ratio(b).unwrap() * c / a * b / c;
+        assert_eq!(r, DecimalRatio::ONE);
+        assert_eq!(r.to_decimal(), Ok(Decimal::ONE));
+        let r = a.ratio(b).unwrap() * c;
+        assert_eq!(
+            r.to_decimal_rounded(3, RoundingMode::RoundHalfEven),
+            Ok(Decimal::new_raw(23333, 3))
+        );
+        assert_eq!(
+            r.to_decimal_rounded(2, RoundingMode::RoundUp),
+            Ok(Decimal::new_raw(2334, 2))
+        );
+        assert_eq!(
+            r.to_decimal_rounded(19, RoundingMode::RoundUp),
+            Err(DecimalError::MaxNFracDigitsExceeded)
+        );
+        assert_eq!(r.to_decimal(), Err(DecimalError::MaxNFracDigitsExceeded));
+    }
+
+    #[test]
+    fn test_overflow() {
+        let r = DecimalRatio::new(1, i128::MAX).unwrap();
+        let s = DecimalRatio::new(1, i128::MAX - 1).unwrap();
+        assert_eq!(r.try_mul(s), Err(DecimalError::InternalOverflow));
+        assert_eq!(r.try_add(s), Err(DecimalError::InternalOverflow));
+        assert_eq!(r.try_sub(s), Err(DecimalError::InternalOverflow));
+        assert_eq!(r.try_mul(r.try_recip().unwrap()), Ok(DecimalRatio::ONE));
+        let r = DecimalRatio::new(i128::MAX, 1).unwrap();
+        assert_eq!(r.try_add(r), Err(DecimalError::InternalOverflow));
+        let two = DecimalRatio::new(2, 1).unwrap();
+        assert_eq!(r.try_mul(two), Err(DecimalError::InternalOverflow));
+        assert_eq!(
+            r.try_div(two.try_recip().unwrap()),
+            Err(DecimalError::InternalOverflow)
+        );
+        assert_eq!(r.try_sub(-r), Err(DecimalError::InternalOverflow));
+        assert_eq!(
+            DecimalRatio::ONE.try_div(DecimalRatio::ZERO),
+            Err(DecimalError::DivisionByZero)
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_overflow_panics() {
+        let r = DecimalRatio::new(i128::MAX, 1).unwrap();
+        let _ = r * r;
+    }
+
+    #[test]
+    fn test_overflow_avoided_by_reduction() {
+        // the products would overflow without cross-reducing the operands
+        let r = DecimalRatio::new(1 << 100, 3).unwrap();
+        let s = DecimalRatio::new(3, 1 << 99).unwrap();
+        assert_eq!(r.try_mul(s).unwrap().as_integer_ratio(), (2, 1));
+        assert_eq!(r.try_div(s.try_recip().unwrap()), r.try_mul(s));
+        // the sum of the numerators exceeds the range of i128 before being
+        // reduced
+        let r = DecimalRatio::new((1 << 126) + 1, 2).unwrap();
+        assert_eq!(
+            r.try_add(r).unwrap().as_integer_ratio(),
+            ((1 << 126) + 1, 1)
+        );
+        let s = DecimalRatio::new(-(1 << 126) - 3, 2).unwrap();
+        assert_eq!(
+            (-r).try_add(s).unwrap().as_integer_ratio(),
+            (-(1 << 126) - 2, 1)
+        );
+        assert_eq!(r.try_sub(r), Ok(DecimalRatio::ZERO));
+    }
+
+    #[test]
+    fn test_display() {
+        let r = DecimalRatio::new(-12, 18).unwrap();
+        assert_eq!(format!("{r}"), "-2/3");
+        assert_eq!(format!("{r:>6}"), "  -2/3");
+        assert_eq!(format!("{r:<6}|"), "-2/3  |");
+        assert_eq!(format!("{r:*^8}"), "**-2/3**");
+        let r = DecimalRatio::new(i128::MAX, i128::MAX - 1).unwrap();
+        assert_eq!(
+            format!("{r}"),
+            format!("{}/{}", i128::MAX, i128::MAX - 1)
+        );
+        let r = DecimalRatio::new(-i128::MAX, i128::MAX - 1).unwrap();
+        assert_eq!(
+            format!("{r}"),
+            format!("-{}/{}", i128::MAX, i128::MAX - 1)
+        );
+        assert_eq!(format!("{}", DecimalRatio::ZERO), "0/1");
+    }
+}
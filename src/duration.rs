@@ -0,0 +1,394 @@
+// ---------------------------------------------------------------------------
+// Copyright:   (c) 2026 ff. Michael Amrhein (michael@adrhinum.de)
+// License:     This program is part of a larger application. For license
+//              details please read the file LICENSE.TXT provided together
+//              with the application.
+// ---------------------------------------------------------------------------
+// $Source$
+// $Revision$
+
+use core::{convert::TryFrom, time::Duration};
+
+use fpdec_core::{checked_mul_pow_ten, i128_div_rounded, ten_pow};
+
+use crate::{normalize, Decimal, RoundingMode, TryFromDecimalError};
+
+const NANOS_PER_SEC: i128 = 1_000_000_000;
+const N_FRAC_DIGITS_NANOS: u8 = 9;
+
+/// Returns `d` as an amount of nanoseconds, rounded according to `mode`, or
+/// `None` if that amount is not representable as an `i128`.
+#[inline]
+fn to_nanos(d: Decimal, mode: Option<RoundingMode>) -> Option<i128> {
+    if d.n_frac_digits <= N_FRAC_DIGITS_NANOS {
+        checked_mul_pow_ten(d.coeff, N_FRAC_DIGITS_NANOS - d.n_frac_digits)
+    } else {
+        Some(i128_div_rounded(
+            d.coeff,
+            ten_pow(d.n_frac_digits - N_FRAC_DIGITS_NANOS),
+            mode,
+        ))
+    }
+}
+
+/// Returns the `Decimal` equivalent to the given amount of nanoseconds,
+/// i.e. having at most 9 fractional digits.
+#[inline]
+fn from_nanos(nanos: i128) -> Decimal {
+    let mut coeff = nanos;
+    let mut n_frac_digits = N_FRAC_DIGITS_NANOS;
+    normalize(&mut coeff, &mut n_frac_digits);
+    Decimal::new_raw(coeff, n_frac_digits)
+}
+
+/// Splits the given amount of nanoseconds into whole seconds (rounded
+/// towards negative infinity) and the remaining nanoseconds.
+#[inline]
+fn to_timestamp(nanos: i128) -> Result<(i64, u32), TryFromDecimalError> {
+    let secs = i64::try_from(nanos.div_euclid(NANOS_PER_SEC))
+        .map_err(|_| TryFromDecimalError::ValueOutOfRange)?;
+    // rem_euclid is in 0..NANOS_PER_SEC, so it always fits an u32
+    #[allow(clippy::cast_possible_truncation)]
+    #[allow(clippy::cast_sign_loss)]
+    let nanos = nanos.rem_euclid(NANOS_PER_SEC) as u32;
+    Ok((secs, nanos))
+}
+
+/// Converts `d`, interpreted as an amount of seconds, into a `Duration`,
+/// after rounding it to whole nanoseconds according to `mode`.
+#[inline]
+fn to_duration(
+    d: Decimal,
+    mode: Option<RoundingMode>,
+) -> Result<Duration, TryFromDecimalError> {
+    if d.coeff < 0 {
+        return Err(TryFromDecimalError::ValueOutOfRange);
+    }
+    let nanos =
+        to_nanos(d, mode).ok_or(TryFromDecimalError::ValueOutOfRange)?;
+    let secs = u64::try_from(nanos.div_euclid(NANOS_PER_SEC))
+        .map_err(|_| TryFromDecimalError::ValueOutOfRange)?;
+    // rem_euclid is in 0..NANOS_PER_SEC, so it always fits an u32
+    #[allow(clippy::cast_possible_truncation)]
+    #[allow(clippy::cast_sign_loss)]
+    Ok(Duration::new(secs, nanos.rem_euclid(NANOS_PER_SEC) as u32))
+}
+
+impl From<Duration> for Decimal {
+    /// Converts a `Duration` into the equivalent amount of seconds.
+    ///
+    /// The result is exact, having at most 9 fractional digits.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use core::time::Duration;
+    /// # use fpdec::Decimal;
+    /// let d = Decimal::from(Duration::new(17, 250_000_000));
+    /// assert_eq!(d.to_string(), "17.25");
+    /// ```
+    fn from(duration: Duration) -> Self {
+        from_nanos(
+            i128::from(duration.as_secs()) * NANOS_PER_SEC
+                + i128::from(duration.subsec_nanos()),
+        )
+    }
+}
+
+impl TryFrom<Decimal> for Duration {
+    type Error = TryFromDecimalError;
+
+    /// Tries to convert a `Decimal` value `d`, interpreted as an amount of
+    /// seconds, into a `Duration`.
+    ///
+    /// If `d` has more than 9 fractional digits, it is rounded to whole
+    /// nanoseconds according to the current default rounding mode.
+    ///
+    /// Returns an error (wrapped in Result::Err) in the following cases:
+    /// * `d` is negative, even if it would round to zero =>
+    ///   `TryFromDecimalError::ValueOutOfRange`,
+    /// * the rounded value of `d` exceeds the range of `Duration` values =>
+    ///   `TryFromDecimalError::ValueOutOfRange`.
+    fn try_from(d: Decimal) -> Result<Self, Self::Error> {
+        to_duration(d, None)
+    }
+}
+
+impl Decimal {
+    /// Converts `self`, interpreted as an amount of seconds, into a
+    /// `Duration`, after rounding it to whole nanoseconds according to
+    /// `mode`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TryFromDecimalError::ValueOutOfRange` (wrapped in
+    /// Result::Err), if `self` is negative (even if it would round to zero)
+    /// or the rounded value exceeds the range of `Duration` values.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use core::time::Duration;
+    /// # use fpdec::{Dec, Decimal, RoundingMode, TryFromDecimalError};
+    /// let d = Dec!(0.0000000015);
+    /// assert_eq!(
+    ///     d.to_duration_rounded(RoundingMode::RoundHalfUp),
+    ///     Ok(Duration::from_nanos(2))
+    /// );
+    /// assert_eq!(
+    ///     Dec!(-1).to_duration_rounded(RoundingMode::RoundHalfUp),
+    ///     Err(TryFromDecimalError::ValueOutOfRange)
+    /// );
+    /// ```
+    pub fn to_duration_rounded(
+        self,
+        mode: RoundingMode,
+    ) -> Result<Duration, TryFromDecimalError> {
+        to_duration(self, Some(mode))
+    }
+
+    /// Creates a `Decimal` from a timestamp given as whole seconds since the
+    /// epoch and additional nanoseconds.
+    ///
+    /// The result is exact, having at most 9 fractional digits. A value of
+    /// `nanos` exceeding 999_999_999 is carried over into the seconds.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fpdec::Decimal;
+    /// let d = Decimal::from_timestamp(1_700_000_000, 123_000_000);
+    /// assert_eq!(d.to_string(), "1700000000.123");
+    /// let d = Decimal::from_timestamp(-2, 500_000_000);
+    /// assert_eq!(d.to_string(), "-1.5");
+    /// ```
+    pub fn from_timestamp(secs: i64, nanos: u32) -> Self {
+        from_nanos(i128::from(secs) * NANOS_PER_SEC + i128::from(nanos))
+    }
+
+    /// Converts `self`, interpreted as seconds since the epoch, into a
+    /// timestamp given as whole seconds and additional nanoseconds, i.e.
+    /// the inverse of [`Decimal::from_timestamp`].
+    ///
+    /// If `self` has more than 9 fractional digits, it is rounded to whole
+    /// nanoseconds according to the current default rounding mode.
+    ///
+    /// The seconds are rounded towards negative infinity, so that the
+    /// nanoseconds are always in the range 0..=999_999_999.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TryFromDecimalError::ValueOutOfRange` (wrapped in
+    /// Result::Err), if the whole seconds exceed the range of `i64` values.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fpdec::{Dec, Decimal};
+    /// let d = Dec!(1700000000.123);
+    /// assert_eq!(d.to_timestamp(), Ok((1_700_000_000, 123_000_000)));
+    /// assert_eq!(Dec!(-1.5).to_timestamp(), Ok((-2, 500_000_000)));
+    /// ```
+    pub fn to_timestamp(self) -> Result<(i64, u32), TryFromDecimalError> {
+        to_nanos(self, None)
+            .ok_or(TryFromDecimalError::ValueOutOfRange)
+            .and_then(to_timestamp)
+    }
+
+    /// Converts `self`, interpreted as seconds since the epoch, into a
+    /// timestamp given as whole seconds and additional nanoseconds, after
+    /// rounding it to whole nanoseconds according to `mode`.
+    ///
+    /// The seconds are rounded towards negative infinity, so that the
+    /// nanoseconds are always in the range 0..=999_999_999.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TryFromDecimalError::ValueOutOfRange` (wrapped in
+    /// Result::Err), if the whole seconds exceed the range of `i64` values.
+    pub fn to_timestamp_rounded(
+        self,
+        mode: RoundingMode,
+    ) -> Result<(i64, u32), TryFromDecimalError> {
+        to_nanos(self, Some(mode))
+            .ok_or(TryFromDecimalError::ValueOutOfRange)
+            .and_then(to_timestamp)
+    }
+}
+
+#[cfg(test)]
+mod duration_tests {
+    use super::*;
+
+    #[test]
+    fn test_from_duration() {
+        let d = Decimal::from(Duration::ZERO);
+        assert_eq!(d, Decimal::ZERO);
+        assert_eq!(d.n_frac_digits(), 0);
+        let d = Decimal::from(Duration::new(3, 0));
+        assert_eq!(d.coefficient(), 3);
+        assert_eq!(d.n_frac_digits(), 0);
+        let d = Decimal::from(Duration::new(3, 10_000));
+        assert_eq!(d.coefficient(), 300_001);
+        assert_eq!(d.n_frac_digits(), 5);
+        let d = Decimal::from(Duration::from_nanos(1));
+        assert_eq!(d, Decimal::new_raw(1, 9));
+        let d = Decimal::from(Duration::MAX);
+        assert_eq!(d.coefficient(), 18_446_744_073_709_551_615_999_999_999);
+        assert_eq!(d.n_frac_digits(), 9);
+    }
+
+    #[test]
+    fn test_duration_roundtrip() {
+        for duration in [
+            Duration::ZERO,
+            Duration::from_nanos(7),
+            Duration::from_millis(12_345),
+            Duration::new(86_400, 999_999_999),
+            Duration::MAX,
+        ] {
+            let d = Decimal::from(duration);
+            assert_eq!(Duration::try_from(d), Ok(duration));
+        }
+    }
+
+    #[test]
+    fn test_try_into_duration() {
+        let d = Decimal::new_raw(17_25, 2);
+        assert_eq!(Duration::try_from(d), Ok(Duration::new(17, 250_000_000)));
+        // rounded half to even by default
+        let d = Decimal::new_raw(25, 10);
+        assert_eq!(Duration::try_from(d), Ok(Duration::from_nanos(2)));
+        let d = Decimal::new_raw(35, 10);
+        assert_eq!(Duration::try_from(d), Ok(Duration::from_nanos(4)));
+        // negative values are rejected, even if they round to zero
+        let d = Decimal::new_raw(-4, 10);
+        assert_eq!(
+            Duration::try_from(d),
+            Err(TryFromDecimalError::ValueOutOfRange)
+        );
+        assert_eq!(
+            Duration::try_from(Decimal::new_raw(0, 10)),
+            Ok(Duration::ZERO)
+        );
+    }
+
+    #[test]
+    fn test_try_into_duration_out_of_range() {
+        let d = Decimal::new_raw(-1, 9);
+        assert_eq!(
+            Duration::try_from(d),
+            Err(TryFromDecimalError::ValueOutOfRange)
+        );
+        let d = Decimal::from(Duration::MAX) + Decimal::new_raw(1, 9);
+        assert_eq!(
+            Duration::try_from(d),
+            Err(TryFromDecimalError::ValueOutOfRange)
+        );
+        assert_eq!(
+            Duration::try_from(Decimal::MAX),
+            Err(TryFromDecimalError::ValueOutOfRange)
+        );
+    }
+
+    #[test]
+    fn test_to_duration_rounded() {
+        let d = Decimal::new_raw(10_000_000_005, 10);
+        let modes = [
+            (RoundingMode::Round05Up, 1_000_000_001),
+            (RoundingMode::RoundCeiling, 1_000_000_001),
+            (RoundingMode::RoundDown, 1_000_000_000),
+            (RoundingMode::RoundFloor, 1_000_000_000),
+            (RoundingMode::RoundHalfDown, 1_000_000_000),
+            (RoundingMode::RoundHalfEven, 1_000_000_000),
+            (RoundingMode::RoundHalfUp, 1_000_000_001),
+            (RoundingMode::RoundUp, 1_000_000_001),
+        ];
+        for (mode, nanos) in modes {
+            assert_eq!(
+                d.to_duration_rounded(mode),
+                Ok(Duration::from_nanos(nanos))
+            );
+        }
+        let d = Decimal::new_raw(-1, 18);
+        for mode in [RoundingMode::RoundCeiling, RoundingMode::RoundFloor] {
+            assert_eq!(
+                d.to_duration_rounded(mode),
+                Err(TryFromDecimalError::ValueOutOfRange)
+            );
+        }
+    }
+
+    #[test]
+    fn test_from_timestamp() {
+        let d = Decimal::from_timestamp(0, 0);
+        assert_eq!(d, Decimal::ZERO);
+        let d = Decimal::from_timestamp(1_700_000_000, 1);
+        assert_eq!(d.coefficient(), 1_700_000_000_000_000_001);
+        assert_eq!(d.n_frac_digits(), 9);
+        let d = Decimal::from_timestamp(-1, 750_000_000);
+        assert_eq!(d, Decimal::new_raw(-25, 2));
+        let d = Decimal::from_timestamp(5, 2_500_000_000);
+        assert_eq!(d, Decimal::new_raw(75, 1));
+        let d = Decimal::from_timestamp(i64::MIN, 0);
+        assert_eq!(d, Decimal::from(i64::MIN));
+    }
+
+    #[test]
+    fn test_timestamp_roundtrip() {
+        for (secs, nanos) in [
+            (0_i64, 0_u32),
+            (1_700_000_000, 123_456_789),
+            (-1, 999_999_999),
+            (-1_700_000_000, 1),
+            (i64::MAX, 999_999_999),
+            (i64::MIN, 0),
+        ] {
+            let d = Decimal::from_timestamp(secs, nanos);
+            assert_eq!(d.to_timestamp(), Ok((secs, nanos)));
+        }
+    }
+
+    #[test]
+    fn test_to_timestamp() {
+        let d = Decimal::new_raw(-15, 1);
+        assert_eq!(d.to_timestamp(), Ok((-2, 500_000_000)));
+        let d = Decimal::new_raw(-1, 9);
+        assert_eq!(d.to_timestamp(), Ok((-1, 999_999_999)));
+        let d = Decimal::new_raw(-5, 10);
+        assert_eq!(d.to_timestamp(), Ok((0, 0)));
+        let d = Decimal::new_raw(99_999_999_995, 10);
+        assert_eq!(d.to_timestamp(), Ok((10, 0)));
+        let d = Decimal::from(i64::MAX) + Decimal::ONE;
+        assert_eq!(
+            d.to_timestamp(),
+            Err(TryFromDecimalError::ValueOutOfRange)
+        );
+        let d = Decimal::from(i64::MIN) - Decimal::new_raw(1, 9);
+        assert_eq!(
+            d.to_timestamp(),
+            Err(TryFromDecimalError::ValueOutOfRange)
+        );
+        assert_eq!(
+            Decimal::MIN.to_timestamp(),
+            Err(TryFromDecimalError::ValueOutOfRange)
+        );
+    }
+
+    #[test]
+    fn test_to_timestamp_rounded() {
+        let d = Decimal::new_raw(-12_345_678_901, 10);
+        assert_eq!(
+            d.to_timestamp_rounded(RoundingMode::RoundFloor),
+            Ok((-2, 765_432_109))
+        );
+        assert_eq!(
+            d.to_timestamp_rounded(RoundingMode::RoundCeiling),
+            Ok((-2, 765_432_110))
+        );
+        assert_eq!(
+            d.to_timestamp_rounded(RoundingMode::RoundHalfUp),
+            Ok((-2, 765_432_110))
+        );
+    }
+}
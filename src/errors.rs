@@ -28,6 +28,8 @@ pub enum DecimalError {
     NotANumber,
     /// A division op called with a divisor equal to zero.
     DivisionByZero,
+    /// An unsupported resolution given for a fractional price quote.
+    InvalidResolution,
 }
 
 impl DecimalError {
@@ -43,6 +45,7 @@ impl DecimalError {
             Self::InfiniteValue => "Can't convert infinite value to Decimal.",
             Self::NotANumber => "Given value is not a number.",
             Self::DivisionByZero => "Division by Zero.",
+            Self::InvalidResolution => "Unsupported resolution.",
         }
     }
 }
@@ -90,3 +93,93 @@ impl Display for TryFromDecimalError {
 
 #[cfg(feature = "std")]
 impl std::error::Error for TryFromDecimalError {}
+
+/// An error which can be returned from encoding `Decimal` values into or
+/// decoding them from fixed-width fields like packed decimal or zoned
+/// decimal fields.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FieldCodecError {
+    /// The field contains an invalid digit.
+    InvalidDigit,
+    /// The field contains an invalid sign.
+    InvalidSign,
+    /// The length of the field is not valid.
+    InvalidLength,
+    /// The value does not fit into the given field width.
+    FieldOverflow,
+    /// More than [MAX_N_FRAC_DIGITS](crate::MAX_N_FRAC_DIGITS) fractional
+    /// decimal digits requested.
+    MaxNFracDigitsExceeded,
+    /// The value of the field exceeds the range of `Decimal` values.
+    ValueOutOfRange,
+}
+
+impl FieldCodecError {
+    #[doc(hidden)]
+    #[must_use]
+    pub const fn _description(&self) -> &str {
+        match self {
+            Self::InvalidDigit => "Invalid digit found in field.",
+            Self::InvalidSign => "Invalid sign found in field.",
+            Self::InvalidLength => "Invalid length of field.",
+            Self::FieldOverflow => "Value exceeds the width of the field.",
+            Self::MaxNFracDigitsExceeded => {
+                "More than MAX_N_FRAC_DIGITS fractional decimal digits \
+                 requested."
+            }
+            Self::ValueOutOfRange => {
+                "Value of field exceeds the range of Decimal values."
+            }
+        }
+    }
+}
+
+impl Display for FieldCodecError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        Display::fmt(self._description(), f)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for FieldCodecError {}
+
+/// An error which can be returned from operations on `Money` values.
+#[cfg(feature = "money")]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MoneyError {
+    /// The currencies of the operands differ.
+    CurrencyMismatch,
+    /// No known currency code or symbol found.
+    UnknownCurrency,
+    /// The amount can not be parsed.
+    InvalidAmount,
+    /// The exchange rate is not positive.
+    InvalidRate,
+    /// The result would exceed the internal representation of `Decimal`.
+    InternalOverflow,
+}
+
+#[cfg(feature = "money")]
+impl MoneyError {
+    #[doc(hidden)]
+    #[must_use]
+    pub const fn _description(&self) -> &str {
+        match self {
+            Self::CurrencyMismatch => "Currencies of operands differ.",
+            Self::UnknownCurrency => "Unknown currency.",
+            Self::InvalidAmount => "Invalid amount.",
+            Self::InvalidRate => "Exchange rate must be positive.",
+            Self::InternalOverflow => "Internal representation exceeded.",
+        }
+    }
+}
+
+#[cfg(feature = "money")]
+impl Display for MoneyError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        Display::fmt(self._description(), f)
+    }
+}
+
+#[cfg(all(feature = "money", feature = "std"))]
+impl std::error::Error for MoneyError {}
@@ -0,0 +1,489 @@
+// ---------------------------------------------------------------------------
+// Copyright:   (c) 2026 ff. Michael Amrhein (michael@adrhinum.de)
+// License:     This program is part of a larger application. For license
+//              details please read the file LICENSE.TXT provided together
+//              with the application.
+// ---------------------------------------------------------------------------
+// $Source$
+// $Revision$
+
+use fpdec_core::{checked_mul_pow_ten, i128_magnitude, ten_pow};
+
+use crate::{
+    AsIntegerRatio, Currency, Decimal, DecimalError, DecimalRatio, Money,
+    MoneyError, RoundingMode, MAX_N_FRAC_DIGITS,
+};
+
+/// Returns the position of the most significant decimal digit of the
+/// positive ratio `numer / denom`, i.e. floor(log10(numer / denom)).
+fn ratio_magnitude(numer: i128, denom: i128) -> i32 {
+    let magn =
+        i32::from(i128_magnitude(numer)) - i32::from(i128_magnitude(denom));
+    let is_less = if magn >= 0 {
+        // magn <= 38, so the cast is safe
+        #[allow(clippy::cast_sign_loss, clippy::cast_possible_truncation)]
+        let shift = magn as u8;
+        #[allow(clippy::integer_division)]
+        let quot = numer / ten_pow(shift);
+        quot < denom
+    } else {
+        // -magn <= 38, so the cast is safe
+        #[allow(clippy::cast_sign_loss, clippy::cast_possible_truncation)]
+        let shift = -magn as u8;
+        checked_mul_pow_ten(numer, shift).is_some_and(|n| n < denom)
+    };
+    magn - i32::from(is_less)
+}
+
+/// Returns the positive ratio `rate`, rounded to `sig_digits` significant
+/// digits according to `mode`.
+fn round_to_sig_digits(
+    rate: DecimalRatio,
+    sig_digits: u8,
+    mode: RoundingMode,
+) -> Result<Decimal, MoneyError> {
+    let (numer, denom) = rate.as_integer_ratio();
+    let n_frac_digits =
+        i32::from(sig_digits) - 1 - ratio_magnitude(numer, denom);
+    if let Ok(n_frac_digits) = u8::try_from(n_frac_digits) {
+        if n_frac_digits > MAX_N_FRAC_DIGITS {
+            return Err(MoneyError::InternalOverflow);
+        }
+        return rate
+            .to_decimal_rounded(n_frac_digits, mode)
+            .map_err(|_| MoneyError::InternalOverflow);
+    }
+    // n_frac_digits < 0, i.e. the rate has to be rounded to a multiple of
+    // 10 ^ shift, where shift <= 38
+    #[allow(clippy::cast_sign_loss, clippy::cast_possible_truncation)]
+    let shift = -n_frac_digits as u8;
+    DecimalRatio::new(ten_pow(shift), 1)
+        .and_then(|unit| rate.try_div(unit))
+        .and_then(|r| r.to_decimal_rounded(0, mode))
+        .ok()
+        .and_then(|d| checked_mul_pow_ten(d.coefficient(), shift))
+        .map(|coeff| Decimal::new_raw(coeff, 0))
+        .ok_or(MoneyError::InternalOverflow)
+}
+
+/// Rate for exchanging one unit of a base currency into a quote currency.
+///
+/// The rate is held as an exact ratio, so that neither crossing rates nor
+/// converting amounts introduces intermediate rounding. Rounding happens
+/// only in [`ExchangeRate::invert`], which gives a rate with a specified
+/// number of significant digits, and in [`ExchangeRate::convert`], which
+/// gives the final amount.
+///
+/// # Examples
+///
+/// Triangulation in the style of the EU conversion rules: rates with 6
+/// significant digits are given per euro, an amount is converted via the
+/// euro into another currency, and only the final amount is rounded.
+///
+/// ```rust
+/// # use fpdec::{
+/// #     Currency, Dec, Decimal, ExchangeRate, Money, MoneyError,
+/// #     RoundingMode,
+/// # };
+/// # fn main() -> Result<(), MoneyError> {
+/// let eur_usd =
+///     ExchangeRate::new(Currency::EUR, Currency::USD, Dec!(1.0823))?;
+/// let eur_gbp =
+///     ExchangeRate::new(Currency::EUR, Currency::GBP, Dec!(0.8561))?;
+/// // USD -> EUR -> GBP without intermediate rounding
+/// let usd_gbp = eur_usd.cross(&eur_gbp)?;
+/// let amount = Money::new(Dec!(1000), Currency::USD);
+/// let res = usd_gbp.convert(amount, 2, RoundingMode::RoundHalfUp)?;
+/// assert_eq!(res, Money::new(Dec!(791.00), Currency::GBP));
+/// // the inverse rate, rounded to 6 significant digits
+/// let usd_eur = eur_usd.invert(6)?;
+/// assert_eq!(usd_eur.rate().to_decimal(), Ok(Dec!(0.923958)));
+/// # Ok(()) }
+/// ```
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct ExchangeRate {
+    base: Currency,
+    quote: Currency,
+    rate: DecimalRatio,
+}
+
+impl ExchangeRate {
+    /// Returns a new `ExchangeRate`, where one unit of `base` equals `rate`
+    /// units of `quote`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error (wrapped in Result::Err) in the following cases:
+    /// * `base` equals `quote` => `MoneyError::CurrencyMismatch`,
+    /// * `rate` <= 0 => `MoneyError::InvalidRate`.
+    pub fn new(
+        base: Currency,
+        quote: Currency,
+        rate: Decimal,
+    ) -> Result<Self, MoneyError> {
+        if base == quote {
+            return Err(MoneyError::CurrencyMismatch);
+        }
+        if rate.coefficient() <= 0 {
+            return Err(MoneyError::InvalidRate);
+        }
+        Ok(Self {
+            base,
+            quote,
+            rate: DecimalRatio::from(rate),
+        })
+    }
+
+    /// Base currency of `self`.
+    #[must_use]
+    #[inline(always)]
+    pub const fn base(&self) -> Currency {
+        self.base
+    }
+
+    /// Quote currency of `self`.
+    #[must_use]
+    #[inline(always)]
+    pub const fn quote(&self) -> Currency {
+        self.quote
+    }
+
+    /// Exact number of units of the quote currency equal to one unit of the
+    /// base currency.
+    #[must_use]
+    #[inline(always)]
+    pub const fn rate(&self) -> DecimalRatio {
+        self.rate
+    }
+
+    /// Returns the inverse of `self`, i.e. the rate for exchanging the quote
+    /// currency into the base currency, rounded to `sig_digits` significant
+    /// digits according to the current [RoundingMode](crate::RoundingMode).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `sig_digits` equals zero!
+    ///
+    /// # Errors
+    ///
+    /// Returns `MoneyError::InternalOverflow` if the resulting rate can not
+    /// be represented by a `Decimal` with the given number of significant
+    /// digits.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fpdec::{Currency, Dec, Decimal, ExchangeRate, MoneyError};
+    /// # fn main() -> Result<(), MoneyError> {
+    /// let usd_jpy =
+    ///     ExchangeRate::new(Currency::USD, Currency::JPY, Dec!(156.73))?;
+    /// let jpy_usd = usd_jpy.invert(6)?;
+    /// assert_eq!(jpy_usd.base(), Currency::JPY);
+    /// assert_eq!(jpy_usd.rate().to_decimal(), Ok(Dec!(0.00638040)));
+    /// # Ok(()) }
+    /// ```
+    pub fn invert(&self, sig_digits: u8) -> Result<Self, MoneyError> {
+        assert!(sig_digits > 0, "Number of significant digits must be > 0.");
+        let inv =
+            self.rate.try_recip().map_err(|_| MoneyError::InvalidRate)?;
+        let rate =
+            round_to_sig_digits(inv, sig_digits, RoundingMode::default())?;
+        Ok(Self {
+            base: self.quote,
+            quote: self.base,
+            rate: DecimalRatio::from(rate),
+        })
+    }
+
+    /// Returns the cross rate of `self` and `other`, which must have exactly
+    /// one currency in common.
+    ///
+    /// The base currency of the result is the currency of `self` not in
+    /// common with `other`, its quote currency the currency of `other` not in
+    /// common with `self`. The resulting rate is exact, i.e. it is not
+    /// rounded.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error (wrapped in Result::Err) in the following cases:
+    /// * `self` and `other` do not have exactly one currency in common =>
+    ///   `MoneyError::CurrencyMismatch`,
+    /// * the resulting rate exceeds the internal representation =>
+    ///   `MoneyError::InternalOverflow`.
+    pub fn cross(&self, other: &Self) -> Result<Self, MoneyError> {
+        let (base, quote, rate) = if self.quote == other.base {
+            // A/B, B/C => A/C
+            (self.base, other.quote, self.rate.try_mul(other.rate))
+        } else if self.quote == other.quote {
+            // A/B, C/B => A/C
+            (self.base, other.base, self.rate.try_div(other.rate))
+        } else if self.base == other.base {
+            // A/B, A/C => B/C
+            (self.quote, other.quote, other.rate.try_div(self.rate))
+        } else if self.base == other.quote {
+            // A/B, C/A => B/C
+            (
+                self.quote,
+                other.base,
+                self.rate
+                    .try_mul(other.rate)
+                    .and_then(DecimalRatio::try_recip),
+            )
+        } else {
+            return Err(MoneyError::CurrencyMismatch);
+        };
+        if base == quote {
+            return Err(MoneyError::CurrencyMismatch);
+        }
+        let rate =
+            rate.map_err(|_: DecimalError| MoneyError::InternalOverflow)?;
+        Ok(Self { base, quote, rate })
+    }
+
+    /// Converts `amount` from the base currency of `self` into its quote
+    /// currency (or vice versa), rounding the result to `target_scale`
+    /// fractional digits according to `mode`.
+    ///
+    /// The conversion is done exactly, so rounding happens only once.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `target_scale` >
+    /// [MAX_N_FRAC_DIGITS](crate::MAX_N_FRAC_DIGITS)!
+    ///
+    /// # Errors
+    ///
+    /// Returns an error (wrapped in Result::Err) in the following cases:
+    /// * the currency of `amount` is neither the base nor the quote currency
+    ///   of `self` => `MoneyError::CurrencyMismatch`,
+    /// * the result can not be represented by `Decimal` =>
+    ///   `MoneyError::InternalOverflow`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fpdec::{
+    /// #     Currency, Dec, Decimal, ExchangeRate, Money, MoneyError,
+    /// #     RoundingMode,
+    /// # };
+    /// # fn main() -> Result<(), MoneyError> {
+    /// let eur_chf =
+    ///     ExchangeRate::new(Currency::EUR, Currency::CHF, Dec!(0.9372))?;
+    /// let m = Money::new(Dec!(250), Currency::CHF);
+    /// let res = eur_chf.convert(m, 2, RoundingMode::RoundHalfEven)?;
+    /// assert_eq!(res.to_string(), "EUR 266.75");
+    /// # Ok(()) }
+    /// ```
+    pub fn convert(
+        &self,
+        amount: Money,
+        target_scale: u8,
+        mode: RoundingMode,
+    ) -> Result<Money, MoneyError> {
+        assert!(
+            target_scale <= MAX_N_FRAC_DIGITS,
+            "{}",
+            DecimalError::MaxNFracDigitsExceeded
+        );
+        let value = DecimalRatio::from(amount.amount());
+        let (currency, res) = if amount.currency() == self.base {
+            (self.quote, value.try_mul(self.rate))
+        } else if amount.currency() == self.quote {
+            (self.base, value.try_div(self.rate))
+        } else {
+            return Err(MoneyError::CurrencyMismatch);
+        };
+        res.and_then(|r| r.to_decimal_rounded(target_scale, mode))
+            .map(|d| Money::new(d, currency))
+            .map_err(|_| MoneyError::InternalOverflow)
+    }
+}
+
+#[cfg(test)]
+mod exchange_rate_tests {
+    use super::*;
+    use crate::Dec;
+
+    fn rate(base: Currency, quote: Currency, rate: Decimal) -> ExchangeRate {
+        ExchangeRate::new(base, quote, rate).unwrap()
+    }
+
+    #[test]
+    fn test_new() {
+        let r = rate(Currency::EUR, Currency::USD, Dec!(1.0823));
+        assert_eq!(r.base(), Currency::EUR);
+        assert_eq!(r.quote(), Currency::USD);
+        assert_eq!(r.rate().to_decimal(), Ok(Dec!(1.0823)));
+        assert_eq!(
+            ExchangeRate::new(Currency::EUR, Currency::USD, Dec!(0)),
+            Err(MoneyError::InvalidRate)
+        );
+        assert_eq!(
+            ExchangeRate::new(Currency::EUR, Currency::USD, Dec!(-1.2)),
+            Err(MoneyError::InvalidRate)
+        );
+        assert_eq!(
+            ExchangeRate::new(Currency::EUR, Currency::EUR, Dec!(1)),
+            Err(MoneyError::CurrencyMismatch)
+        );
+    }
+
+    #[test]
+    fn test_invert() {
+        for (r, sig_digits, inv) in [
+            (Dec!(1.0823), 6, Dec!(0.923958)),
+            (Dec!(1.95583), 6, Dec!(0.511292)),
+            (Dec!(156.73), 6, Dec!(0.00638040)),
+            (Dec!(156.73), 1, Dec!(0.006)),
+            (Dec!(0.0001), 6, Dec!(10000)),
+            (Dec!(0.000123), 2, Dec!(8100)),
+            (Dec!(0.000123), 6, Dec!(8130.08)),
+            (Dec!(4), 3, Dec!(0.25)),
+            (Dec!(1.0000001), 6, Dec!(1)),
+            (Dec!(0.9999999), 6, Dec!(1)),
+            (Dec!(3), 18, Dec!(0.333333333333333333)),
+        ] {
+            let inverted =
+                rate(Currency::EUR, Currency::USD, r).invert(sig_digits);
+            let inverted = inverted.unwrap();
+            assert_eq!(inverted.base(), Currency::USD);
+            assert_eq!(inverted.quote(), Currency::EUR);
+            assert_eq!(inverted.rate().to_decimal(), Ok(inv), "{r}");
+        }
+        // 1 / 3 with 19 significant digits needs 19 fractional digits
+        assert_eq!(
+            rate(Currency::EUR, Currency::USD, Dec!(3)).invert(19),
+            Err(MoneyError::InternalOverflow)
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_invert_zero_sig_digits() {
+        let _ = rate(Currency::EUR, Currency::USD, Dec!(1.1)).invert(0);
+    }
+
+    #[test]
+    fn test_cross() {
+        let eur_usd = rate(Currency::EUR, Currency::USD, Dec!(1.25));
+        let usd_jpy = rate(Currency::USD, Currency::JPY, Dec!(150));
+        let eur_gbp = rate(Currency::EUR, Currency::GBP, Dec!(0.8));
+        let gbp_usd = rate(Currency::GBP, Currency::USD, Dec!(1.6));
+        let chf_eur = rate(Currency::CHF, Currency::EUR, Dec!(1.05));
+        for (a, b, base, quote, r) in [
+            (eur_usd, usd_jpy, Currency::EUR, Currency::JPY, Dec!(187.5)),
+            (eur_usd, gbp_usd, Currency::EUR, Currency::GBP, Dec!(0.7812)),
+            (eur_usd, eur_gbp, Currency::USD, Currency::GBP, Dec!(0.64)),
+            (eur_usd, chf_eur, Currency::USD, Currency::CHF, Dec!(0.7619)),
+        ] {
+            let c = a.cross(&b).unwrap();
+            assert_eq!(c.base(), base);
+            assert_eq!(c.quote(), quote);
+            assert_eq!(
+                c.rate().to_decimal_rounded(4, RoundingMode::RoundHalfEven),
+                Ok(r)
+            );
+        }
+        // USD/CHF = 1 / (1.25 * 1.05) is not representable as Decimal
+        assert_eq!(
+            eur_usd.cross(&chf_eur).unwrap().rate().as_integer_ratio(),
+            (16, 21)
+        );
+        assert_eq!(
+            eur_usd.cross(&rate(Currency::CHF, Currency::JPY, Dec!(170))),
+            Err(MoneyError::CurrencyMismatch)
+        );
+        assert_eq!(
+            eur_usd.cross(&eur_usd.invert(6).unwrap()),
+            Err(MoneyError::CurrencyMismatch)
+        );
+        assert_eq!(
+            eur_usd.cross(&eur_usd),
+            Err(MoneyError::CurrencyMismatch)
+        );
+    }
+
+    #[test]
+    fn test_convert() {
+        let eur_usd = rate(Currency::EUR, Currency::USD, Dec!(1.0823));
+        let eur = Money::new(Dec!(100), Currency::EUR);
+        let usd = Money::new(Dec!(100), Currency::USD);
+        assert_eq!(
+            eur_usd.convert(eur, 2, RoundingMode::RoundHalfEven),
+            Ok(Money::new(Dec!(108.23), Currency::USD))
+        );
+        assert_eq!(
+            eur_usd.convert(usd, 2, RoundingMode::RoundHalfEven),
+            Ok(Money::new(Dec!(92.40), Currency::EUR))
+        );
+        assert_eq!(
+            eur_usd.convert(usd, 2, RoundingMode::RoundDown),
+            Ok(Money::new(Dec!(92.39), Currency::EUR))
+        );
+        assert_eq!(
+            eur_usd.convert(usd, 6, RoundingMode::RoundHalfEven),
+            Ok(Money::new(Dec!(92.395824), Currency::EUR))
+        );
+        assert_eq!(
+            eur_usd.convert(
+                Money::new(Dec!(1), Currency::GBP),
+                2,
+                RoundingMode::RoundUp
+            ),
+            Err(MoneyError::CurrencyMismatch)
+        );
+        assert_eq!(
+            rate(Currency::EUR, Currency::USD, Dec!(1000)).convert(
+                Money::new(Decimal::MAX, Currency::EUR),
+                0,
+                RoundingMode::RoundUp
+            ),
+            Err(MoneyError::InternalOverflow)
+        );
+    }
+
+    #[test]
+    fn test_triangulation_without_intermediate_rounding() {
+        // historic conversion rates of DEM and FRF per euro; as these
+        // currencies are no longer part of ISO 4217, CHE and CHW are used
+        // instead
+        let eur_dem = rate(Currency::EUR, Currency::CHE, Dec!(1.95583));
+        let eur_frf = rate(Currency::EUR, Currency::CHW, Dec!(6.55957));
+        let dem_frf = eur_dem.cross(&eur_frf).unwrap();
+        let amount = Money::new(Dec!(100), Currency::CHE);
+        // exact: 335.38548851382788...
+        assert_eq!(
+            dem_frf.convert(amount, 2, RoundingMode::RoundHalfUp),
+            Ok(Money::new(Dec!(335.39), Currency::CHW))
+        );
+        assert_eq!(
+            dem_frf.convert(amount, 10, RoundingMode::RoundHalfUp),
+            Ok(Money::new(Dec!(335.3854885138), Currency::CHW))
+        );
+        // exact: 6.70770977...
+        let amount = Money::new(Dec!(2), Currency::CHE);
+        assert_eq!(
+            dem_frf.convert(amount, 2, RoundingMode::RoundHalfUp),
+            Ok(Money::new(Dec!(6.71), Currency::CHW))
+        );
+        // rounding the intermediate amount in euro gives a different result
+        let intermediate = eur_dem
+            .convert(amount, 2, RoundingMode::RoundHalfUp)
+            .unwrap();
+        assert_eq!(intermediate.amount(), Dec!(1.02));
+        assert_eq!(
+            eur_frf.convert(intermediate, 2, RoundingMode::RoundHalfUp),
+            Ok(Money::new(Dec!(6.69), Currency::CHW))
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_convert_invalid_scale() {
+        let _ = rate(Currency::EUR, Currency::USD, Dec!(1.1)).convert(
+            Money::new(Dec!(1), Currency::EUR),
+            19,
+            RoundingMode::RoundUp,
+        );
+    }
+}
@@ -7,41 +7,194 @@
 // $Source$
 // $Revision$
 
-use alloc::{
-    format,
-    string::{String, ToString},
-};
+#[cfg(feature = "alloc")]
+use alloc::string::String;
 use core::{
     cmp::{min, Ordering},
     fmt,
 };
 
-use fpdec_core::{i128_div_mod_floor, i128_div_rounded, ten_pow};
+use fpdec_core::{
+    i128_div_mod_floor, i128_div_rounded, i128_magnitude, ten_pow,
+};
 
 #[cfg(feature = "rkyv")]
 use crate::ArchivedDecimal;
-use crate::{Decimal, MAX_N_FRAC_DIGITS};
+use crate::{Decimal, RoundingMode, MAX_N_FRAC_DIGITS};
+
+/// Maximum number of bytes needed to represent a `Decimal` as string,
+/// i.e. sign, 39 integral digits, decimal point and
+/// [MAX_N_FRAC_DIGITS](crate::MAX_N_FRAC_DIGITS) fractional digits.
+pub(crate) const MAX_STR_LEN: usize = 59;
+
+/// Writes the absolute value given by `coeff` and `n_frac_digits`, rounded
+/// to `prec` fractional digits according to `mode` (or the default rounding
+/// mode if `mode` is None), right aligned into `buf`, and returns the index
+/// of its first byte.
+///
+/// As the absolute value occupies at most `MAX_STR_LEN` - 1 bytes, there is
+/// always room left for a sign in front of it, if `buf` has a length of at
+/// least `MAX_STR_LEN`.
+pub(crate) fn write_abs_to_buf(
+    coeff: i128,
+    n_frac_digits: u8,
+    prec: u8,
+    mode: Option<RoundingMode>,
+    buf: &mut [u8],
+) -> usize {
+    let (int, frac) = abs_int_and_frac(coeff, n_frac_digits, prec, mode);
+    let (mut int, mut frac) = (int.unsigned_abs(), frac.unsigned_abs());
+    let mut pos = buf.len();
+    if prec > 0 {
+        for _ in 0..prec {
+            pos -= 1;
+            // frac % 10 < 10, so the cast is safe
+            #[allow(clippy::cast_possible_truncation)]
+            {
+                buf[pos] = b'0' + (frac % 10) as u8;
+            }
+            #[allow(clippy::integer_division)]
+            {
+                frac /= 10;
+            }
+        }
+        pos -= 1;
+        buf[pos] = b'.';
+    }
+    loop {
+        pos -= 1;
+        // int % 10 < 10, so the cast is safe
+        #[allow(clippy::cast_possible_truncation)]
+        {
+            buf[pos] = b'0' + (int % 10) as u8;
+        }
+        #[allow(clippy::integer_division)]
+        {
+            int /= 10;
+        }
+        if int == 0 {
+            return pos;
+        }
+    }
+}
+
+/// Returns the bytes written into `buf` from index `start` as `&str`.
+pub(crate) fn buf_as_str(buf: &[u8], start: usize) -> &str {
+    // only ASCII characters and complete UTF-8 encoded strings are written
+    // into the buffer, so the conversion can't fail
+    core::str::from_utf8(&buf[start..]).unwrap_or_default()
+}
+
+/// Returns `s`, a number with `n_frac_digits` fractional digits, with
+/// trailing fractional zeros removed, but keeping at least `min_frac_digits`
+/// fractional digits. The decimal point is removed together with the last
+/// fractional digit.
+fn trim_frac_zeros(s: &str, n_frac_digits: u8, min_frac_digits: u8) -> &str {
+    let bytes = s.as_bytes();
+    let mut end = bytes.len();
+    let mut n = n_frac_digits;
+    while n > min_frac_digits && bytes[end - 1] == b'0' {
+        end -= 1;
+        n -= 1;
+    }
+    if n == 0 && n_frac_digits > 0 {
+        // remove decimal point
+        end -= 1;
+    }
+    &s[..end]
+}
+
+/// Formats the value given by `coeff` and `n_frac_digits`, rounded to
+/// `prec` fractional digits according to `mode` (or the default rounding
+/// mode if `mode` is None), with trailing fractional zeros beyond
+/// `min_frac_digits` removed.
+fn fmt_with_frac_digits(
+    form: &mut fmt::Formatter<'_>,
+    coeff: i128,
+    n_frac_digits: u8,
+    prec: u8,
+    min_frac_digits: u8,
+    mode: Option<RoundingMode>,
+) -> fmt::Result {
+    let mut buf = [0_u8; MAX_STR_LEN];
+    let start = write_abs_to_buf(coeff, n_frac_digits, prec, mode, &mut buf);
+    let tmp = trim_frac_zeros(buf_as_str(&buf, start), prec, min_frac_digits);
+    form.pad_integral(coeff >= 0, "", tmp)
+}
+
+impl Decimal {
+    /// Size of the buffer needed by [Decimal::write_to_buf] and
+    /// [Decimal::format_with_precision_to_buf].
+    pub const MAX_STR_LEN: usize = MAX_STR_LEN;
+
+    /// Writes the string representation of `self` into `buf` and returns it
+    /// as `&str`, without any heap allocation.
+    ///
+    /// The result equals `self.to_string()`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fpdec::{Dec, Decimal};
+    /// let mut buf = [0_u8; Decimal::MAX_STR_LEN];
+    /// let d = Dec!(-1234.560);
+    /// assert_eq!(d.write_to_buf(&mut buf), "-1234.560");
+    /// ```
+    #[must_use]
+    pub fn write_to_buf<'a>(
+        &self,
+        buf: &'a mut [u8; MAX_STR_LEN],
+    ) -> &'a str {
+        self.format_with_precision_to_buf(self.n_frac_digits, buf)
+    }
+
+    /// Writes the string representation of `self` with `prec` fractional
+    /// digits into `buf` and returns it as `&str`, without any heap
+    /// allocation.
+    ///
+    /// If `prec` is less than `self.n_frac_digits()`, the value gets rounded
+    /// according to the default rounding mode. A `prec` greater than
+    /// [MAX_N_FRAC_DIGITS](crate::MAX_N_FRAC_DIGITS) is reduced to that
+    /// limit. The result equals `format!("{:.prec$}", self)`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fpdec::{Dec, Decimal};
+    /// let mut buf = [0_u8; Decimal::MAX_STR_LEN];
+    /// let d = Dec!(-1234.567);
+    /// assert_eq!(d.format_with_precision_to_buf(2, &mut buf), "-1234.57");
+    /// assert_eq!(d.format_with_precision_to_buf(5, &mut buf), "-1234.56700");
+    /// ```
+    #[must_use]
+    pub fn format_with_precision_to_buf<'a>(
+        &self,
+        prec: u8,
+        buf: &'a mut [u8; MAX_STR_LEN],
+    ) -> &'a str {
+        let prec = min(prec, MAX_N_FRAC_DIGITS);
+        let mut start =
+            write_abs_to_buf(self.coeff, self.n_frac_digits, prec, None, buf);
+        if self.coeff < 0 {
+            start -= 1;
+            buf[start] = b'-';
+        }
+        buf_as_str(buf, start)
+    }
+}
 
+#[cfg(feature = "alloc")]
 impl From<Decimal> for String {
     fn from(d: Decimal) -> Self {
-        if d.n_frac_digits == 0 {
-            format!("{}", d.coefficient())
-        } else {
-            let (int, frac) =
-                i128_div_mod_floor(d.coeff.abs(), ten_pow(d.n_frac_digits));
-            format!(
-                "{}{}.{:0width$}",
-                if d.coeff >= 0 { "" } else { "-" },
-                int,
-                frac,
-                width = d.n_frac_digits() as usize
-            )
-        }
+        let mut buf = [0_u8; MAX_STR_LEN];
+        Self::from(d.write_to_buf(&mut buf))
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "alloc"))]
 mod test_into_string {
+    use alloc::format;
+
     use super::*;
     use crate::Dec;
 
@@ -59,26 +212,76 @@ mod test_into_string {
     }
 }
 
+#[cfg(test)]
+mod test_write_to_buf {
+    use alloc::{format, string::ToString};
+
+    use super::*;
+    use crate::Dec;
+
+    #[test]
+    fn test_write_to_buf() {
+        let mut buf = [0_u8; Decimal::MAX_STR_LEN];
+        for d in [
+            Dec!(0),
+            Dec!(-0.000),
+            Dec!(7),
+            Dec!(-1234567890123456789000.00700),
+            Decimal::new_raw(1, 18),
+            Decimal::new_raw(-1, 18),
+            Decimal::MAX,
+            Decimal::MIN,
+            Decimal::new_raw(i128::MAX, 18),
+            Decimal::new_raw(i128::MIN + 1, 18),
+        ] {
+            assert_eq!(d.write_to_buf(&mut buf), d.to_string());
+        }
+        let d = Decimal::new_raw(1, 18);
+        assert_eq!(d.write_to_buf(&mut buf), "0.000000000000000001");
+    }
+
+    #[test]
+    fn test_format_with_precision_to_buf() {
+        let mut buf = [0_u8; Decimal::MAX_STR_LEN];
+        for (d, prec, s) in [
+            (Dec!(0), 2, "0.00"),
+            (Dec!(-1234.5678), 0, "-1235"),
+            (Dec!(-1234.5678), 2, "-1234.57"),
+            (Dec!(0.125), 2, "0.12"),
+            (Dec!(-0.0012347), 6, "-0.001235"),
+            (Dec!(-0.004), 2, "-0.00"),
+            (Dec!(17), 3, "17.000"),
+            (Dec!(1.5), 40, "1.500000000000000000"),
+        ] {
+            assert_eq!(d.format_with_precision_to_buf(prec, &mut buf), s);
+            assert_eq!(format!("{d:.*}", prec as usize), s);
+        }
+        let d = Decimal::MIN;
+        assert_eq!(
+            d.format_with_precision_to_buf(18, &mut buf).len(),
+            Decimal::MAX_STR_LEN
+        );
+    }
+}
+
 macro_rules! impl_debug {
     ($ty:ty, $tag:literal) => {
         impl fmt::Debug for $ty {
             fn fmt(&self, form: &mut fmt::Formatter<'_>) -> fmt::Result {
-                if self.n_frac_digits == 0 {
-                    write!(form, concat!($tag, "({})"), self.coefficient())
-                } else {
-                    let (int, frac) = i128_div_mod_floor(
-                        self.coeff.abs(),
-                        ten_pow(self.n_frac_digits),
-                    );
-                    write!(
-                        form,
-                        concat!($tag, "({}{}.{:0width$})"),
-                        if self.coeff >= 0 { "" } else { "-" },
-                        int,
-                        frac,
-                        width = self.n_frac_digits as usize
-                    )
-                }
+                let mut buf = [0_u8; MAX_STR_LEN];
+                let start = write_abs_to_buf(
+                    self.coefficient(),
+                    self.n_frac_digits(),
+                    self.n_frac_digits(),
+                    None,
+                    &mut buf,
+                );
+                write!(
+                    form,
+                    concat!($tag, "({}{})"),
+                    if self.coefficient() >= 0 { "" } else { "-" },
+                    buf_as_str(&buf, start)
+                )
             }
         }
     };
@@ -90,6 +293,8 @@ impl_debug!(ArchivedDecimal, "ArchivedDecimal");
 
 #[cfg(test)]
 mod test_fmt_debug {
+    use alloc::format;
+
     use super::*;
     use crate::Dec;
 
@@ -112,81 +317,211 @@ mod test_fmt_debug {
     }
 }
 
-impl fmt::Display for Decimal {
-    /// Formats the value using the given formatter.
-    ///
-    /// If the format specifies less fractional digits than
-    /// `self.n_frac_digits()`, the value gets rounded according to the
-    /// default rounding mode.
-    ///
-    /// # Examples:
+/// Returns the integral part and the fractional digits (as int) of the
+/// absolute value of `coeff` / 10 ^ `n_frac_digits`, after rounding it to
+/// `prec` fractional digits according to `mode` (or the default rounding
+/// mode, if `mode` is `None`).
+pub(crate) fn abs_int_and_frac(
+    coeff: i128,
+    n_frac_digits: u8,
+    prec: u8,
+    mode: Option<RoundingMode>,
+) -> (i128, i128) {
+    match prec.cmp(&n_frac_digits) {
+        Ordering::Equal => {
+            i128_div_mod_floor(coeff.abs(), ten_pow(n_frac_digits))
+        }
+        Ordering::Less => {
+            // Important: first round, then take abs() !
+            let coeff =
+                i128_div_rounded(coeff, ten_pow(n_frac_digits - prec), mode);
+            i128_div_mod_floor(coeff.abs(), ten_pow(prec))
+        }
+        Ordering::Greater => {
+            let (int, frac) =
+                i128_div_mod_floor(coeff.abs(), ten_pow(n_frac_digits));
+            (int, frac * ten_pow(prec - n_frac_digits))
+        }
+    }
+}
+
+macro_rules! impl_display {
+    ($ty:ty) => {
+        impl fmt::Display for $ty {
+            /// Formats the value using the given formatter.
+            ///
+            /// If the format specifies less fractional digits than
+            /// `self.n_frac_digits()`, the value gets rounded according to
+            /// the default rounding mode. If the alternate flag `#` is given,
+            /// trailing fractional zeros are removed.
+            ///
+            /// # Examples:
+            ///
+            /// ```rust
+            /// # use core::fmt;
+            /// # use fpdec::{Dec, Decimal};
+            /// let d = Dec!(-1234.56);
+            /// assert_eq!(format!("{}", d), "-1234.56");
+            /// assert_eq!(format!("{:014.3}", d), "-000001234.560");
+            /// assert_eq!(format!("{:10.1}", d), "   -1234.6");
+            /// assert_eq!(format!("{:#}", Dec!(1.500)), "1.5");
+            /// assert_eq!(format!("{:#.3}", Dec!(2.0004)), "2");
+            /// ```
+            fn fmt(&self, form: &mut fmt::Formatter<'_>) -> fmt::Result {
+                let n_frac_digits = self.n_frac_digits();
+                #[allow(clippy::cast_possible_truncation)]
+                let prec = match form.precision() {
+                    Some(prec) => min(prec, MAX_N_FRAC_DIGITS as usize) as u8,
+                    None => n_frac_digits,
+                };
+                let min_frac_digits = if form.alternate() { 0 } else { prec };
+                fmt_with_frac_digits(
+                    form,
+                    self.coefficient(),
+                    n_frac_digits,
+                    prec,
+                    min_frac_digits,
+                    None,
+                )
+            }
+        }
+    };
+}
+
+impl_display!(Decimal);
+#[cfg(feature = "rkyv")]
+impl_display!(ArchivedDecimal);
+
+/// Wrapper used to format a `Decimal` with a configurable number of
+/// fractional digits.
+///
+/// Created by [`Decimal::display_options`]. The value is shown with at least
+/// `min_frac_digits` (default: 0) and at most `max_frac_digits` (default:
+/// `n_frac_digits` of the value) fractional digits, both limited to
+/// [MAX_N_FRAC_DIGITS](crate::MAX_N_FRAC_DIGITS). If the value has more
+/// fractional digits than `max_frac_digits`, it gets rounded according to
+/// the current or an explicitly given [RoundingMode]. If it has less than
+/// `min_frac_digits`, it is padded with zeros. If `trim_zeros` is set or the
+/// alternate flag `#` is given, trailing fractional zeros beyond
+/// `min_frac_digits` are removed. Width, fill, alignment and sign given in
+/// the format are applied as for `Decimal`, a precision is ignored.
+///
+/// # Examples:
+///
+/// ```rust
+/// # use fpdec::{Dec, Decimal, RoundingMode};
+/// let d = Dec!(1.500);
+/// assert_eq!(d.display_options().trim_zeros(true).to_string(), "1.5");
+/// let opts = d.display_options().min_frac_digits(2).trim_zeros(true);
+/// assert_eq!(opts.to_string(), "1.50");
+/// let d = Dec!(-3.14159);
+/// let opts = d.display_options().max_frac_digits(3);
+/// assert_eq!(format!("{opts:>8}"), "  -3.142");
+/// let opts = opts.rounding_mode(RoundingMode::RoundDown);
+/// assert_eq!(opts.to_string(), "-3.141");
+/// ```
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct DisplayOptions {
+    value: Decimal,
+    min_frac_digits: u8,
+    max_frac_digits: Option<u8>,
+    trim_zeros: bool,
+    rounding_mode: Option<RoundingMode>,
+}
+
+impl DisplayOptions {
+    /// Returns `self` showing at least `n` fractional digits.
     ///
-    /// ```rust
-    /// # use core::fmt;
-    /// # use fpdec::{Dec, Decimal};
-    /// let d = Dec!(-1234.56);
-    /// assert_eq!(format!("{}", d), "-1234.56");
-    /// assert_eq!(format!("{:014.3}", d), "-000001234.560");
-    /// assert_eq!(format!("{:10.1}", d), "   -1234.6");
-    /// ```
+    /// If `n` exceeds `max_frac_digits`, the latter is raised to `n`.
+    #[must_use]
+    pub const fn min_frac_digits(mut self, n: u8) -> Self {
+        self.min_frac_digits = n;
+        self
+    }
+
+    /// Returns `self` showing at most `n` fractional digits.
+    #[must_use]
+    pub const fn max_frac_digits(mut self, n: u8) -> Self {
+        self.max_frac_digits = Some(n);
+        self
+    }
+
+    /// Returns `self` with trailing fractional zeros beyond
+    /// `min_frac_digits` being removed or not, depending on `trim`.
+    #[must_use]
+    pub const fn trim_zeros(mut self, trim: bool) -> Self {
+        self.trim_zeros = trim;
+        self
+    }
+
+    /// Returns `self` with the value rounded according to `mode` instead of
+    /// the current [RoundingMode].
+    #[must_use]
+    pub const fn rounding_mode(mut self, mode: RoundingMode) -> Self {
+        self.rounding_mode = Some(mode);
+        self
+    }
+}
+
+impl fmt::Display for DisplayOptions {
     fn fmt(&self, form: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let tmp: String;
-        #[allow(clippy::cast_possible_truncation)]
-        let prec = match form.precision() {
-            Some(prec) => min(prec, MAX_N_FRAC_DIGITS as usize) as u8,
-            None => self.n_frac_digits,
-        };
-        if self.n_frac_digits == 0 {
-            if prec > 0 {
-                tmp = format!(
-                    "{}.{:0width$}",
-                    self.coeff.abs(),
-                    0,
-                    width = prec as usize
-                );
-            } else {
-                tmp = self.coeff.abs().to_string();
-            }
+        let n_frac_digits = self.value.n_frac_digits;
+        let min_frac_digits = min(self.min_frac_digits, MAX_N_FRAC_DIGITS);
+        let max_frac_digits = self
+            .max_frac_digits
+            .map_or(n_frac_digits, |n| min(n, MAX_N_FRAC_DIGITS))
+            .max(min_frac_digits);
+        let prec = n_frac_digits.clamp(min_frac_digits, max_frac_digits);
+        let min_frac_digits = if self.trim_zeros || form.alternate() {
+            min_frac_digits
         } else {
-            let (int, frac) = match prec.cmp(&(self.n_frac_digits)) {
-                Ordering::Equal => i128_div_mod_floor(
-                    self.coeff.abs(),
-                    ten_pow(self.n_frac_digits),
-                ),
-                Ordering::Less => {
-                    // Important: first round, then take abs() !
-                    let coeff = i128_div_rounded(
-                        self.coeff,
-                        ten_pow(self.n_frac_digits - prec),
-                        None,
-                    );
-                    i128_div_mod_floor(coeff.abs(), ten_pow(prec))
-                }
-                Ordering::Greater => {
-                    let (int, frac) = i128_div_mod_floor(
-                        self.coeff.abs(),
-                        ten_pow(self.n_frac_digits),
-                    );
-                    (int, frac * ten_pow(prec - self.n_frac_digits))
-                }
-            };
-            if prec > 0 {
-                tmp = format!(
-                    "{}.{:0width$}",
-                    int,
-                    frac,
-                    width = prec as usize
-                );
-            } else {
-                tmp = int.to_string();
-            }
+            prec
+        };
+        fmt_with_frac_digits(
+            form,
+            self.value.coeff,
+            n_frac_digits,
+            prec,
+            min_frac_digits,
+            self.rounding_mode,
+        )
+    }
+}
+
+impl Decimal {
+    /// Returns a wrapper formatting `self` with a configurable number of
+    /// fractional digits.
+    ///
+    /// See [DisplayOptions] for details.
+    #[must_use]
+    pub const fn display_options(self) -> DisplayOptions {
+        DisplayOptions {
+            value: self,
+            min_frac_digits: 0,
+            max_frac_digits: None,
+            trim_zeros: false,
+            rounding_mode: None,
         }
-        form.pad_integral(self.coeff >= 0, "", &tmp)
+    }
+}
+
+#[cfg(feature = "rkyv")]
+impl ArchivedDecimal {
+    /// Returns a wrapper formatting `self` with a configurable number of
+    /// fractional digits.
+    ///
+    /// See [DisplayOptions] for details.
+    #[must_use]
+    pub const fn display_options(self) -> DisplayOptions {
+        Decimal::new_raw(self.coefficient(), self.n_frac_digits())
+            .display_options()
     }
 }
 
 #[cfg(test)]
 mod test_fmt_display {
+    use alloc::{format, string::ToString};
+
     use super::*;
     use crate::Dec;
 
@@ -242,4 +577,878 @@ mod test_fmt_display {
         assert_eq!(format!("{:10.5}", d), "  -0.00123");
         assert_eq!(format!("{:010.6}", d), "-00.001235");
     }
+
+    #[test]
+    fn test_fmt_alternate() {
+        for (d, s) in [
+            (Dec!(1.500), "1.5"),
+            (Dec!(-1.500), "-1.5"),
+            (Dec!(2.000), "2"),
+            (Dec!(1200), "1200"),
+            (Dec!(0.000), "0"),
+            (Dec!(0.00012), "0.00012"),
+        ] {
+            assert_eq!(format!("{:#}", d), s);
+        }
+        let d = Dec!(-2.0004);
+        assert_eq!(format!("{:#.3}", d), "-2");
+        assert_eq!(format!("{:#.4}", d), "-2.0004");
+        assert_eq!(format!("{:#.6}", d), "-2.0004");
+        assert_eq!(format!("{:>#8.1}", d), "      -2");
+        assert_eq!(format!("{:#08.3}", Dec!(1.25)), "00001.25");
+        assert_eq!(format!("{:+#}", Dec!(1.50)), "+1.5");
+    }
+}
+
+#[cfg(test)]
+mod test_display_options {
+    use alloc::{format, string::ToString};
+
+    use super::*;
+    use crate::Dec;
+
+    #[test]
+    fn test_frac_digits() {
+        let d = Dec!(1.500);
+        for (min_n, max_n, s) in [
+            (0, None, "1.500"),
+            (0, Some(1), "1.5"),
+            (0, Some(0), "2"),
+            (0, Some(5), "1.500"),
+            (2, None, "1.500"),
+            (5, None, "1.50000"),
+            (5, Some(2), "1.50000"),
+            (30, None, "1.500000000000000000"),
+        ] {
+            let mut opts = d.display_options().min_frac_digits(min_n);
+            if let Some(max_n) = max_n {
+                opts = opts.max_frac_digits(max_n);
+            }
+            assert_eq!(opts.to_string(), s, "{min_n} {max_n:?}");
+        }
+        let opts = Dec!(1.23456).display_options();
+        assert_eq!(opts.max_frac_digits(3).to_string(), "1.235");
+        assert_eq!(
+            opts.min_frac_digits(4).max_frac_digits(2).to_string(),
+            "1.2346"
+        );
+        assert_eq!(
+            Dec!(-7).display_options().min_frac_digits(2).to_string(),
+            "-7.00"
+        );
+    }
+
+    #[test]
+    fn test_trim_zeros() {
+        for (d, min_n, s) in [
+            (Dec!(1.500), 0, "1.5"),
+            (Dec!(1.500), 2, "1.50"),
+            (Dec!(1.500), 4, "1.5000"),
+            (Dec!(-3.000), 0, "-3"),
+            (Dec!(-3.000), 1, "-3.0"),
+            (Dec!(0.000), 0, "0"),
+            (Dec!(1200), 0, "1200"),
+        ] {
+            let opts = d.display_options().min_frac_digits(min_n);
+            assert_eq!(opts.trim_zeros(true).to_string(), s, "{d} {min_n}");
+            assert_eq!(format!("{opts:#}"), s, "{d} {min_n}");
+        }
+        let opts = Dec!(2.0004).display_options().max_frac_digits(3);
+        assert_eq!(opts.trim_zeros(true).to_string(), "2");
+        assert_eq!(opts.trim_zeros(false).to_string(), "2.000");
+    }
+
+    #[test]
+    fn test_rounding_mode() {
+        let d = Dec!(-0.125);
+        for (mode, s) in [
+            (RoundingMode::RoundHalfEven, "-0.12"),
+            (RoundingMode::RoundHalfUp, "-0.13"),
+            (RoundingMode::RoundCeiling, "-0.12"),
+            (RoundingMode::RoundFloor, "-0.13"),
+            (RoundingMode::RoundDown, "-0.12"),
+            (RoundingMode::RoundUp, "-0.13"),
+        ] {
+            let opts =
+                d.display_options().max_frac_digits(2).rounding_mode(mode);
+            assert_eq!(opts.to_string(), s, "{mode:?}");
+        }
+    }
+
+    #[test]
+    fn test_formatter_flags() {
+        let opts = Dec!(-1.5).display_options().min_frac_digits(2);
+        assert_eq!(format!("{opts:>8}"), "   -1.50");
+        assert_eq!(format!("{opts:<8}|"), "-1.50   |");
+        assert_eq!(format!("{opts:08}"), "-0001.50");
+        assert_eq!(format!("{opts:.5}"), "-1.50");
+        let opts = Dec!(1.5).display_options();
+        assert_eq!(format!("{opts:+}"), "+1.5");
+    }
+}
+
+#[cfg(feature = "rkyv")]
+#[cfg(test)]
+mod rkyv_fmt_tests {
+    use alloc::{format, string::ToString};
+
+    use super::*;
+    use crate::Dec;
+
+    #[test]
+    fn test_archived_decimal() {
+        let d = Dec!(-1234.5600);
+        let bytes = rkyv::to_bytes::<_, 256>(&d).unwrap();
+        let a = rkyv::check_archived_root::<Decimal>(&bytes[..]).unwrap();
+        assert_eq!(a.to_string(), "-1234.5600");
+        assert_eq!(format!("{a:#}"), "-1234.56");
+        assert_eq!(format!("{a:>10.1}"), "   -1234.6");
+        let opts = a.display_options().max_frac_digits(1).trim_zeros(true);
+        assert_eq!(
+            opts.to_string(),
+            d.display_options().max_frac_digits(1).to_string()
+        );
+        assert_eq!(format!("{a:?}"), "ArchivedDecimal(-1234.5600)");
+    }
+}
+
+/// Part of a formatted number: either a string or a run of zeros.
+#[derive(Clone, Copy)]
+pub(crate) enum Part<'a> {
+    Str(&'a str),
+    Zeros(usize),
+}
+
+impl Part<'_> {
+    const fn len(&self) -> usize {
+        match self {
+            Part::Str(s) => s.len(),
+            Part::Zeros(n) => *n,
+        }
+    }
+}
+
+fn write_n_chars(
+    form: &mut fmt::Formatter<'_>,
+    c: char,
+    n: usize,
+) -> fmt::Result {
+    for _ in 0..n {
+        fmt::Write::write_char(form, c)?;
+    }
+    Ok(())
+}
+
+/// Writes the concatenation of `parts`, preceded by a sign and padded
+/// according to the flags of `form`, like `fmt::Formatter::pad_integral`
+/// does for a single string, but without the need to build that string.
+pub(crate) fn pad_integral_parts(
+    form: &mut fmt::Formatter<'_>,
+    is_nonnegative: bool,
+    parts: &[Part<'_>],
+) -> fmt::Result {
+    let sign = if !is_nonnegative {
+        "-"
+    } else if form.sign_plus() {
+        "+"
+    } else {
+        ""
+    };
+    let len = sign.len() + parts.iter().map(Part::len).sum::<usize>();
+    let n_pad = form.width().map_or(0, |width| width.saturating_sub(len));
+    let (fill, n_pre, n_post) = if form.sign_aware_zero_pad() {
+        form.write_str(sign)?;
+        ('0', n_pad, 0)
+    } else {
+        match form.align() {
+            Some(fmt::Alignment::Left) => (form.fill(), 0, n_pad),
+            // integer division intended
+            #[allow(clippy::integer_division)]
+            Some(fmt::Alignment::Center) => {
+                (form.fill(), n_pad / 2, n_pad.div_ceil(2))
+            }
+            _ => (form.fill(), n_pad, 0),
+        }
+    };
+    write_n_chars(form, fill, n_pre)?;
+    if !form.sign_aware_zero_pad() {
+        form.write_str(sign)?;
+    }
+    for part in parts {
+        match *part {
+            Part::Str(s) => form.write_str(s)?,
+            Part::Zeros(n) => write_n_chars(form, '0', n)?,
+        }
+    }
+    write_n_chars(form, fill, n_post)
+}
+
+/// Returns the parts of the number given by the significant `digits`,
+/// followed by `n_zeros` zeros, with a decimal point after `n_int_digits`
+/// digits (or filled up with zeros to `n_int_digits` digits).
+fn point_parts(
+    digits: &str,
+    n_zeros: usize,
+    n_int_digits: usize,
+) -> [Part<'_>; 4] {
+    let n_digits = digits.len();
+    if n_digits + n_zeros <= n_int_digits {
+        [
+            Part::Str(digits),
+            Part::Zeros(n_int_digits - n_digits),
+            Part::Str(""),
+            Part::Zeros(0),
+        ]
+    } else if n_int_digits <= n_digits {
+        let (int, frac) = digits.split_at(n_int_digits);
+        [
+            Part::Str(int),
+            Part::Str("."),
+            Part::Str(frac),
+            Part::Zeros(n_zeros),
+        ]
+    } else {
+        [
+            Part::Str(digits),
+            Part::Zeros(n_int_digits - n_digits),
+            Part::Str("."),
+            Part::Zeros(n_digits + n_zeros - n_int_digits),
+        ]
+    }
+}
+
+/// Writes the significant digits of the value given by `coeff` and
+/// `n_frac_digits`, rounded to `prec` + 1 digits (if given) according to
+/// `mode` (or the default rounding mode if `mode` is None), right aligned
+/// into `buf`.
+///
+/// Returns the index of the first digit, the number of zeros to be appended
+/// to get `prec` + 1 digits and the exponent of the first digit.
+#[allow(clippy::cast_possible_truncation)]
+#[allow(clippy::cast_possible_wrap)]
+fn sci_digits(
+    coeff: i128,
+    n_frac_digits: u8,
+    prec: Option<usize>,
+    mode: Option<RoundingMode>,
+    buf: &mut [u8; MAX_STR_LEN],
+) -> (usize, usize, i32) {
+    let mut coeff = coeff;
+    let mut exp = -i32::from(n_frac_digits);
+    if coeff == 0 {
+        exp = 0;
+    } else {
+        while coeff % 10 == 0 {
+            coeff /= 10;
+            exp += 1;
+        }
+    }
+    let mut n_digits = usize::from(i128_magnitude(coeff)) + 1;
+    if let Some(prec) = prec {
+        if n_digits > prec + 1 {
+            // n_digits <= 39, so the cast is safe
+            let shift = (n_digits - prec - 1) as u8;
+            coeff = i128_div_rounded(coeff, ten_pow(shift), mode);
+            exp += i32::from(shift);
+            n_digits = prec + 1;
+            if coeff.unsigned_abs() == ten_pow(n_digits as u8).unsigned_abs()
+            {
+                coeff /= 10;
+                exp += 1;
+            }
+        }
+    }
+    // n_digits <= 39, so the cast is safe
+    exp += n_digits as i32 - 1;
+    let start = write_abs_to_buf(coeff, 0, 0, None, buf);
+    let n_zeros = prec.map_or(0, |prec| prec + 1 - n_digits);
+    (start, n_zeros, exp)
+}
+
+/// Formats the significant `digits`, followed by `n_zeros` zeros, with
+/// `n_int_digits` integral digits, followed by the exponent `exp` marked by
+/// `exp_char`.
+fn fmt_sci(
+    form: &mut fmt::Formatter<'_>,
+    is_nonnegative: bool,
+    digits: &str,
+    n_zeros: usize,
+    n_int_digits: usize,
+    exp: i32,
+    exp_char: u8,
+) -> fmt::Result {
+    // exp_char, sign and at most 10 digits
+    let mut exp_buf = [0_u8; 12];
+    let mut start =
+        write_abs_to_buf(i128::from(exp), 0, 0, None, &mut exp_buf);
+    if exp < 0 {
+        start -= 1;
+        exp_buf[start] = b'-';
+    }
+    start -= 1;
+    exp_buf[start] = exp_char;
+    let [p0, p1, p2, p3] = point_parts(digits, n_zeros, n_int_digits);
+    pad_integral_parts(
+        form,
+        is_nonnegative,
+        &[p0, p1, p2, p3, Part::Str(buf_as_str(&exp_buf, start))],
+    )
+}
+
+macro_rules! impl_exp {
+    ($ty:ty) => {
+        impl fmt::LowerExp for $ty {
+            /// Formats the value in scientific notation, using 'e' to mark
+            /// the exponent.
+            ///
+            /// If the format specifies a precision, the value gets rounded
+            /// to `precision` + 1 significant digits according to the
+            /// default rounding mode. Otherwise all significant digits are
+            /// shown.
+            ///
+            /// # Examples:
+            ///
+            /// ```rust
+            /// # use fpdec::{Dec, Decimal};
+            /// let d = Dec!(-1234.500);
+            /// assert_eq!(format!("{:e}", d), "-1.2345e3");
+            /// assert_eq!(format!("{:.2e}", d), "-1.23e3");
+            /// assert_eq!(format!("{:>10.1e}", Dec!(0.00098)), "    9.8e-4");
+            /// ```
+            fn fmt(&self, form: &mut fmt::Formatter<'_>) -> fmt::Result {
+                let coeff = self.coefficient();
+                let mut buf = [0_u8; MAX_STR_LEN];
+                let (start, n_zeros, exp) = sci_digits(
+                    coeff,
+                    self.n_frac_digits(),
+                    form.precision(),
+                    None,
+                    &mut buf,
+                );
+                fmt_sci(
+                    form,
+                    coeff >= 0,
+                    buf_as_str(&buf, start),
+                    n_zeros,
+                    1,
+                    exp,
+                    b'e',
+                )
+            }
+        }
+
+        impl fmt::UpperExp for $ty {
+            /// Formats the value in scientific notation, using 'E' to mark
+            /// the exponent.
+            ///
+            /// If the format specifies a precision, the value gets rounded
+            /// to `precision` + 1 significant digits according to the
+            /// default rounding mode. Otherwise all significant digits are
+            /// shown.
+            ///
+            /// # Examples:
+            ///
+            /// ```rust
+            /// # use fpdec::{Dec, Decimal};
+            /// let d = Dec!(-1234.500);
+            /// assert_eq!(format!("{:E}", d), "-1.2345E3");
+            /// assert_eq!(format!("{:010.3E}", d), "-001.234E3");
+            /// ```
+            fn fmt(&self, form: &mut fmt::Formatter<'_>) -> fmt::Result {
+                let coeff = self.coefficient();
+                let mut buf = [0_u8; MAX_STR_LEN];
+                let (start, n_zeros, exp) = sci_digits(
+                    coeff,
+                    self.n_frac_digits(),
+                    form.precision(),
+                    None,
+                    &mut buf,
+                );
+                fmt_sci(
+                    form,
+                    coeff >= 0,
+                    buf_as_str(&buf, start),
+                    n_zeros,
+                    1,
+                    exp,
+                    b'E',
+                )
+            }
+        }
+    };
+}
+
+impl_exp!(Decimal);
+#[cfg(feature = "rkyv")]
+impl_exp!(ArchivedDecimal);
+
+/// Wrapper used to format a `Decimal` in engineering notation, i.e. in
+/// scientific notation with an exponent being a multiple of 3.
+///
+/// Created by [`Decimal::engineering`]. `Display` and `LowerExp` use 'e' to
+/// mark the exponent, `UpperExp` uses 'E'. A precision given in the format
+/// specifies the number of significant digits following the first one, as
+/// for `LowerExp` and `UpperExp` of `Decimal`.
+#[derive(Clone, Copy, Debug)]
+pub struct EngineeringNotation(Decimal);
+
+impl EngineeringNotation {
+    fn fmt_with(
+        &self,
+        form: &mut fmt::Formatter<'_>,
+        exp_char: u8,
+    ) -> fmt::Result {
+        let mut buf = [0_u8; MAX_STR_LEN];
+        let (start, n_zeros, exp) = sci_digits(
+            self.0.coeff,
+            self.0.n_frac_digits,
+            form.precision(),
+            None,
+            &mut buf,
+        );
+        let eng_exp = exp.div_euclid(3) * 3;
+        // exp - eng_exp is in 0..3, so the cast is safe
+        #[allow(clippy::cast_sign_loss)]
+        let n_int_digits = (exp - eng_exp) as usize + 1;
+        fmt_sci(
+            form,
+            self.0.coeff >= 0,
+            buf_as_str(&buf, start),
+            n_zeros,
+            n_int_digits,
+            eng_exp,
+            exp_char,
+        )
+    }
+}
+
+impl fmt::Display for EngineeringNotation {
+    fn fmt(&self, form: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.fmt_with(form, b'e')
+    }
+}
+
+impl fmt::LowerExp for EngineeringNotation {
+    fn fmt(&self, form: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.fmt_with(form, b'e')
+    }
+}
+
+impl fmt::UpperExp for EngineeringNotation {
+    fn fmt(&self, form: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.fmt_with(form, b'E')
+    }
+}
+
+impl Decimal {
+    /// Returns a wrapper formatting `self` in engineering notation, i.e. in
+    /// scientific notation with an exponent being a multiple of 3.
+    ///
+    /// # Examples:
+    ///
+    /// ```rust
+    /// # use fpdec::{Dec, Decimal};
+    /// let d = Dec!(-12345.6);
+    /// assert_eq!(format!("{}", d.engineering()), "-12.3456e3");
+    /// assert_eq!(format!("{:.2E}", d.engineering()), "-12.3E3");
+    /// let d = Dec!(0.00047);
+    /// assert_eq!(format!("{}", d.engineering()), "470e-6");
+    /// ```
+    #[must_use]
+    pub const fn engineering(self) -> EngineeringNotation {
+        EngineeringNotation(self)
+    }
+}
+
+/// Wrapper used to format a `Decimal` rounded to a fixed number of
+/// significant digits, similar to the `%g` conversion of `printf`.
+///
+/// Created by [`Decimal::sig_digits`]. The value is rounded to the given
+/// number of significant digits according to the current or an explicitly
+/// given [RoundingMode]. Let `exp` be the exponent of the first significant
+/// digit of the rounded value. If `exp` is less than the lower or not less
+/// than the upper threshold (default: -4 and 16), the value is shown in
+/// scientific notation, exactly as `LowerExp` (or `UpperExp`) shows it with
+/// a precision of the number of significant digits minus 1. Otherwise it is
+/// shown in fixed point notation, padded with zeros where needed. Trailing
+/// zeros are kept, so that the number of significant digits is always
+/// visible. A precision given in the format is ignored.
+///
+/// `Display` and `LowerExp` use 'e' to mark the exponent, `UpperExp` uses
+/// 'E'.
+///
+/// # Examples:
+///
+/// ```rust
+/// # use fpdec::{Dec, Decimal, RoundingMode};
+/// assert_eq!(Dec!(12345.678).sig_digits(4).to_string(), "12350");
+/// assert_eq!(Dec!(0.00012345).sig_digits(3).to_string(), "0.000123");
+/// assert_eq!(Dec!(-1.5).sig_digits(3).to_string(), "-1.50");
+/// assert_eq!(Dec!(0.000012345).sig_digits(3).to_string(), "1.23e-5");
+/// let s = Dec!(12345.678).sig_digits(4).exp_thresholds(-4, 4);
+/// assert_eq!(format!("{s:E}"), "1.235E4");
+/// let s = Dec!(0.125).sig_digits(2).rounding_mode(RoundingMode::RoundUp);
+/// assert_eq!(s.to_string(), "0.13");
+/// ```
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct SigDigitsNotation {
+    value: Decimal,
+    n_sig_digits: u8,
+    lower_exp: i8,
+    upper_exp: i8,
+    rounding_mode: Option<RoundingMode>,
+}
+
+impl SigDigitsNotation {
+    /// Returns `self` using scientific notation for values with an exponent
+    /// less than `lower` or greater than or equal to `upper`.
+    ///
+    /// Passing `-4` and the number of significant digits gives the same
+    /// choice between fixed point and scientific notation as `%g`.
+    #[must_use]
+    pub const fn exp_thresholds(mut self, lower: i8, upper: i8) -> Self {
+        self.lower_exp = lower;
+        self.upper_exp = upper;
+        self
+    }
+
+    /// Returns `self` with the value rounded according to `mode` instead of
+    /// the current [RoundingMode].
+    #[must_use]
+    pub const fn rounding_mode(mut self, mode: RoundingMode) -> Self {
+        self.rounding_mode = Some(mode);
+        self
+    }
+
+    fn fmt_with(
+        &self,
+        form: &mut fmt::Formatter<'_>,
+        exp_char: u8,
+    ) -> fmt::Result {
+        let is_nonnegative = self.value.coeff >= 0;
+        let mut buf = [0_u8; MAX_STR_LEN];
+        let (start, n_zeros, exp) = sci_digits(
+            self.value.coeff,
+            self.value.n_frac_digits,
+            Some(usize::from(self.n_sig_digits) - 1),
+            self.rounding_mode,
+            &mut buf,
+        );
+        let digits = buf_as_str(&buf, start);
+        if exp < i32::from(self.lower_exp) || exp >= i32::from(self.upper_exp)
+        {
+            return fmt_sci(
+                form,
+                is_nonnegative,
+                digits,
+                n_zeros,
+                1,
+                exp,
+                exp_char,
+            );
+        }
+        match usize::try_from(exp) {
+            Ok(exp) => pad_integral_parts(
+                form,
+                is_nonnegative,
+                &point_parts(digits, n_zeros, exp + 1),
+            ),
+            Err(_) => pad_integral_parts(
+                form,
+                is_nonnegative,
+                &[
+                    Part::Str("0."),
+                    // exp < 0
+                    Part::Zeros(exp.unsigned_abs() as usize - 1),
+                    Part::Str(digits),
+                    Part::Zeros(n_zeros),
+                ],
+            ),
+        }
+    }
+}
+
+impl fmt::Display for SigDigitsNotation {
+    fn fmt(&self, form: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.fmt_with(form, b'e')
+    }
+}
+
+impl fmt::LowerExp for SigDigitsNotation {
+    fn fmt(&self, form: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.fmt_with(form, b'e')
+    }
+}
+
+impl fmt::UpperExp for SigDigitsNotation {
+    fn fmt(&self, form: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.fmt_with(form, b'E')
+    }
+}
+
+impl Decimal {
+    /// Returns a wrapper formatting `self` rounded to `n` significant
+    /// digits, in fixed point or scientific notation depending on its
+    /// magnitude.
+    ///
+    /// See [SigDigitsNotation] for details.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` equals zero!
+    #[must_use]
+    pub const fn sig_digits(self, n: u8) -> SigDigitsNotation {
+        assert!(n > 0, "Number of significant digits must be > 0.");
+        SigDigitsNotation {
+            value: self,
+            n_sig_digits: n,
+            lower_exp: -4,
+            upper_exp: 16,
+            rounding_mode: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test_fmt_exp {
+    use alloc::{format, string::ToString};
+
+    use super::*;
+    use crate::Dec;
+
+    #[test]
+    fn test_lower_exp() {
+        assert_eq!(format!("{:e}", Decimal::ZERO), "0e0");
+        assert_eq!(format!("{:e}", Dec!(0.000)), "0e0");
+        assert_eq!(format!("{:.2e}", Decimal::ZERO), "0.00e0");
+        assert_eq!(format!("{:e}", Dec!(1)), "1e0");
+        assert_eq!(format!("{:e}", Dec!(-7000)), "-7e3");
+        assert_eq!(format!("{:e}", Dec!(1234.5)), "1.2345e3");
+        assert_eq!(format!("{:e}", Dec!(0.00012300)), "1.23e-4");
+        assert_eq!(format!("{:.4e}", Dec!(0.000123)), "1.2300e-4");
+        assert_eq!(format!("{:.0e}", Dec!(0.000123)), "1e-4");
+        assert_eq!(format!("{:e}", Dec!(0.000000000000000001)), "1e-18");
+        assert_eq!(
+            format!("{:e}", Decimal::MAX),
+            "1.70141183460469231731687303715884105727e38"
+        );
+        assert_eq!(
+            format!("{:e}", Decimal::MIN),
+            "-1.70141183460469231731687303715884105727e38"
+        );
+    }
+
+    #[test]
+    fn test_lower_exp_rounded() {
+        // rounded half to even by default
+        assert_eq!(format!("{:.2e}", Dec!(1234.5)), "1.23e3");
+        assert_eq!(format!("{:.3e}", Dec!(1234.5)), "1.234e3");
+        assert_eq!(format!("{:.3e}", Dec!(1235.5)), "1.236e3");
+        assert_eq!(format!("{:.3e}", Dec!(-1234.51)), "-1.235e3");
+        assert_eq!(format!("{:.1e}", Dec!(9.96)), "1.0e1");
+        assert_eq!(format!("{:.0e}", Dec!(-0.0995)), "-1e-1");
+        assert_eq!(format!("{:.1e}", Decimal::MAX), "1.7e38");
+        assert_eq!(
+            format!("{:.40e}", Dec!(1.5)),
+            "1.5000000000000000000000000000000000000000e0"
+        );
+    }
+
+    #[test]
+    fn test_lower_exp_padded() {
+        let d = Dec!(-1234.5);
+        assert_eq!(format!("{:12e}", d), "   -1.2345e3");
+        assert_eq!(format!("{:<12e}", d), "-1.2345e3   ");
+        assert_eq!(format!("{:*^13e}", d), "**-1.2345e3**");
+        assert_eq!(format!("{:012e}", d), "-0001.2345e3");
+        assert_eq!(format!("{:+e}", Dec!(0.5)), "+5e-1");
+    }
+
+    #[test]
+    fn test_pad_integral_parts() {
+        struct Parts(bool);
+        impl fmt::Display for Parts {
+            fn fmt(&self, form: &mut fmt::Formatter<'_>) -> fmt::Result {
+                pad_integral_parts(
+                    form,
+                    self.0,
+                    &[Part::Str("12"), Part::Zeros(2), Part::Str(".5")],
+                )
+            }
+        }
+        struct Whole(bool);
+        impl fmt::Display for Whole {
+            fn fmt(&self, form: &mut fmt::Formatter<'_>) -> fmt::Result {
+                form.pad_integral(self.0, "", "1200.5")
+            }
+        }
+        for is_nonnegative in [true, false] {
+            let (p, w) = (Parts(is_nonnegative), Whole(is_nonnegative));
+            assert_eq!(format!("{p}"), format!("{w}"));
+            assert_eq!(format!("{p:3}"), format!("{w:3}"));
+            assert_eq!(format!("{p:10}"), format!("{w:10}"));
+            assert_eq!(format!("{p:<10}"), format!("{w:<10}"));
+            assert_eq!(format!("{p:*^11}"), format!("{w:*^11}"));
+            assert_eq!(format!("{p:010}"), format!("{w:010}"));
+            assert_eq!(format!("{p:<+010}"), format!("{w:<+010}"));
+            assert_eq!(format!("{p:+}"), format!("{w:+}"));
+        }
+    }
+
+    #[test]
+    fn test_exp_high_precision() {
+        let d = Dec!(-1.5);
+        let s = format!("{d:.40e}");
+        assert_eq!(s, format!("-1.5{:0<39}e0", ""));
+        assert_eq!(format!("{d:>47.40e}"), format!("  {s}"));
+        let s = d.sig_digits(60).to_string();
+        assert_eq!(s, format!("-1.5{:0<58}", ""));
+        let s = Dec!(0.0012).sig_digits(50).to_string();
+        assert_eq!(s, format!("0.0012{:0<48}", ""));
+    }
+
+    #[test]
+    fn test_upper_exp() {
+        assert_eq!(format!("{:E}", Decimal::ZERO), "0E0");
+        assert_eq!(format!("{:E}", Dec!(-0.0025)), "-2.5E-3");
+        assert_eq!(format!("{:.3E}", Dec!(98765)), "9.876E4");
+        assert_eq!(format!("{:>9.1E}", Dec!(98765)), "    9.9E4");
+    }
+
+    #[test]
+    fn test_engineering() {
+        for (d, s) in [
+            (Decimal::ZERO, "0e0"),
+            (Dec!(1), "1e0"),
+            (Dec!(12), "12e0"),
+            (Dec!(123), "123e0"),
+            (Dec!(1234), "1.234e3"),
+            (Dec!(-12000), "-12e3"),
+            (Dec!(100000), "100e3"),
+            (Dec!(0.1), "100e-3"),
+            (Dec!(0.012), "12e-3"),
+            (Dec!(0.0012), "1.2e-3"),
+            (Dec!(0.000000000000000001), "1e-18"),
+        ] {
+            assert_eq!(format!("{}", d.engineering()), s);
+            assert_eq!(format!("{:e}", d.engineering()), s);
+        }
+    }
+
+    #[test]
+    fn test_engineering_zero_padded() {
+        assert_eq!(format!("{:.3}", Dec!(12).engineering()), "12.00e0");
+        assert_eq!(format!("{:.3}", Dec!(100000).engineering()), "100.0e3");
+        assert_eq!(format!("{:.0}", Dec!(-100000).engineering()), "-100e3");
+        assert_eq!(
+            format!("{:09.1}", Dec!(-0.05).engineering()),
+            "-00050e-3"
+        );
+    }
+
+    #[test]
+    fn test_engineering_rounded() {
+        let d = Dec!(-123456.7);
+        assert_eq!(format!("{:.0}", d.engineering()), "-100e3");
+        assert_eq!(format!("{:.1}", d.engineering()), "-120e3");
+        assert_eq!(format!("{:.3}", d.engineering()), "-123.5e3");
+        assert_eq!(format!("{:.3E}", d.engineering()), "-123.5E3");
+        assert_eq!(format!("{:.1}", Dec!(999.9).engineering()), "1.0e3");
+        assert_eq!(format!("{:.3}", Dec!(0.5).engineering()), "500.0e-3");
+        assert_eq!(format!("{:>12.2}", d.engineering()), "      -123e3");
+    }
+
+    #[test]
+    fn test_sig_digits() {
+        for (d, n, s) in [
+            (Decimal::ZERO, 1, "0"),
+            (Dec!(0.00), 3, "0.00"),
+            (Dec!(7), 1, "7"),
+            (Dec!(12345.678), 4, "12350"),
+            (Dec!(12345.678), 5, "12346"),
+            (Dec!(12345.678), 7, "12345.68"),
+            (Dec!(12345.678), 10, "12345.67800"),
+            (Dec!(-12345.678), 1, "-10000"),
+            (Dec!(99999.5), 5, "100000"),
+            (Dec!(0.00012345), 3, "0.000123"),
+            (Dec!(-0.00012355), 3, "-0.000124"),
+            (Dec!(0.0000999), 2, "0.00010"),
+            (Dec!(0.000099), 2, "9.9e-5"),
+            (Dec!(1234567890123456), 3, "1230000000000000"),
+            (Dec!(9999999999999999), 3, "1.00e16"),
+            (Decimal::MAX, 2, "1.7e38"),
+            (Decimal::MIN, 2, "-1.7e38"),
+            (Decimal::new_raw(1, 18), 2, "1.0e-18"),
+        ] {
+            assert_eq!(d.sig_digits(n).to_string(), s, "{d} {n}");
+        }
+    }
+
+    #[test]
+    fn test_sig_digits_consistent_with_exp() {
+        for d in [
+            Dec!(0.000012345),
+            Dec!(-98765432.1),
+            Dec!(0.5),
+            Dec!(9.96),
+            Decimal::MAX,
+        ] {
+            for n in 1..=6_u8 {
+                let s = d.sig_digits(n).exp_thresholds(0, 0);
+                let prec = usize::from(n - 1);
+                assert_eq!(format!("{s}"), format!("{d:.prec$e}"));
+                assert_eq!(format!("{s:E}"), format!("{d:.prec$E}"));
+            }
+        }
+    }
+
+    #[test]
+    fn test_sig_digits_thresholds() {
+        // same choice as %g
+        let d = Dec!(12345.678);
+        assert_eq!(
+            d.sig_digits(4).exp_thresholds(-4, 4).to_string(),
+            "1.235e4"
+        );
+        assert_eq!(
+            d.sig_digits(5).exp_thresholds(-4, 5).to_string(),
+            "12346"
+        );
+        assert_eq!(
+            d.sig_digits(6).exp_thresholds(-4, 6).to_string(),
+            "12345.7"
+        );
+        let d = Dec!(0.00012345);
+        assert_eq!(
+            d.sig_digits(3).exp_thresholds(-3, 16).to_string(),
+            "1.23e-4"
+        );
+    }
+
+    #[test]
+    fn test_sig_digits_rounding_mode() {
+        let d = Dec!(-0.1250);
+        for (mode, s) in [
+            (RoundingMode::RoundHalfEven, "-0.12"),
+            (RoundingMode::RoundHalfUp, "-0.13"),
+            (RoundingMode::RoundCeiling, "-0.12"),
+            (RoundingMode::RoundFloor, "-0.13"),
+            (RoundingMode::RoundDown, "-0.12"),
+            (RoundingMode::RoundUp, "-0.13"),
+        ] {
+            assert_eq!(
+                d.sig_digits(2).rounding_mode(mode).to_string(),
+                s,
+                "{mode:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_sig_digits_padded() {
+        let s = Dec!(-1.5).sig_digits(3);
+        assert_eq!(format!("{s:>8}"), "   -1.50");
+        assert_eq!(format!("{s:08}"), "-0001.50");
+        assert_eq!(format!("{:+}", Dec!(0.00012).sig_digits(1)), "+0.0001");
+    }
 }
@@ -0,0 +1,414 @@
+// ---------------------------------------------------------------------------
+// Copyright:   (c) 2026 ff. Michael Amrhein (michael@adrhinum.de)
+// License:     This program is part of a larger application. For license
+//              details please read the file LICENSE.TXT provided together
+//              with the application.
+// ---------------------------------------------------------------------------
+// $Source$
+// $Revision$
+
+#[cfg(feature = "alloc")]
+use alloc::{format, string::String};
+
+#[cfg(feature = "alloc")]
+use fpdec_core::i128_mul_div_ten_pow_rounded;
+
+use crate::{AsIntegerRatio, Decimal, ParseDecimalError};
+#[cfg(feature = "alloc")]
+use crate::{DecimalError, RoundingMode};
+
+impl Decimal {
+    /// Returns the pair of integers `(numer, denom)` with
+    /// `0 < denom <= max_denom` whose ratio is closest to `self`.
+    ///
+    /// The result is determined from the continued fraction expansion of
+    /// `self`, so it is the best rational approximation of `self` with a
+    /// denominator not greater than `max_denom`. If the denominator of
+    /// `self.as_integer_ratio()` does not exceed `max_denom`, that ratio is
+    /// returned.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `max_denom` is less than 1!
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fpdec::{Dec, Decimal};
+    /// let d = Dec!(3.141592653589793);
+    /// assert_eq!(d.to_fraction_limited(1000), (355, 113));
+    /// let d = Dec!(-0.333);
+    /// assert_eq!(d.to_fraction_limited(10), (-1, 3));
+    /// let d = Dec!(99.515625);
+    /// assert_eq!(d.to_fraction_limited(64), (6369, 64));
+    /// ```
+    #[must_use]
+    #[allow(clippy::integer_division)]
+    pub fn to_fraction_limited(self, max_denom: i128) -> (i128, i128) {
+        assert!(max_denom >= 1, "max_denom must be >= 1.");
+        let (numer, denom) = self.as_integer_ratio();
+        if denom <= max_denom {
+            return (numer, denom);
+        }
+        // here: 1 < denom <= 10 ^ MAX_N_FRAC_DIGITS and max_denom < denom,
+        // so |numer| = int * denom + rem with 0 < rem < denom
+        let abs_numer = numer.unsigned_abs();
+        #[allow(clippy::cast_possible_truncation)]
+        let (int, rem) = (
+            (abs_numer / denom as u128) as i128,
+            (abs_numer % denom as u128) as i128,
+        );
+        // Calculate the convergents of rem / denom until the next one would
+        // exceed max_denom.
+        let (mut p0, mut q0, mut p1, mut q1) =
+            (0_i128, 1_i128, 1_i128, 0_i128);
+        let (mut n, mut d) = (rem, denom);
+        loop {
+            let a = n / d;
+            let q2 = q0 + a * q1;
+            if q2 > max_denom {
+                break;
+            }
+            (p0, q0, p1, q1) = (p1, q1, p0 + a * p1, q2);
+            (n, d) = (d, n - a * d);
+        }
+        // The best approximation is either the last convergent p1 / q1 or
+        // the semiconvergent with the largest denominator <= max_denom.
+        let k = (max_denom - q0) / q1;
+        let (p2, q2) = (p0 + k * p1, q0 + k * q1);
+        // |rem / denom - p / q| = |p * denom - rem * q| / (denom * q), where
+        // |p * denom - rem * q| <= denom for both candidates, so that the
+        // following products can't overflow.
+        let dist = |p: i128, q: i128| (p * denom - rem * q).unsigned_abs();
+        let (p, q) = if dist(p1, q1) * q2 as u128 <= dist(p2, q2) * q1 as u128
+        {
+            (p1, q1)
+        } else {
+            (p2, q2)
+        };
+        // int * q < int * denom <= |numer|, so this can't overflow
+        let p = int * q + p;
+        if numer < 0 {
+            (-p, q)
+        } else {
+            (p, q)
+        }
+    }
+
+    /// Converts a price quoted in 32nds, like "99-16+", into a `Decimal`.
+    ///
+    /// The quote must be in the form
+    ///
+    /// `[+|-]<int>[<-|'><32nds>[<+|eighth>]]`
+    ///
+    /// where `<32nds>` consists of two digits denoting a value in the range
+    /// 0 .. 31 and the optional trailing character denotes a fraction of a
+    /// 32nd: '+' = 1/2, '0' .. '7' = 0/8 .. 7/8 (i.e. '2' = 1/4, '6' =
+    /// 3/4).
+    ///
+    /// As any multiple of 1/256 has a finite decimal representation, the
+    /// result is exact.
+    ///
+    /// # Errors
+    ///
+    /// The function returns an error in these cases:
+    ///
+    /// * An empty string has been given as `quote` ->
+    ///   `ParseDecimalError::Empty`
+    /// * `quote` does not fit the form given above ->
+    ///   `ParseDecimalError::Invalid`
+    /// * The value exceeds the internal representation of `Decimal` ->
+    ///   `ParseDecimalError::InternalOverflow`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fpdec::{Decimal, ParseDecimalError};
+    /// # fn main() -> Result<(), ParseDecimalError> {
+    /// let d = Decimal::from_fractional_quote("99-16+")?;
+    /// assert_eq!(d.to_string(), "99.515625");
+    /// let d = Decimal::from_fractional_quote("101-082")?;
+    /// assert_eq!(d.to_string(), "101.2578125");
+    /// let d = Decimal::from_fractional_quote("-0'01")?;
+    /// assert_eq!(d.to_string(), "-0.03125");
+    /// # Ok(()) }
+    /// ```
+    pub fn from_fractional_quote(
+        quote: &str,
+    ) -> Result<Self, ParseDecimalError> {
+        let bytes = quote.as_bytes();
+        let (is_negative, bytes) = match bytes.first() {
+            None => return Err(ParseDecimalError::Empty),
+            Some(&b'-') => (true, &bytes[1..]),
+            Some(&b'+') => (false, &bytes[1..]),
+            Some(_) => (false, bytes),
+        };
+        let sep_idx = bytes
+            .iter()
+            .position(|b| *b == b'-' || *b == b'\'')
+            .unwrap_or(bytes.len());
+        let (int_part, frac_part) = bytes.split_at(sep_idx);
+        if int_part.is_empty() || !int_part.iter().all(u8::is_ascii_digit) {
+            return Err(ParseDecimalError::Invalid);
+        }
+        let mut int = 0_i128;
+        for b in int_part {
+            int = int
+                .checked_mul(10)
+                .and_then(|i| i.checked_add(i128::from(b - b'0')))
+                .ok_or(ParseDecimalError::InternalOverflow)?;
+        }
+        // value in units of 1/256
+        let n_256ths = match frac_part {
+            [] => 0_i128,
+            [_, t1 @ b'0'..=b'3', t0 @ b'0'..=b'9', rest @ ..] => {
+                let n_32nds = i128::from((t1 - b'0') * 10 + t0 - b'0');
+                if n_32nds > 31 {
+                    return Err(ParseDecimalError::Invalid);
+                }
+                let n_8ths = match rest {
+                    [] => 0,
+                    [b'+'] => 4,
+                    [e @ b'0'..=b'7'] => i128::from(e - b'0'),
+                    _ => return Err(ParseDecimalError::Invalid),
+                };
+                n_32nds * 8 + n_8ths
+            }
+            _ => return Err(ParseDecimalError::Invalid),
+        };
+        let mut numer = int
+            .checked_mul(256)
+            .and_then(|i| i.checked_add(n_256ths))
+            .ok_or(ParseDecimalError::InternalOverflow)?;
+        if is_negative {
+            numer = -numer;
+        }
+        Self::from_integer_ratio(numer, 256).map_err(|_| {
+            // can only be an overflow, because 256 = 2 ^ 8
+            ParseDecimalError::InternalOverflow
+        })
+    }
+
+    /// Returns `self` as a price quoted in 32nds, like "99-16+", rounded to
+    /// a multiple of 1 / `resolution` according to `mode`.
+    ///
+    /// `resolution` must be one of 32, 64, 128 or 256. The fraction of a
+    /// 32nd is denoted by a trailing character: '+' = 1/2, '2' = 1/4, '6' =
+    /// 3/4 and '1' .. '7' = 1/8 .. 7/8 in general. It is omitted for whole
+    /// 32nds.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error (wrapped in Result::Err) in the following cases:
+    /// * `resolution` is not one of the supported values =>
+    ///   `DecimalError::InvalidResolution`,
+    /// * the rounded value can not be represented internally =>
+    ///   `DecimalError::InternalOverflow`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fpdec::{Dec, Decimal, DecimalError, RoundingMode};
+    /// # fn main() -> Result<(), DecimalError> {
+    /// let d = Dec!(99.515625);
+    /// let q = d.to_fractional_quote(64, RoundingMode::RoundHalfEven)?;
+    /// assert_eq!(q, "99-16+");
+    /// let d = Dec!(99.52);
+    /// let q = d.to_fractional_quote(32, RoundingMode::RoundHalfEven)?;
+    /// assert_eq!(q, "99-17");
+    /// let q = d.to_fractional_quote(32, RoundingMode::RoundFloor)?;
+    /// assert_eq!(q, "99-16");
+    /// let q = d.to_fractional_quote(256, RoundingMode::RoundHalfEven)?;
+    /// assert_eq!(q, "99-165");
+    /// # Ok(()) }
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[allow(clippy::integer_division)]
+    pub fn to_fractional_quote(
+        self,
+        resolution: u16,
+        mode: RoundingMode,
+    ) -> Result<String, DecimalError> {
+        if !matches!(resolution, 32 | 64 | 128 | 256) {
+            return Err(DecimalError::InvalidResolution);
+        }
+        // value in units of 1 / resolution
+        let n_units = i128_mul_div_ten_pow_rounded(
+            self.coeff,
+            i128::from(resolution),
+            self.n_frac_digits,
+            Some(mode),
+        )
+        .ok_or(DecimalError::InternalOverflow)?;
+        let res = resolution as u128;
+        let abs_units = n_units.unsigned_abs();
+        let (int, rem) = (abs_units / res, abs_units % res);
+        // rem in units of 1/256
+        let n_256ths = rem * (256 / res);
+        let sign = if n_units < 0 { "-" } else { "" };
+        let n_32nds = n_256ths / 8;
+        Ok(match n_256ths % 8 {
+            0 => format!("{sign}{int}-{n_32nds:02}"),
+            4 => format!("{sign}{int}-{n_32nds:02}+"),
+            n_8ths => format!("{sign}{int}-{n_32nds:02}{n_8ths}"),
+        })
+    }
+}
+
+#[cfg(test)]
+mod to_fraction_limited_tests {
+    use super::*;
+
+    #[test]
+    fn test_to_fraction_limited() {
+        let test_data = [
+            (Decimal::new_raw(3141592653589793, 15), 1000, (355, 113)),
+            (Decimal::new_raw(3141592653589793, 15), 100, (311, 99)),
+            (Decimal::new_raw(333, 3), 10, (1, 3)),
+            (Decimal::new_raw(-2827095, 5), 100, (-1357, 48)),
+            (Decimal::new_raw(1, 1), 5, (0, 1)),
+            (Decimal::new_raw(99515625, 6), 64, (6369, 64)),
+            (Decimal::new_raw(12345678, 3), 7, (37037, 3)),
+            (Decimal::new_raw(1, 6), 1000, (0, 1)),
+            (Decimal::new_raw(15, 1), 1, (1, 1)),
+            (Decimal::new_raw(-15, 1), 1, (-1, 1)),
+        ];
+        for (d, max_denom, ratio) in test_data {
+            assert_eq!(d.to_fraction_limited(max_denom), ratio);
+        }
+    }
+
+    #[test]
+    fn test_exact() {
+        let d = Decimal::new_raw(-2827095, 5);
+        assert_eq!(d.to_fraction_limited(20000), d.as_integer_ratio());
+        assert_eq!(d.to_fraction_limited(i128::MAX), d.as_integer_ratio());
+        assert_eq!(Decimal::MAX.to_fraction_limited(1), (i128::MAX, 1));
+    }
+
+    #[test]
+    fn test_large_values() {
+        let d = Decimal::new_raw(i128::MAX, 18);
+        let (p, q) = d.to_fraction_limited(3);
+        assert_eq!(q, 3);
+        assert_eq!(p, 510423550381407695195);
+        let d = Decimal::new_raw(i128::MIN + 1, 18);
+        assert_eq!(d.to_fraction_limited(3), (-510423550381407695195, 3));
+        let (p, q) = d.to_fraction_limited(999_999_999_999_999_999);
+        assert!(p < 0 && q <= 999_999_999_999_999_999);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_invalid_max_denom() {
+        let _ = Decimal::ONE.to_fraction_limited(0);
+    }
+}
+
+#[cfg(test)]
+mod fractional_quote_tests {
+    use super::*;
+
+    #[test]
+    fn test_from_fractional_quote() {
+        let test_data = [
+            ("99-16+", 99515625, 6),
+            ("99-16", 995, 1),
+            ("99'16", 995, 1),
+            ("+99-162", 995078125, 7),
+            ("99-166", 995234375, 7),
+            ("101-082", 1012578125, 7),
+            ("101-087", 10127734375, 8),
+            ("100", 100, 0),
+            ("100-00", 100, 0),
+            ("-0-01", -3125, 5),
+            ("0-31+", 984375, 6),
+        ];
+        for (quote, coeff, n_frac_digits) in test_data {
+            let d = Decimal::from_fractional_quote(quote).unwrap();
+            assert_eq!(d.coefficient(), coeff, "{quote}");
+            assert_eq!(d.n_frac_digits(), n_frac_digits, "{quote}");
+        }
+    }
+
+    #[test]
+    fn test_from_fractional_quote_err() {
+        let res = Decimal::from_fractional_quote("");
+        assert_eq!(res.unwrap_err(), ParseDecimalError::Empty);
+        for quote in [
+            "-", "99-", "99-1", "99-32", "99-168", "99-16++", "99.16",
+            "-99-1a", "99-16-", "a-16", "--16",
+        ] {
+            let res = Decimal::from_fractional_quote(quote);
+            assert_eq!(
+                res.unwrap_err(),
+                ParseDecimalError::Invalid,
+                "{quote}"
+            );
+        }
+        let res = Decimal::from_fractional_quote(
+            "1000000000000000000000000000000000000-00",
+        );
+        assert_eq!(res.unwrap_err(), ParseDecimalError::InternalOverflow);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_to_fractional_quote() {
+        let test_data = [
+            (Decimal::new_raw(99515625, 6), 64, "99-16+"),
+            (Decimal::new_raw(99515625, 6), 32, "99-16"),
+            (Decimal::new_raw(9952, 2), 32, "99-17"),
+            (Decimal::new_raw(9952, 2), 128, "99-166"),
+            (Decimal::new_raw(9952, 2), 256, "99-165"),
+            (Decimal::new_raw(1012578125, 7), 128, "101-082"),
+            (Decimal::new_raw(-3125, 5), 32, "-0-01"),
+            (Decimal::new_raw(100, 0), 32, "100-00"),
+            (Decimal::new_raw(9999, 2), 32, "100-00"),
+        ];
+        for (d, resolution, quote) in test_data {
+            let q = d
+                .to_fractional_quote(resolution, RoundingMode::RoundHalfEven)
+                .unwrap();
+            assert_eq!(q, quote);
+            let r = Decimal::from_fractional_quote(&q).unwrap();
+            assert_eq!(
+                r.to_fractional_quote(resolution, RoundingMode::RoundUp),
+                Ok(q)
+            );
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_to_fractional_quote_directed_rounding() {
+        let d = Decimal::new_raw(-1, 2);
+        let q = d.to_fractional_quote(32, RoundingMode::RoundFloor).unwrap();
+        assert_eq!(q, "-0-01");
+        let q = d
+            .to_fractional_quote(32, RoundingMode::RoundCeiling)
+            .unwrap();
+        assert_eq!(q, "0-00");
+        let d = Decimal::new_raw(99515625, 6);
+        let q = d
+            .to_fractional_quote(32, RoundingMode::RoundHalfUp)
+            .unwrap();
+        assert_eq!(q, "99-17");
+        let q = d
+            .to_fractional_quote(32, RoundingMode::RoundHalfDown)
+            .unwrap();
+        assert_eq!(q, "99-16");
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_to_fractional_quote_invalid_resolution() {
+        for resolution in [0, 16, 100, 512] {
+            assert_eq!(
+                Decimal::ONE
+                    .to_fractional_quote(resolution, RoundingMode::RoundUp),
+                Err(DecimalError::InvalidResolution)
+            );
+        }
+    }
+}
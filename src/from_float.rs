@@ -9,14 +9,16 @@
 
 use core::convert::TryFrom;
 
-use fpdec_core::{i128_magnitude, MAX_N_FRAC_DIGITS};
+use fpdec_core::{
+    i128_div_rounded, i128_magnitude, ten_pow, MAX_N_FRAC_DIGITS,
+};
 
-use crate::{normalize, Decimal, DecimalError};
+use crate::{normalize, Decimal, DecimalError, RoundingMode};
 
 /// Returns a normal f64 value f as (significand, exponent, sign) so that
 /// `f = sign * significand * 2 ^ exponent`.
 /// If f is signed zero or subnormal, (0, 0, 0) is returned.
-fn f64_decode(f: f64) -> (u64, i16, i8) {
+pub(crate) fn f64_decode(f: f64) -> (u64, i16, i8) {
     let bits = f.to_bits();
     // sign bit at pos 63
     let sign_bit: u8 = (bits >> 63) as u8;
@@ -43,7 +45,7 @@ fn f64_decode(f: f64) -> (u64, i16, i8) {
 /// Returns a normal f32 value f as (significand, exponent, sign) so that
 /// `f = sign * significand * 2 ^ exponent`.
 /// If f is signed zero or subnormal, (0, 0, 0) is returned.
-fn f32_decode(f: f32) -> (u64, i16, i8) {
+pub(crate) fn f32_decode(f: f32) -> (u64, i16, i8) {
     let bits = f.to_bits();
     // sign bit at pos 31
     let sign_bit: u8 = (bits >> 31) as u8;
@@ -67,7 +69,7 @@ fn f32_decode(f: f32) -> (u64, i16, i8) {
     (significand, exponent, sign)
 }
 
-const MAGN_I128_MAX: u8 = 38;
+pub(crate) const MAGN_I128_MAX: u8 = 38;
 
 #[allow(clippy::integer_division)]
 #[inline]
@@ -196,6 +198,7 @@ impl TryFrom<f64> for Decimal {
     /// assert_eq!(d.to_string(), "37.000500299999998788");
     /// # Ok(()) }
     /// ```
+    #[allow(clippy::cast_possible_truncation)]
     fn try_from(f: f64) -> Result<Self, Self::Error> {
         if f.is_infinite() {
             return Err(DecimalError::InfiniteValue);
@@ -230,6 +233,697 @@ impl TryFrom<f64> for Decimal {
     }
 }
 
+/// Returns a finite f64 value f as (numer, exponent) so that
+/// `f = numer * 2 ^ exponent`, including subnormal values.
+fn f64_to_binary(f: f64) -> (i128, i16) {
+    match f64_decode(f) {
+        (_, _, 0) if f == 0.0 => (0, 0),
+        (_, _, 0) => {
+            // f is subnormal, so f * 2 ^ 64 is normal and exact
+            let (numer, exponent) = f64_to_binary(f * 18446744073709551616.0);
+            (numer, exponent - 64)
+        }
+        (significand, exponent, sign) => {
+            (i128::from(sign) * i128::from(significand), exponent)
+        }
+    }
+}
+
+/// Returns a finite f32 value f as (numer, exponent) so that
+/// `f = numer * 2 ^ exponent`, including subnormal values.
+fn f32_to_binary(f: f32) -> (i128, i16) {
+    match f32_decode(f) {
+        (_, _, 0) if f == 0.0 => (0, 0),
+        (_, _, 0) => {
+            // f is subnormal, so f * 2 ^ 32 is normal and exact
+            let (numer, exponent) = f32_to_binary(f * 4294967296.0);
+            (numer, exponent - 32)
+        }
+        (significand, exponent, sign) => {
+            (i128::from(sign) * i128::from(significand), exponent)
+        }
+    }
+}
+
+/// Returns `numer * 2 ^ exponent` as coefficient for the given number of
+/// fractional digits, rounded according to `mode`.
+/// `numer` must be less than 2 ^ 64 in magnitude.
+fn binary_to_coeff_rounded(
+    numer: i128,
+    exponent: i16,
+    n_frac_digits: u8,
+    mode: RoundingMode,
+) -> Result<i128, DecimalError> {
+    if n_frac_digits > MAX_N_FRAC_DIGITS {
+        return Err(DecimalError::MaxNFracDigitsExceeded);
+    }
+    // |numer| < 2 ^ 64 and 10 ^ n_frac_digits < 2 ^ 60
+    let numer = numer * ten_pow(n_frac_digits);
+    if exponent >= 0 {
+        if numer == 0 {
+            return Ok(0);
+        }
+        if exponent >= 127 {
+            return Err(DecimalError::InternalOverflow);
+        }
+        match numer.checked_mul(1_i128 << exponent) {
+            Some(coeff) if coeff != i128::MIN => Ok(coeff),
+            _ => Err(DecimalError::InternalOverflow),
+        }
+    } else if exponent < -126 {
+        // |numer| < 2 ^ 124 => quotient is zero and remainder is less than
+        // half of the divisor, so the result is the same as for +/- 1 / 2 ^
+        // 126
+        Ok(i128_div_rounded(numer.signum(), 1_i128 << 126, Some(mode)))
+    } else {
+        Ok(i128_div_rounded(numer, 1_i128 << -exponent, Some(mode)))
+    }
+}
+
+/// Returns `numer * 2 ^ exponent` as `Decimal` without rounding.
+fn binary_to_decimal_exact(
+    numer: i128,
+    exponent: i16,
+) -> Result<Decimal, DecimalError> {
+    if numer == 0 {
+        return Ok(Decimal::ZERO);
+    }
+    if exponent >= 0 {
+        return binary_to_coeff_rounded(
+            numer,
+            exponent,
+            0,
+            RoundingMode::RoundHalfEven,
+        )
+        .map(|coeff| Decimal::new_raw(coeff, 0));
+    }
+    let shift = numer
+        .trailing_zeros()
+        .min(u32::from(exponent.unsigned_abs()));
+    let numer = numer >> shift;
+    // 1 / 2 ^ n = 5 ^ n / 10 ^ n
+    let n_frac_digits = u32::from(exponent.unsigned_abs()) - shift;
+    if n_frac_digits > u32::from(MAX_N_FRAC_DIGITS) {
+        return Err(DecimalError::MaxNFracDigitsExceeded);
+    }
+    match 5_i128
+        .checked_pow(n_frac_digits)
+        .and_then(|f| numer.checked_mul(f))
+    {
+        #[allow(clippy::cast_possible_truncation)]
+        Some(coeff) => Ok(Decimal::new_raw(coeff, n_frac_digits as u8)),
+        None => Err(DecimalError::InternalOverflow),
+    }
+}
+
+/// Returns the candidate nearest to `v / divisor` from the integers `c`
+/// with `lo <= c * divisor <= hi`, if there is any. The bounds are excluded,
+/// if `inclusive` is false.
+#[allow(clippy::integer_division)]
+fn nearest_candidate(
+    (lo, v, hi): (u128, u128, u128),
+    divisor: u128,
+    inclusive: bool,
+) -> Option<u128> {
+    let (c_lo, c_hi) = if inclusive {
+        (lo / divisor + u128::from(lo % divisor != 0), hi / divisor)
+    } else {
+        (lo / divisor + 1, (hi - 1) / divisor)
+    };
+    if c_lo > c_hi {
+        return None;
+    }
+    // round half to even
+    let (quot, rem) = (v / divisor, v % divisor);
+    let rem_compl = divisor - rem;
+    let quot = if rem > rem_compl || rem == rem_compl && quot & 1 == 1 {
+        quot + 1
+    } else {
+        quot
+    };
+    Some(quot.clamp(c_lo, c_hi))
+}
+
+/// Returns the shortest decimal (as absolute coefficient and number of
+/// fractional digits) which rounds to the binary float with the absolute
+/// value `m * 2 ^ exponent`, where `m` has at most 53 significant bits.
+/// `lower_gap_halved` must be true, if the next lower float has a distance
+/// half the distance of the next higher float.
+#[allow(clippy::cast_sign_loss)]
+#[allow(clippy::cast_possible_truncation)]
+#[allow(clippy::integer_division)]
+fn shortest_abs_coeff(
+    m: u128,
+    exponent: i16,
+    lower_gap_halved: bool,
+) -> Result<(u128, u8), DecimalError> {
+    if m == 0 {
+        return Ok((0, 0));
+    }
+    // The float rounds all values within [lo, hi] (in units of 2 ^ (e - 2))
+    // to itself. The bounds are included, if m is even.
+    let v = m << 2;
+    let lo = if lower_gap_halved { v - 1 } else { v - 2 };
+    let hi = v + 2;
+    let inclusive = m & 1 == 0;
+    if exponent >= 2 {
+        // All bounds are integers, the shortest decimal may have trailing
+        // zeros.
+        let shift = (exponent - 2) as u32;
+        if (u128::BITS - m.leading_zeros()) + shift + 2 > u128::BITS {
+            return Err(DecimalError::InternalOverflow);
+        }
+        let bounds = (lo << shift, v << shift, hi << shift);
+        for n in (1..=MAGN_I128_MAX).rev() {
+            let divisor = ten_pow(n) as u128;
+            if let Some(c) = nearest_candidate(bounds, divisor, inclusive) {
+                // c * divisor <= hi
+                return Ok((c * divisor, 0));
+            }
+        }
+        Ok((bounds.1, 0))
+    } else {
+        let shift = (2 - exponent) as u32;
+        if shift >= u128::BITS {
+            return Err(DecimalError::MaxNFracDigitsExceeded);
+        }
+        let divisor = 1_u128 << shift;
+        for n in 0..=MAX_N_FRAC_DIGITS {
+            // m < 2 ^ 53 and 10 ^ n < 2 ^ 60, so this can't overflow
+            let f = ten_pow(n) as u128;
+            let bounds = (lo * f, v * f, hi * f);
+            if let Some(c) = nearest_candidate(bounds, divisor, inclusive) {
+                return Ok((c, n));
+            }
+        }
+        Err(DecimalError::MaxNFracDigitsExceeded)
+    }
+}
+
+/// Returns the shortest `Decimal` which rounds to the binary float
+/// `numer * 2 ^ exponent`.
+fn binary_to_decimal_shortest(
+    numer: i128,
+    exponent: i16,
+    lower_gap_halved: bool,
+) -> Result<Decimal, DecimalError> {
+    let (abs_coeff, n_frac_digits) =
+        shortest_abs_coeff(numer.unsigned_abs(), exponent, lower_gap_halved)?;
+    match i128::try_from(abs_coeff) {
+        Ok(coeff) => Ok(Decimal {
+            coeff: if numer < 0 { -coeff } else { coeff },
+            n_frac_digits,
+        }),
+        Err(_) => Err(DecimalError::InternalOverflow),
+    }
+}
+
+impl Decimal {
+    /// Converts a `f64` value `f` into a `Decimal` with the given number of
+    /// fractional digits, rounding the exact binary value of `f` according
+    /// to `mode`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error (wrapped in Result::Err) in the following cases:
+    /// * `n_frac_digits` > `MAX_N_FRAC_DIGITS` =>
+    ///   `DecimalError::MaxNFracDigitsExceeded`,
+    /// * `f` is infinite => `DecimalError::InfiniteValue`,
+    /// * `f` is Nan => `DecimalError::NotANumber`,
+    /// * the result can not be represented as `Decimal` =>
+    ///   `DecimalError::InternalOverflow`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fpdec::{Decimal, DecimalError, RoundingMode};
+    /// # fn main() -> Result<(), DecimalError> {
+    /// let d = Decimal::from_f64_rounded(0.1, 2, RoundingMode::RoundHalfEven)?;
+    /// assert_eq!(d.to_string(), "0.10");
+    /// let d = Decimal::from_f64_rounded(2.675, 2, RoundingMode::RoundHalfUp)?;
+    /// // 2.675_f64 is slightly less than 2.675
+    /// assert_eq!(d.to_string(), "2.67");
+    /// let d = Decimal::from_f64_rounded(-0.125, 2, RoundingMode::RoundUp)?;
+    /// assert_eq!(d.to_string(), "-0.13");
+    /// # Ok(()) }
+    /// ```
+    pub fn from_f64_rounded(
+        f: f64,
+        n_frac_digits: u8,
+        mode: RoundingMode,
+    ) -> Result<Self, DecimalError> {
+        if f.is_infinite() {
+            return Err(DecimalError::InfiniteValue);
+        }
+        if f.is_nan() {
+            return Err(DecimalError::NotANumber);
+        }
+        let (numer, exponent) = f64_to_binary(f);
+        let coeff =
+            binary_to_coeff_rounded(numer, exponent, n_frac_digits, mode)?;
+        Ok(Self {
+            coeff,
+            n_frac_digits,
+        })
+    }
+
+    /// Converts a `f32` value `f` into a `Decimal` with the given number of
+    /// fractional digits, rounding the exact binary value of `f` according
+    /// to `mode`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error (wrapped in Result::Err) in the following cases:
+    /// * `n_frac_digits` > `MAX_N_FRAC_DIGITS` =>
+    ///   `DecimalError::MaxNFracDigitsExceeded`,
+    /// * `f` is infinite => `DecimalError::InfiniteValue`,
+    /// * `f` is Nan => `DecimalError::NotANumber`,
+    /// * the result can not be represented as `Decimal` =>
+    ///   `DecimalError::InternalOverflow`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fpdec::{Decimal, DecimalError, RoundingMode};
+    /// # fn main() -> Result<(), DecimalError> {
+    /// let d = Decimal::from_f32_rounded(0.1, 3, RoundingMode::RoundDown)?;
+    /// assert_eq!(d.to_string(), "0.100");
+    /// let d = Decimal::from_f32_rounded(0.1, 9, RoundingMode::RoundDown)?;
+    /// assert_eq!(d.to_string(), "0.100000001");
+    /// # Ok(()) }
+    /// ```
+    pub fn from_f32_rounded(
+        f: f32,
+        n_frac_digits: u8,
+        mode: RoundingMode,
+    ) -> Result<Self, DecimalError> {
+        if f.is_infinite() {
+            return Err(DecimalError::InfiniteValue);
+        }
+        if f.is_nan() {
+            return Err(DecimalError::NotANumber);
+        }
+        let (numer, exponent) = f32_to_binary(f);
+        let coeff =
+            binary_to_coeff_rounded(numer, exponent, n_frac_digits, mode)?;
+        Ok(Self {
+            coeff,
+            n_frac_digits,
+        })
+    }
+
+    /// Converts a `f64` value `f` into a `Decimal` holding exactly the
+    /// binary value of `f`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error (wrapped in Result::Err) in the following cases:
+    /// * `f` is infinite => `DecimalError::InfiniteValue`,
+    /// * `f` is Nan => `DecimalError::NotANumber`,
+    /// * the exact value of `f` needs more than `MAX_N_FRAC_DIGITS`
+    ///   fractional digits => `DecimalError::MaxNFracDigitsExceeded`,
+    /// * the exact value of `f` can not be represented as `Decimal` =>
+    ///   `DecimalError::InternalOverflow`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fpdec::{Decimal, DecimalError};
+    /// # fn main() -> Result<(), DecimalError> {
+    /// let d = Decimal::from_f64_exact(-289.0625)?;
+    /// assert_eq!(d.to_string(), "-289.0625");
+    /// let d = Decimal::from_f64_exact(2.0_f64.powi(-18))?;
+    /// assert_eq!(d.to_string(), "0.000003814697265625");
+    /// let res = Decimal::from_f64_exact(0.1);
+    /// assert_eq!(res.unwrap_err(), DecimalError::MaxNFracDigitsExceeded);
+    /// # Ok(()) }
+    /// ```
+    pub fn from_f64_exact(f: f64) -> Result<Self, DecimalError> {
+        if f.is_infinite() {
+            return Err(DecimalError::InfiniteValue);
+        }
+        if f.is_nan() {
+            return Err(DecimalError::NotANumber);
+        }
+        let (numer, exponent) = f64_to_binary(f);
+        binary_to_decimal_exact(numer, exponent)
+    }
+
+    /// Converts a `f64` value `f` into the `Decimal` with the least number
+    /// of significant digits which converts back to `f`.
+    ///
+    /// If there are several such values, the one nearest to `f` is
+    /// returned (ties resolved to an even last digit).
+    ///
+    /// In contrast, `Decimal::try_from(f)` returns the decimal nearest to
+    /// the exact binary value of `f`, using up to `MAX_N_FRAC_DIGITS`
+    /// fractional digits. For example, `0.1_f64` converts to `0.1` using
+    /// this function, but to `0.100000000000000006` via `TryFrom`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error (wrapped in Result::Err) in the following cases:
+    /// * `f` is infinite => `DecimalError::InfiniteValue`,
+    /// * `f` is Nan => `DecimalError::NotANumber`,
+    /// * the shortest decimal needs more than `MAX_N_FRAC_DIGITS` fractional
+    ///   digits => `DecimalError::MaxNFracDigitsExceeded`,
+    /// * the shortest decimal can not be represented as `Decimal` =>
+    ///   `DecimalError::InternalOverflow`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fpdec::{Decimal, DecimalError};
+    /// # use core::convert::TryFrom;
+    /// # fn main() -> Result<(), DecimalError> {
+    /// let d = Decimal::from_f64_shortest(0.1)?;
+    /// assert_eq!(d.to_string(), "0.1");
+    /// let d = Decimal::try_from(0.1_f64)?;
+    /// assert_eq!(d.to_string(), "0.100000000000000006");
+    /// let d = Decimal::from_f64_shortest(1e-7)?;
+    /// assert_eq!(d.to_string(), "0.0000001");
+    /// let d = Decimal::from_f64_shortest(1.2345678901234567e27)?;
+    /// assert_eq!(d.to_string(), "1234567890123456700000000000");
+    /// # Ok(()) }
+    /// ```
+    pub fn from_f64_shortest(f: f64) -> Result<Self, DecimalError> {
+        if f.is_infinite() {
+            return Err(DecimalError::InfiniteValue);
+        }
+        if f.is_nan() {
+            return Err(DecimalError::NotANumber);
+        }
+        let (numer, exponent) = f64_to_binary(f);
+        binary_to_decimal_shortest(
+            numer,
+            exponent,
+            numer.unsigned_abs() == 1 << 52 && exponent > -1074,
+        )
+    }
+
+    /// Converts a `f32` value `f` into the `Decimal` with the least number
+    /// of significant digits which converts back to `f`.
+    ///
+    /// If there are several such values, the one nearest to `f` is
+    /// returned (ties resolved to an even last digit).
+    ///
+    /// In contrast, `Decimal::try_from(f)` returns the decimal nearest to
+    /// the exact binary value of `f`, using up to `MAX_N_FRAC_DIGITS`
+    /// fractional digits. For example, `0.1_f32` converts to `0.1` using
+    /// this function, but to `0.100000001490116119` via `TryFrom`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error (wrapped in Result::Err) in the following cases:
+    /// * `f` is infinite => `DecimalError::InfiniteValue`,
+    /// * `f` is Nan => `DecimalError::NotANumber`,
+    /// * the shortest decimal needs more than `MAX_N_FRAC_DIGITS` fractional
+    ///   digits => `DecimalError::MaxNFracDigitsExceeded`,
+    /// * the shortest decimal can not be represented as `Decimal` =>
+    ///   `DecimalError::InternalOverflow`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fpdec::{Decimal, DecimalError};
+    /// # fn main() -> Result<(), DecimalError> {
+    /// let d = Decimal::from_f32_shortest(0.1)?;
+    /// assert_eq!(d.to_string(), "0.1");
+    /// let d = Decimal::from_f32_shortest(16777217.0)?;
+    /// assert_eq!(d.to_string(), "16777216");
+    /// # Ok(()) }
+    /// ```
+    pub fn from_f32_shortest(f: f32) -> Result<Self, DecimalError> {
+        if f.is_infinite() {
+            return Err(DecimalError::InfiniteValue);
+        }
+        if f.is_nan() {
+            return Err(DecimalError::NotANumber);
+        }
+        let (numer, exponent) = f32_to_binary(f);
+        binary_to_decimal_shortest(
+            numer,
+            exponent,
+            numer.unsigned_abs() == 1 << 23 && exponent > -149,
+        )
+    }
+}
+
+#[cfg(test)]
+mod from_float_rounded_tests {
+    use super::*;
+
+    const MODES: [RoundingMode; 8] = [
+        RoundingMode::RoundDown,
+        RoundingMode::RoundUp,
+        RoundingMode::RoundFloor,
+        RoundingMode::RoundCeiling,
+        RoundingMode::RoundHalfDown,
+        RoundingMode::RoundHalfUp,
+        RoundingMode::RoundHalfEven,
+        RoundingMode::Round05Up,
+    ];
+
+    #[test]
+    fn test_to_binary() {
+        assert_eq!(f64_to_binary(0.0), (0, 0));
+        assert_eq!(f64_to_binary(-0.0), (0, 0));
+        assert_eq!(f64_to_binary(-1.5), (-3 << 51, -52));
+        // 2 ^ 52 * 2 ^ -1126 = 2 ^ -1074
+        assert_eq!(f64_to_binary(5e-324), (1 << 52, -1126));
+        assert_eq!(
+            f64_to_binary(-f64::MIN_POSITIVE / 2.0),
+            (-1 << 52, -1075)
+        );
+        assert_eq!(f32_to_binary(0.0), (0, 0));
+        assert_eq!(f32_to_binary(1.5), (3 << 22, -23));
+        // 2 ^ 23 * 2 ^ -172 = 2 ^ -149
+        assert_eq!(f32_to_binary(-1e-45), (-1 << 23, -172));
+    }
+
+    #[test]
+    fn test_from_f64_rounded() {
+        let test_data = [
+            (0.1_f64, 2, [10, 11, 10, 11, 10, 10, 10, 11]),
+            (
+                0.1_f64,
+                18,
+                [
+                    100000000000000005,
+                    100000000000000006,
+                    100000000000000005,
+                    100000000000000006,
+                    100000000000000006,
+                    100000000000000006,
+                    100000000000000006,
+                    100000000000000006,
+                ],
+            ),
+            (-0.125_f64, 2, [-12, -13, -13, -12, -12, -13, -12, -12]),
+            (2.5_f64, 0, [2, 3, 2, 3, 2, 3, 2, 2]),
+            (
+                -2.675_f64,
+                2,
+                [-267, -268, -268, -267, -267, -267, -267, -267],
+            ),
+            (1e-300_f64, 18, [0, 1, 0, 1, 0, 0, 0, 1]),
+            (-5e-324_f64, 18, [0, -1, -1, 0, 0, 0, 0, -1]),
+            (
+                1e20_f64,
+                18,
+                [
+                    100000000000000000000000000000000000000,
+                    100000000000000000000000000000000000000,
+                    100000000000000000000000000000000000000,
+                    100000000000000000000000000000000000000,
+                    100000000000000000000000000000000000000,
+                    100000000000000000000000000000000000000,
+                    100000000000000000000000000000000000000,
+                    100000000000000000000000000000000000000,
+                ],
+            ),
+        ];
+        for (f, n, coeffs) in test_data {
+            for (mode, coeff) in MODES.iter().zip(coeffs) {
+                let d = Decimal::from_f64_rounded(f, n, *mode).unwrap();
+                assert_eq!(d.coefficient(), coeff, "{f} {n} {mode:?}");
+                assert_eq!(d.n_frac_digits(), n);
+            }
+        }
+    }
+
+    #[test]
+    fn test_from_f32_rounded() {
+        let d =
+            Decimal::from_f32_rounded(0.1, 2, RoundingMode::RoundHalfEven)
+                .unwrap();
+        assert_eq!(d, Decimal::new_raw(10, 2));
+        let d = Decimal::from_f32_rounded(-0.1, 10, RoundingMode::RoundUp)
+            .unwrap();
+        assert_eq!(d, Decimal::new_raw(-1000000015, 10));
+        let d = Decimal::from_f32_rounded(1e-45, 18, RoundingMode::RoundUp)
+            .unwrap();
+        assert_eq!(d, Decimal::new_raw(1, 18));
+        let d = Decimal::from_f32_rounded(1.5e38, 0, RoundingMode::RoundUp)
+            .unwrap();
+        assert_eq!(d.coefficient(), 150000000274887787888901997140572635136);
+    }
+
+    #[test]
+    fn test_from_float_rounded_errors() {
+        let mode = RoundingMode::RoundHalfEven;
+        assert_eq!(
+            Decimal::from_f64_rounded(1.5, 19, mode).unwrap_err(),
+            DecimalError::MaxNFracDigitsExceeded
+        );
+        assert_eq!(
+            Decimal::from_f64_rounded(f64::NAN, 2, mode).unwrap_err(),
+            DecimalError::NotANumber
+        );
+        assert_eq!(
+            Decimal::from_f32_rounded(f32::NEG_INFINITY, 2, mode)
+                .unwrap_err(),
+            DecimalError::InfiniteValue
+        );
+        assert_eq!(
+            Decimal::from_f64_rounded(1e21, 18, mode).unwrap_err(),
+            DecimalError::InternalOverflow
+        );
+        assert_eq!(
+            Decimal::from_f64_rounded(-1e39, 0, mode).unwrap_err(),
+            DecimalError::InternalOverflow
+        );
+        assert_eq!(
+            Decimal::from_f32_rounded(1.5e38, 1, mode).unwrap_err(),
+            DecimalError::InternalOverflow
+        );
+    }
+
+    #[test]
+    fn test_from_f64_exact() {
+        let test_data = [
+            (0.0_f64, 0, 0),
+            (-0.0_f64, 0, 0),
+            (-289.0625_f64, -2890625, 4),
+            (0.5_f64, 5, 1),
+            (1e20_f64, 100000000000000000000, 0),
+            (2.0_f64.powi(-18), 3814697265625, 18),
+            (-(2.0_f64.powi(50) + 0.75), -112589990684262475, 2),
+        ];
+        for (f, coeff, n) in test_data {
+            let d = Decimal::from_f64_exact(f).unwrap();
+            assert_eq!(d.coefficient(), coeff);
+            assert_eq!(d.n_frac_digits(), n);
+        }
+    }
+
+    #[test]
+    fn test_from_f64_exact_errors() {
+        for f in [0.1_f64, 2.0_f64.powi(-19), 5e-324] {
+            assert_eq!(
+                Decimal::from_f64_exact(f).unwrap_err(),
+                DecimalError::MaxNFracDigitsExceeded
+            );
+        }
+        for f in [1e39_f64, 2.0_f64.powi(127)] {
+            assert_eq!(
+                Decimal::from_f64_exact(f).unwrap_err(),
+                DecimalError::InternalOverflow
+            );
+        }
+        assert_eq!(
+            Decimal::from_f64_exact(f64::INFINITY).unwrap_err(),
+            DecimalError::InfiniteValue
+        );
+        assert_eq!(
+            Decimal::from_f64_exact(f64::NAN).unwrap_err(),
+            DecimalError::NotANumber
+        );
+    }
+}
+
+#[cfg(test)]
+mod from_float_shortest_tests {
+    use super::*;
+
+    #[test]
+    fn test_from_f64_shortest() {
+        let test_data = [
+            (0.0_f64, 0, 0),
+            (-0.0_f64, 0, 0),
+            (0.1_f64, 1, 1),
+            (-0.3_f64, -3, 1),
+            (1e-7_f64, 1, 7),
+            (123.456_f64, 123456, 3),
+            (1e-18_f64, 1, 18),
+            (5e17_f64, 500000000000000000, 0),
+            (9007199254740993_f64, 9007199254740992, 0),
+            (1.2345678901234567e27_f64, 1234567890123456700000000000, 0),
+            (
+                2.0_f64.powi(127),
+                170141183460469230000000000000000000000,
+                0,
+            ),
+            // tie between 820.6672973632812 and 820.6672973632813
+            (820.66729736328125_f64, 8206672973632812, 13),
+        ];
+        for (f, coeff, n) in test_data {
+            let d = Decimal::from_f64_shortest(f).unwrap();
+            assert_eq!(d.coefficient(), coeff, "{f}");
+            assert_eq!(d.n_frac_digits(), n, "{f}");
+            assert_eq!(f64::from(d), f);
+        }
+    }
+
+    #[test]
+    fn test_from_f32_shortest() {
+        let test_data = [
+            (0.1_f32, 1, 1),
+            (16777217_f32, 16777216, 0),
+            (1.7656293e6_f32, 17656292, 1),
+            (-2.5e-5_f32, -25, 6),
+        ];
+        for (f, coeff, n) in test_data {
+            let d = Decimal::from_f32_shortest(f).unwrap();
+            assert_eq!(d.coefficient(), coeff, "{f}");
+            assert_eq!(d.n_frac_digits(), n, "{f}");
+            assert_eq!(f32::from(d), f);
+        }
+    }
+
+    #[test]
+    fn test_from_float_shortest_errors() {
+        for f in [1e-19_f64, -2.2250738585072014e-308, 5e-324] {
+            assert_eq!(
+                Decimal::from_f64_shortest(f).unwrap_err(),
+                DecimalError::MaxNFracDigitsExceeded
+            );
+        }
+        for f in [2e38_f64, -1e300, f64::MAX] {
+            assert_eq!(
+                Decimal::from_f64_shortest(f).unwrap_err(),
+                DecimalError::InternalOverflow
+            );
+        }
+        assert_eq!(
+            Decimal::from_f32_shortest(-1.17549435e-38).unwrap_err(),
+            DecimalError::MaxNFracDigitsExceeded
+        );
+        assert_eq!(
+            Decimal::from_f32_shortest(3.4028235e38).unwrap_err(),
+            DecimalError::InternalOverflow
+        );
+        assert_eq!(
+            Decimal::from_f64_shortest(f64::NEG_INFINITY).unwrap_err(),
+            DecimalError::InfiniteValue
+        );
+        assert_eq!(
+            Decimal::from_f32_shortest(f32::NAN).unwrap_err(),
+            DecimalError::NotANumber
+        );
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
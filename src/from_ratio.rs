@@ -0,0 +1,343 @@
+// ---------------------------------------------------------------------------
+// Copyright:   (c) 2026 ff. Michael Amrhein (michael@adrhinum.de)
+// License:     This program is part of a larger application. For license
+//              details please read the file LICENSE.TXT provided together
+//              with the application.
+// ---------------------------------------------------------------------------
+// $Source$
+// $Revision$
+
+use core::cmp::max;
+
+use fpdec_core::{i128_shifted_div_rounded, MAX_N_FRAC_DIGITS};
+
+use crate::{as_integer_ratio::gcd, Decimal, DecimalError, RoundingMode};
+
+impl Decimal {
+    /// Converts the ratio `numer / denom` into an equivalent `Decimal`.
+    ///
+    /// Returns the `Decimal` with the least number of fractional digits
+    /// exactly equal to `numer / denom`, if such a value exists, wrapped in
+    /// Result::Ok.
+    ///
+    /// A finite decimal representation exists only if the denominator of
+    /// the reduced ratio has no prime factors other than 2 and 5.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error (wrapped in Result::Err) in the following cases:
+    /// * `denom` == 0 => `DecimalError::DivisionByZero`,
+    /// * the ratio can not be represented with at most
+    ///   [MAX_N_FRAC_DIGITS](crate::MAX_N_FRAC_DIGITS) fractional digits,
+    ///   including the case of a non-terminating decimal expansion =>
+    ///   `DecimalError::MaxNFracDigitsExceeded`,
+    /// * the resulting coefficient exceeds the internal representation of
+    ///   `Decimal` => `DecimalError::InternalOverflow`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fpdec::{Decimal, DecimalError};
+    /// # fn main() -> Result<(), DecimalError> {
+    /// let d = Decimal::from_integer_ratio(565419, 20000)?;
+    /// assert_eq!(d.to_string(), "28.27095");
+    /// let d = Decimal::from_integer_ratio(-21, 12)?;
+    /// assert_eq!(d.to_string(), "-1.75");
+    /// let res = Decimal::from_integer_ratio(1, 3);
+    /// assert_eq!(res.unwrap_err(), DecimalError::MaxNFracDigitsExceeded);
+    /// # Ok(()) }
+    /// ```
+    pub fn from_integer_ratio(
+        numer: i128,
+        denom: i128,
+    ) -> Result<Self, DecimalError> {
+        if denom == 0 {
+            return Err(DecimalError::DivisionByZero);
+        }
+        if numer == 0 {
+            return Ok(Self::ZERO);
+        }
+        let gcd = gcd(numer.unsigned_abs(), denom.unsigned_abs());
+        #[allow(clippy::integer_division)]
+        let mut num = numer.unsigned_abs() / gcd;
+        #[allow(clippy::integer_division)]
+        let mut den = denom.unsigned_abs() / gcd;
+        // den = 2 ^ n_twos * 5 ^ n_fives * rest
+        let n_twos = den.trailing_zeros();
+        den >>= n_twos;
+        let mut n_fives = 0_u32;
+        while den % 5 == 0 {
+            den /= 5;
+            n_fives += 1;
+        }
+        if den != 1 {
+            // non-terminating decimal expansion
+            return Err(DecimalError::MaxNFracDigitsExceeded);
+        }
+        let n_frac_digits = max(n_twos, n_fives);
+        if n_frac_digits > MAX_N_FRAC_DIGITS as u32 {
+            return Err(DecimalError::MaxNFracDigitsExceeded);
+        }
+        // num / den = num * 2 ^ (n - n_twos) * 5 ^ (n - n_fives) / 10 ^ n
+        num = num
+            .checked_mul(1_u128 << (n_frac_digits - n_twos))
+            .and_then(|num| {
+                num.checked_mul(5_u128.pow(n_frac_digits - n_fives))
+            })
+            .ok_or(DecimalError::InternalOverflow)?;
+        let Ok(mut coeff) = i128::try_from(num) else {
+            return Err(DecimalError::InternalOverflow);
+        };
+        if (numer < 0) != (denom < 0) {
+            coeff = -coeff;
+        }
+        #[allow(clippy::cast_possible_truncation)]
+        Ok(Self {
+            coeff,
+            n_frac_digits: n_frac_digits as u8,
+        })
+    }
+
+    /// Converts the ratio `numer / denom` into a `Decimal` with
+    /// `n_frac_digits` fractional digits, rounded according to `mode`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error (wrapped in Result::Err) in the following cases:
+    /// * `denom` == 0 => `DecimalError::DivisionByZero`,
+    /// * `n_frac_digits` > [MAX_N_FRAC_DIGITS](crate::MAX_N_FRAC_DIGITS) =>
+    ///   `DecimalError::MaxNFracDigitsExceeded`,
+    /// * the resulting coefficient exceeds the internal representation of
+    ///   `Decimal` => `DecimalError::InternalOverflow`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fpdec::{Decimal, DecimalError, RoundingMode};
+    /// # fn main() -> Result<(), DecimalError> {
+    /// let d = Decimal::from_integer_ratio_rounded(
+    ///     2, 3, 4, RoundingMode::RoundHalfUp,
+    /// )?;
+    /// assert_eq!(d.to_string(), "0.6667");
+    /// let d = Decimal::from_integer_ratio_rounded(
+    ///     -2, 3, 4, RoundingMode::RoundDown,
+    /// )?;
+    /// assert_eq!(d.to_string(), "-0.6666");
+    /// let d = Decimal::from_integer_ratio_rounded(
+    ///     1, 8, 2, RoundingMode::RoundHalfEven,
+    /// )?;
+    /// assert_eq!(d.to_string(), "0.12");
+    /// # Ok(()) }
+    /// ```
+    pub fn from_integer_ratio_rounded(
+        numer: i128,
+        denom: i128,
+        n_frac_digits: u8,
+        mode: RoundingMode,
+    ) -> Result<Self, DecimalError> {
+        if n_frac_digits > MAX_N_FRAC_DIGITS {
+            return Err(DecimalError::MaxNFracDigitsExceeded);
+        }
+        if denom == 0 {
+            return Err(DecimalError::DivisionByZero);
+        }
+        if numer == 0 {
+            return Ok(Self::ZERO);
+        }
+        match i128_shifted_div_rounded(
+            numer,
+            n_frac_digits,
+            denom,
+            Some(mode),
+        ) {
+            Some(coeff) => Ok(Self {
+                coeff,
+                n_frac_digits,
+            }),
+            None => Err(DecimalError::InternalOverflow),
+        }
+    }
+}
+
+#[cfg(test)]
+mod from_integer_ratio_tests {
+    use super::*;
+    use crate::AsIntegerRatio;
+
+    #[test]
+    fn test_from_integer_ratio() {
+        let test_data = [
+            (0_i128, 7_i128, 0_i128, 0_u8),
+            (12345, 1, 12345, 0),
+            (565419, 20000, 2827095, 5),
+            (-21, 12, -175, 2),
+            (21, -12, -175, 2),
+            (-21, -12, 175, 2),
+            (3, 3, 1, 0),
+            (1, 1 << 18, 3814697265625, 18),
+            (7, 5_i128.pow(18), 1835008, 18),
+            (i128::MAX, 1, i128::MAX, 0),
+        ];
+        for (numer, denom, coeff, n_frac_digits) in test_data {
+            let d = Decimal::from_integer_ratio(numer, denom).unwrap();
+            assert_eq!(d.coefficient(), coeff);
+            assert_eq!(d.n_frac_digits(), n_frac_digits);
+        }
+    }
+
+    #[test]
+    fn test_roundtrip() {
+        for d in [
+            Decimal::new_raw(2827095, 5),
+            Decimal::new_raw(-1, 18),
+            Decimal::MAX,
+            Decimal::MIN,
+        ] {
+            let (numer, denom) = d.as_integer_ratio();
+            assert_eq!(Decimal::from_integer_ratio(numer, denom).unwrap(), d);
+        }
+    }
+
+    #[test]
+    fn test_non_terminating() {
+        for (numer, denom) in [(1, 3), (-5, 7), (17, 30), (1, 1 << 19)] {
+            let res = Decimal::from_integer_ratio(numer, denom);
+            assert_eq!(
+                res.unwrap_err(),
+                DecimalError::MaxNFracDigitsExceeded
+            );
+        }
+    }
+
+    #[test]
+    fn test_div_by_zero() {
+        let res = Decimal::from_integer_ratio(1, 0);
+        assert_eq!(res.unwrap_err(), DecimalError::DivisionByZero);
+    }
+
+    #[test]
+    fn test_overflow() {
+        let res = Decimal::from_integer_ratio(i128::MAX, 2);
+        assert_eq!(res.unwrap_err(), DecimalError::InternalOverflow);
+        let res = Decimal::from_integer_ratio(i128::MIN, 1);
+        assert_eq!(res.unwrap_err(), DecimalError::InternalOverflow);
+    }
+}
+
+#[cfg(test)]
+mod from_integer_ratio_rounded_tests {
+    use super::*;
+
+    #[test]
+    fn test_from_integer_ratio_rounded() {
+        let test_data = [
+            (2_i128, 3_i128, 4_u8, RoundingMode::RoundHalfUp, 6667_i128),
+            (2, 3, 4, RoundingMode::RoundDown, 6666),
+            (-2, 3, 4, RoundingMode::RoundDown, -6666),
+            (-2, 3, 4, RoundingMode::RoundFloor, -6667),
+            (2, -3, 4, RoundingMode::RoundCeiling, -6666),
+            (1, 8, 2, RoundingMode::RoundHalfEven, 12),
+            (3, 8, 2, RoundingMode::RoundHalfEven, 38),
+            (1, 8, 2, RoundingMode::RoundHalfUp, 13),
+            (-1, 8, 2, RoundingMode::RoundHalfDown, -12),
+            (1, 3, 0, RoundingMode::RoundUp, 1),
+            (
+                i128::MIN,
+                -3,
+                0,
+                RoundingMode::RoundHalfEven,
+                56713727820156410577229101238628035243,
+            ),
+        ];
+        for (numer, denom, n_frac_digits, mode, coeff) in test_data {
+            let d = Decimal::from_integer_ratio_rounded(
+                numer,
+                denom,
+                n_frac_digits,
+                mode,
+            )
+            .unwrap();
+            assert_eq!(d.coefficient(), coeff);
+            assert_eq!(d.n_frac_digits(), n_frac_digits);
+        }
+    }
+
+    #[test]
+    fn test_zero() {
+        let d = Decimal::from_integer_ratio_rounded(
+            0,
+            -7,
+            5,
+            RoundingMode::RoundUp,
+        )
+        .unwrap();
+        assert_eq!(d, Decimal::ZERO);
+    }
+
+    #[test]
+    fn test_errors() {
+        let mode = RoundingMode::RoundHalfEven;
+        let res = Decimal::from_integer_ratio_rounded(1, 3, 19, mode);
+        assert_eq!(res.unwrap_err(), DecimalError::MaxNFracDigitsExceeded);
+        let res = Decimal::from_integer_ratio_rounded(1, 0, 2, mode);
+        assert_eq!(res.unwrap_err(), DecimalError::DivisionByZero);
+        let res = Decimal::from_integer_ratio_rounded(i128::MAX, 3, 2, mode);
+        assert_eq!(res.unwrap_err(), DecimalError::InternalOverflow);
+    }
+}
+
+#[cfg(feature = "num-traits")]
+mod ratio {
+    use num_rational::Ratio;
+
+    use crate::{AsIntegerRatio, Decimal, DecimalError};
+
+    impl From<Decimal> for Ratio<i128> {
+        /// Converts a `Decimal` value `d` into the equivalent reduced
+        /// `Ratio<i128>`.
+        #[inline]
+        fn from(d: Decimal) -> Self {
+            let (numer, denom) = d.as_integer_ratio();
+            Self::new_raw(numer, denom)
+        }
+    }
+
+    impl TryFrom<Ratio<i128>> for Decimal {
+        type Error = DecimalError;
+
+        /// Tries to convert a `Ratio<i128>` value `r` into an equivalent
+        /// `Decimal`.
+        ///
+        /// See [Decimal::from_integer_ratio] for details.
+        #[inline]
+        fn try_from(r: Ratio<i128>) -> Result<Self, Self::Error> {
+            Self::from_integer_ratio(*r.numer(), *r.denom())
+        }
+    }
+
+    #[cfg(test)]
+    mod ratio_tests {
+        use super::*;
+
+        #[test]
+        fn test_from_decimal() {
+            let d = Decimal::new_raw(-2827095, 5);
+            let r = Ratio::<i128>::from(d);
+            assert_eq!(*r.numer(), -565419);
+            assert_eq!(*r.denom(), 20000);
+        }
+
+        #[test]
+        fn test_try_into_decimal() {
+            let r = Ratio::new(-21_i128, 12);
+            let d = Decimal::try_from(r).unwrap();
+            assert_eq!(d, Decimal::new_raw(-175, 2));
+            let r = Ratio::new(2_i128, 3);
+            let res = Decimal::try_from(r);
+            assert_eq!(
+                res.unwrap_err(),
+                DecimalError::MaxNFracDigitsExceeded
+            );
+        }
+    }
+}
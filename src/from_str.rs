@@ -7,6 +7,7 @@
 // $Source$
 // $Revision$
 
+#[cfg(feature = "alloc")]
 use alloc::string::String;
 use core::{convert::TryFrom, str::FromStr};
 
@@ -87,6 +88,7 @@ impl TryFrom<&str> for Decimal {
     }
 }
 
+#[cfg(feature = "alloc")]
 impl TryFrom<String> for Decimal {
     type Error = ParseDecimalError;
 
@@ -251,6 +253,7 @@ mod tests {
         assert_eq!(d, Decimal::MAX);
     }
 
+    #[cfg(feature = "alloc")]
     #[test]
     fn test_try_from_min_as_string() {
         let lit: String = (-i128::MAX).to_string();
@@ -0,0 +1,791 @@
+// ---------------------------------------------------------------------------
+// Copyright:   (c) 2026 ff. Michael Amrhein (michael@adrhinum.de)
+// License:     This program is part of a larger application. For license
+//              details please read the file LICENSE.TXT provided together
+//              with the application.
+// ---------------------------------------------------------------------------
+// $Source$
+// $Revision$
+
+use fpdec_core::{
+    checked_mul_pow_ten, i128_div_rounded, i128_magnitude, ten_pow,
+    MAX_N_FRAC_DIGITS,
+};
+
+use crate::{Decimal, DecimalError, RoundingMode};
+
+/// Parameters of an IEEE 754 decimal interchange format.
+struct Format {
+    /// Total number of bits.
+    n_bits: u32,
+    /// Number of exponent continuation bits.
+    w: u32,
+    /// Number of trailing significand bits.
+    t: u32,
+    /// Bias of the exponent.
+    bias: i32,
+    /// Number of significant decimal digits.
+    precision: u8,
+    /// Number of declets in the trailing significand (DPD).
+    n_declets: u32,
+}
+
+const DECIMAL64: Format = Format {
+    n_bits: 64,
+    w: 8,
+    t: 50,
+    bias: 398,
+    precision: 16,
+    n_declets: 5,
+};
+
+const DECIMAL128: Format = Format {
+    n_bits: 128,
+    w: 12,
+    t: 110,
+    bias: 6176,
+    precision: 34,
+    n_declets: 11,
+};
+
+const COMB_INF: u128 = 0b11110;
+const COMB_NAN: u128 = 0b11111;
+
+#[inline(always)]
+const fn mask(n_bits: u32) -> u128 {
+    (1_u128 << n_bits) - 1
+}
+
+/// Returns the densely packed decimal encoding of `n` (< 1000).
+#[allow(clippy::integer_division)]
+const fn dpd_encode_declet(n: u16) -> u16 {
+    let (d2, d1, d0) = (n / 100, n / 10 % 10, n % 10);
+    let (b, c, d) = ((d2 >> 2) & 1, (d2 >> 1) & 1, d2 & 1);
+    let (f, g, h) = ((d1 >> 2) & 1, (d1 >> 1) & 1, d1 & 1);
+    let (j, k, m) = ((d0 >> 2) & 1, (d0 >> 1) & 1, d0 & 1);
+    let bits = match (d2 > 7, d1 > 7, d0 > 7) {
+        (false, false, false) => [b, c, d, f, g, h, 0, j, k, m],
+        (false, false, true) => [b, c, d, f, g, h, 1, 0, 0, m],
+        (false, true, false) => [b, c, d, j, k, h, 1, 0, 1, m],
+        (true, false, false) => [j, k, d, f, g, h, 1, 1, 0, m],
+        (true, true, false) => [j, k, d, 0, 0, h, 1, 1, 1, m],
+        (true, false, true) => [f, g, d, 0, 1, h, 1, 1, 1, m],
+        (false, true, true) => [b, c, d, 1, 0, h, 1, 1, 1, m],
+        (true, true, true) => [0, 0, d, 1, 1, h, 1, 1, 1, m],
+    };
+    let mut declet = 0_u16;
+    let mut i = 0;
+    while i < 10 {
+        declet = (declet << 1) | bits[i];
+        i += 1;
+    }
+    declet
+}
+
+/// Returns the value of the densely packed decimal `declet`.
+///
+/// Non-canonical declets are decoded as specified by IEEE 754, i.e. the
+/// bits not needed to encode the digits are ignored.
+fn dpd_decode_declet(declet: u16) -> u16 {
+    let bit = |i: u16| (declet >> (9 - i)) & 1;
+    let (p, q, r, s, t, u, v, w, x, y) = (
+        bit(0),
+        bit(1),
+        bit(2),
+        bit(3),
+        bit(4),
+        bit(5),
+        bit(6),
+        bit(7),
+        bit(8),
+        bit(9),
+    );
+    let (pqr, stu, pqy, sty) = (
+        (p << 2) | (q << 1) | r,
+        (s << 2) | (t << 1) | u,
+        (p << 2) | (q << 1) | y,
+        (s << 2) | (t << 1) | y,
+    );
+    let pqu = (p << 2) | (q << 1) | u;
+    let (d2, d1, d0) = match (v, w, x, s, t) {
+        (0, ..) => (pqr, stu, (w << 2) | (x << 1) | y),
+        (_, 0, 0, ..) => (pqr, stu, 8 | y),
+        (_, 0, 1, ..) => (pqr, 8 | u, sty),
+        (_, 1, 0, ..) => (8 | r, stu, pqy),
+        (_, _, _, 0, 0) => (8 | r, 8 | u, pqy),
+        (_, _, _, 0, 1) => (8 | r, pqu, 8 | y),
+        (_, _, _, 1, 0) => (pqr, 8 | u, 8 | y),
+        _ => (8 | r, 8 | u, 8 | y),
+    };
+    d2 * 100 + d1 * 10 + d0
+}
+
+/// Returns sign, coefficient and biased exponent of `d`, the coefficient
+/// being rounded according to `mode` to at most `fmt.precision` digits.
+#[allow(clippy::cast_sign_loss)]
+fn to_sign_coeff_exp(
+    d: Decimal,
+    mode: RoundingMode,
+    fmt: &Format,
+) -> (bool, u128, u128) {
+    let mut coeff = d.coeff;
+    let mut exp = -i32::from(d.n_frac_digits);
+    let n_digits = i128_magnitude(coeff) + 1;
+    if n_digits > fmt.precision {
+        let shift = n_digits - fmt.precision;
+        coeff = i128_div_rounded(coeff, ten_pow(shift), Some(mode));
+        exp += i32::from(shift);
+        if coeff.unsigned_abs() == ten_pow(fmt.precision) as u128 {
+            coeff /= 10;
+            exp += 1;
+        }
+    }
+    // exp is in -18..=39, so the biased exponent is always positive
+    (d.coeff < 0, coeff.unsigned_abs(), (exp + fmt.bias) as u128)
+}
+
+/// Returns the `Decimal` equal to `coeff` * 10 ^ (`biased_exp` - bias).
+#[allow(clippy::cast_possible_truncation)]
+#[allow(clippy::cast_possible_wrap)]
+#[allow(clippy::cast_sign_loss)]
+fn from_sign_coeff_exp(
+    sign: bool,
+    coeff: u128,
+    biased_exp: u128,
+    fmt: &Format,
+) -> Result<Decimal, DecimalError> {
+    // coeff < 10 ^ 34 and biased_exp < 3 * 2 ^ 12, so both casts are safe
+    let mut coeff = coeff as i128;
+    let exp = biased_exp as i32 - fmt.bias;
+    if coeff == 0 {
+        // keep the exponent of zero as far as it fits into a `Decimal`
+        let n_frac_digits = (-exp).clamp(0, i32::from(MAX_N_FRAC_DIGITS));
+        return Ok(Decimal::new_raw(0, n_frac_digits as u8));
+    }
+    let mut n_frac_digits = 0_u8;
+    if exp >= 0 {
+        coeff = u8::try_from(exp)
+            .ok()
+            .and_then(|exp| checked_mul_pow_ten(coeff, exp))
+            .ok_or(DecimalError::InternalOverflow)?;
+    } else {
+        let mut n = -exp;
+        while n > i32::from(MAX_N_FRAC_DIGITS) && coeff % 10 == 0 {
+            coeff /= 10;
+            n -= 1;
+        }
+        if n > i32::from(MAX_N_FRAC_DIGITS) {
+            return Err(DecimalError::MaxNFracDigitsExceeded);
+        }
+        n_frac_digits = n as u8;
+    }
+    if sign {
+        coeff = -coeff;
+    }
+    Ok(Decimal::new_raw(coeff, n_frac_digits))
+}
+
+/// Returns the combination field's leading 5 bits of `bits`.
+#[inline(always)]
+const fn leading_comb_bits(bits: u128, fmt: &Format) -> u128 {
+    (bits >> (fmt.t + fmt.w)) & 0x1f
+}
+
+#[inline(always)]
+const fn sign_bit(sign: bool, fmt: &Format) -> u128 {
+    if sign {
+        1 << (fmt.n_bits - 1)
+    } else {
+        0
+    }
+}
+
+const fn check_special(bits: u128, fmt: &Format) -> Result<(), DecimalError> {
+    match leading_comb_bits(bits, fmt) {
+        COMB_NAN => Err(DecimalError::NotANumber),
+        COMB_INF => Err(DecimalError::InfiniteValue),
+        _ => Ok(()),
+    }
+}
+
+fn encode_bid(d: Decimal, mode: RoundingMode, fmt: &Format) -> u128 {
+    let (sign, coeff, exp) = to_sign_coeff_exp(d, mode, fmt);
+    let n_coeff_bits = fmt.t + 3;
+    if coeff <= mask(n_coeff_bits) {
+        sign_bit(sign, fmt) | (exp << n_coeff_bits) | coeff
+    } else {
+        // coeff has the implicit leading bits 100
+        sign_bit(sign, fmt)
+            | (0b11 << (fmt.n_bits - 3))
+            | (exp << (fmt.t + 1))
+            | (coeff & mask(fmt.t + 1))
+    }
+}
+
+#[allow(clippy::cast_sign_loss)]
+fn decode_bid(bits: u128, fmt: &Format) -> Result<Decimal, DecimalError> {
+    check_special(bits, fmt)?;
+    let sign = (bits >> (fmt.n_bits - 1)) != 0;
+    let exp_mask = mask(fmt.w + 2);
+    let (mut coeff, exp) = if leading_comb_bits(bits, fmt) >> 3 == 0b11 {
+        (
+            (0b100 << (fmt.t + 1)) | (bits & mask(fmt.t + 1)),
+            (bits >> (fmt.t + 1)) & exp_mask,
+        )
+    } else {
+        (bits & mask(fmt.t + 3), (bits >> (fmt.t + 3)) & exp_mask)
+    };
+    // non-canonical coefficients are interpreted as zero
+    if coeff >= ten_pow(fmt.precision) as u128 {
+        coeff = 0;
+    }
+    from_sign_coeff_exp(sign, coeff, exp, fmt)
+}
+
+#[allow(clippy::cast_possible_truncation)]
+#[allow(clippy::cast_sign_loss)]
+#[allow(clippy::integer_division)]
+fn encode_dpd(d: Decimal, mode: RoundingMode, fmt: &Format) -> u128 {
+    let (sign, coeff, exp) = to_sign_coeff_exp(d, mode, fmt);
+    let msd_factor = ten_pow(fmt.precision - 1) as u128;
+    let msd = coeff / msd_factor;
+    let mut rem = coeff % msd_factor;
+    let exp_msb = exp >> fmt.w;
+    let comb = if msd < 8 {
+        (exp_msb << 3) | msd
+    } else {
+        0b11000 | (exp_msb << 1) | (msd & 1)
+    };
+    let mut bits = sign_bit(sign, fmt)
+        | (comb << (fmt.t + fmt.w))
+        | ((exp & mask(fmt.w)) << fmt.t);
+    for i in 0..fmt.n_declets {
+        // rem % 1000 < 1000, so the cast is safe
+        let declet = dpd_encode_declet((rem % 1000) as u16);
+        bits |= u128::from(declet) << (10 * i);
+        rem /= 1000;
+    }
+    bits
+}
+
+#[allow(clippy::cast_possible_truncation)]
+fn decode_dpd(bits: u128, fmt: &Format) -> Result<Decimal, DecimalError> {
+    check_special(bits, fmt)?;
+    let sign = (bits >> (fmt.n_bits - 1)) != 0;
+    let comb = leading_comb_bits(bits, fmt);
+    let (exp_msb, mut coeff) = if comb >> 3 == 0b11 {
+        ((comb >> 1) & 0b11, 8 | (comb & 1))
+    } else {
+        (comb >> 3, comb & 0b111)
+    };
+    let exp = (exp_msb << fmt.w) | ((bits >> fmt.t) & mask(fmt.w));
+    for i in (0..fmt.n_declets).rev() {
+        let declet = ((bits >> (10 * i)) & 0x3ff) as u16;
+        coeff = coeff * 1000 + u128::from(dpd_decode_declet(declet));
+    }
+    from_sign_coeff_exp(sign, coeff, exp, fmt)
+}
+
+impl Decimal {
+    /// Returns the IEEE 754 decimal64 encoding of `self` using binary
+    /// integer decimal (BID) encoding of the coefficient.
+    ///
+    /// If the coefficient of `self` has more than 16 digits, it is rounded
+    /// according to `mode`.
+    ///
+    /// The bit pattern can be converted into a `[u8; 8]` via
+    /// `u64::to_be_bytes` or `u64::to_le_bytes`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fpdec::{Dec, Decimal, RoundingMode};
+    /// let mode = RoundingMode::RoundHalfEven;
+    /// assert_eq!(Dec!(1).to_decimal64_bid(mode), 0x31c0000000000001);
+    /// assert_eq!(Dec!(-7.50).to_decimal64_bid(mode), 0xb1800000000002ee);
+    /// // 17 digits => rounded to 1234567890123457E-2
+    /// let d = Dec!(12345678901234.565);
+    /// assert_eq!(d.to_decimal64_bid(RoundingMode::RoundUp), 0x318462d53c8abac1);
+    /// ```
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn to_decimal64_bid(self, mode: RoundingMode) -> u64 {
+        encode_bid(self, mode, &DECIMAL64) as u64
+    }
+
+    /// Converts the IEEE 754 decimal64 value `bits`, encoded using binary
+    /// integer decimal (BID) encoding, into a `Decimal`.
+    ///
+    /// The exponent of the given value is preserved, i.e. the number of
+    /// fractional digits of the result is given by the negated exponent.
+    /// Trailing zeros beyond `MAX_N_FRAC_DIGITS` are removed. Non-canonical
+    /// encodings of the coefficient are interpreted as zero.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error (wrapped in Result::Err) in the following cases:
+    /// * `bits` encodes an infinite value => `DecimalError::InfiniteValue`,
+    /// * `bits` encodes a NaN => `DecimalError::NotANumber`,
+    /// * the value of `bits` needs more than `MAX_N_FRAC_DIGITS` fractional
+    ///   digits => `DecimalError::MaxNFracDigitsExceeded`,
+    /// * the value of `bits` exceeds the range of `Decimal` values =>
+    ///   `DecimalError::InternalOverflow`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fpdec::{Dec, Decimal, DecimalError};
+    /// # fn main() -> Result<(), DecimalError> {
+    /// let d = Decimal::from_decimal64_bid(0xb1800000000002ee)?;
+    /// assert_eq!(d.to_string(), "-7.50");
+    /// # Ok(()) }
+    /// ```
+    pub fn from_decimal64_bid(bits: u64) -> Result<Self, DecimalError> {
+        decode_bid(u128::from(bits), &DECIMAL64)
+    }
+
+    /// Returns the IEEE 754 decimal64 encoding of `self` using densely
+    /// packed decimal (DPD) encoding of the coefficient.
+    ///
+    /// If the coefficient of `self` has more than 16 digits, it is rounded
+    /// according to `mode`.
+    ///
+    /// The bit pattern can be converted into a `[u8; 8]` via
+    /// `u64::to_be_bytes` or `u64::to_le_bytes`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fpdec::{Dec, Decimal, RoundingMode};
+    /// let mode = RoundingMode::RoundHalfEven;
+    /// assert_eq!(Dec!(1).to_decimal64_dpd(mode), 0x2238000000000001);
+    /// assert_eq!(Dec!(-7.50).to_decimal64_dpd(mode), 0xa2300000000003d0);
+    /// ```
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn to_decimal64_dpd(self, mode: RoundingMode) -> u64 {
+        encode_dpd(self, mode, &DECIMAL64) as u64
+    }
+
+    /// Converts the IEEE 754 decimal64 value `bits`, encoded using densely
+    /// packed decimal (DPD) encoding, into a `Decimal`.
+    ///
+    /// The exponent of the given value is preserved, i.e. the number of
+    /// fractional digits of the result is given by the negated exponent.
+    /// Trailing zeros beyond `MAX_N_FRAC_DIGITS` are removed. Non-canonical
+    /// declets are decoded as specified by IEEE 754.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error (wrapped in Result::Err) in the following cases:
+    /// * `bits` encodes an infinite value => `DecimalError::InfiniteValue`,
+    /// * `bits` encodes a NaN => `DecimalError::NotANumber`,
+    /// * the value of `bits` needs more than `MAX_N_FRAC_DIGITS` fractional
+    ///   digits => `DecimalError::MaxNFracDigitsExceeded`,
+    /// * the value of `bits` exceeds the range of `Decimal` values =>
+    ///   `DecimalError::InternalOverflow`.
+    pub fn from_decimal64_dpd(bits: u64) -> Result<Self, DecimalError> {
+        decode_dpd(u128::from(bits), &DECIMAL64)
+    }
+
+    /// Returns the IEEE 754 decimal128 encoding of `self` using binary
+    /// integer decimal (BID) encoding of the coefficient.
+    ///
+    /// If the coefficient of `self` has more than 34 digits, it is rounded
+    /// according to `mode`.
+    ///
+    /// The bit pattern can be converted into a `[u8; 16]` via
+    /// `u128::to_be_bytes` or `u128::to_le_bytes`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fpdec::{Dec, Decimal, RoundingMode};
+    /// assert_eq!(
+    ///     Dec!(1).to_decimal128_bid(RoundingMode::RoundHalfEven),
+    ///     0x30400000000000000000000000000001
+    /// );
+    /// ```
+    #[must_use]
+    pub fn to_decimal128_bid(self, mode: RoundingMode) -> u128 {
+        encode_bid(self, mode, &DECIMAL128)
+    }
+
+    /// Converts the IEEE 754 decimal128 value `bits`, encoded using binary
+    /// integer decimal (BID) encoding, into a `Decimal`.
+    ///
+    /// The exponent of the given value is preserved, i.e. the number of
+    /// fractional digits of the result is given by the negated exponent.
+    /// Trailing zeros beyond `MAX_N_FRAC_DIGITS` are removed. Non-canonical
+    /// encodings of the coefficient are interpreted as zero.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error (wrapped in Result::Err) in the following cases:
+    /// * `bits` encodes an infinite value => `DecimalError::InfiniteValue`,
+    /// * `bits` encodes a NaN => `DecimalError::NotANumber`,
+    /// * the value of `bits` needs more than `MAX_N_FRAC_DIGITS` fractional
+    ///   digits => `DecimalError::MaxNFracDigitsExceeded`,
+    /// * the value of `bits` exceeds the range of `Decimal` values =>
+    ///   `DecimalError::InternalOverflow`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fpdec::{Dec, Decimal, DecimalError};
+    /// # fn main() -> Result<(), DecimalError> {
+    /// let d = Decimal::from_decimal128_bid(0x303e0000000000000000000000000019)?;
+    /// assert_eq!(d.to_string(), "2.5");
+    /// # Ok(()) }
+    /// ```
+    pub fn from_decimal128_bid(bits: u128) -> Result<Self, DecimalError> {
+        decode_bid(bits, &DECIMAL128)
+    }
+
+    /// Returns the IEEE 754 decimal128 encoding of `self` using densely
+    /// packed decimal (DPD) encoding of the coefficient.
+    ///
+    /// If the coefficient of `self` has more than 34 digits, it is rounded
+    /// according to `mode`.
+    ///
+    /// The bit pattern can be converted into a `[u8; 16]` via
+    /// `u128::to_be_bytes` or `u128::to_le_bytes`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fpdec::{Dec, Decimal, RoundingMode};
+    /// assert_eq!(
+    ///     Dec!(1).to_decimal128_dpd(RoundingMode::RoundHalfEven),
+    ///     0x22080000000000000000000000000001
+    /// );
+    /// ```
+    #[must_use]
+    pub fn to_decimal128_dpd(self, mode: RoundingMode) -> u128 {
+        encode_dpd(self, mode, &DECIMAL128)
+    }
+
+    /// Converts the IEEE 754 decimal128 value `bits`, encoded using densely
+    /// packed decimal (DPD) encoding, into a `Decimal`.
+    ///
+    /// The exponent of the given value is preserved, i.e. the number of
+    /// fractional digits of the result is given by the negated exponent.
+    /// Trailing zeros beyond `MAX_N_FRAC_DIGITS` are removed. Non-canonical
+    /// declets are decoded as specified by IEEE 754.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error (wrapped in Result::Err) in the following cases:
+    /// * `bits` encodes an infinite value => `DecimalError::InfiniteValue`,
+    /// * `bits` encodes a NaN => `DecimalError::NotANumber`,
+    /// * the value of `bits` needs more than `MAX_N_FRAC_DIGITS` fractional
+    ///   digits => `DecimalError::MaxNFracDigitsExceeded`,
+    /// * the value of `bits` exceeds the range of `Decimal` values =>
+    ///   `DecimalError::InternalOverflow`.
+    pub fn from_decimal128_dpd(bits: u128) -> Result<Self, DecimalError> {
+        decode_dpd(bits, &DECIMAL128)
+    }
+}
+
+#[cfg(test)]
+mod ieee754_tests {
+    use super::*;
+
+    const MODE: RoundingMode = RoundingMode::RoundHalfEven;
+
+    #[test]
+    fn test_dpd_declets() {
+        for n in 0..1000_u16 {
+            let declet = dpd_encode_declet(n);
+            assert!(declet < 1024);
+            assert_eq!(dpd_decode_declet(declet), n);
+        }
+        assert_eq!(dpd_encode_declet(0), 0);
+        assert_eq!(dpd_encode_declet(750), 0x3d0);
+        assert_eq!(dpd_encode_declet(999), 0x0ff);
+        // non-canonical encodings of 888, 889, 898, 899, 988, 989, 998, 999
+        for (declet, n) in [
+            (0x16e_u16, 888_u16),
+            (0x26e, 888),
+            (0x36e, 888),
+            (0x16f, 889),
+            (0x17e, 898),
+            (0x3fe, 998),
+            (0x3ff, 999),
+        ] {
+            assert_eq!(dpd_decode_declet(declet), n);
+        }
+    }
+
+    #[test]
+    fn test_to_decimal64() {
+        let d = Decimal::ZERO;
+        assert_eq!(d.to_decimal64_bid(MODE), 0x31c0000000000000);
+        assert_eq!(d.to_decimal64_dpd(MODE), 0x2238000000000000);
+        let d = Decimal::new_raw(-750, 2);
+        assert_eq!(d.to_decimal64_bid(MODE), 0xb1800000000002ee);
+        assert_eq!(d.to_decimal64_dpd(MODE), 0xa2300000000003d0);
+        let d = Decimal::new_raw(9_999_999_999_999_999, 0);
+        assert_eq!(d.to_decimal64_bid(MODE), 0x6c7386f26fc0ffff);
+        assert_eq!(d.to_decimal64_dpd(MODE), 0x6e38ff3fcff3fcff);
+        let d = Decimal::new_raw(1, 18);
+        assert_eq!(d.to_decimal64_bid(MODE), 0x2f80000000000001);
+        assert_eq!(d.to_decimal64_dpd(MODE), 0x21f0000000000001);
+    }
+
+    #[test]
+    fn test_to_decimal64_rounded() {
+        // rounded half to even
+        let d = Decimal::new_raw(12_345_678_901_234_565, 3);
+        let r =
+            Decimal::from_decimal64_bid(d.to_decimal64_bid(MODE)).unwrap();
+        assert_eq!(r, Decimal::new_raw(1_234_567_890_123_456, 2));
+        assert_eq!(r.n_frac_digits(), 2);
+        let r =
+            Decimal::from_decimal64_dpd(d.to_decimal64_dpd(MODE)).unwrap();
+        assert_eq!(r.coefficient(), 1_234_567_890_123_456);
+        assert_eq!(r.n_frac_digits(), 2);
+        let d = Decimal::new_raw(-99_999_999_999_999_999, 0);
+        let r =
+            Decimal::from_decimal64_bid(d.to_decimal64_bid(MODE)).unwrap();
+        assert_eq!(r, Decimal::new_raw(-100_000_000_000_000_000, 0));
+        let r =
+            Decimal::from_decimal64_dpd(d.to_decimal64_dpd(MODE)).unwrap();
+        assert_eq!(r, Decimal::new_raw(-100_000_000_000_000_000, 0));
+        let d = Decimal::MAX;
+        let r =
+            Decimal::from_decimal128_bid(d.to_decimal128_bid(MODE)).unwrap();
+        assert_eq!(
+            r,
+            Decimal::new_raw(
+                1_701_411_834_604_692_317_316_873_037_158_841,
+                0
+            ) * 100_000
+        );
+    }
+
+    #[test]
+    fn test_to_decimal64_rounding_mode() {
+        let d = Decimal::new_raw(-12_345_678_901_234_565, 3);
+        for (mode, coeff) in [
+            (RoundingMode::RoundHalfEven, -1_234_567_890_123_456),
+            (RoundingMode::RoundHalfUp, -1_234_567_890_123_457),
+            (RoundingMode::RoundDown, -1_234_567_890_123_456),
+            (RoundingMode::RoundFloor, -1_234_567_890_123_457),
+            (RoundingMode::RoundCeiling, -1_234_567_890_123_456),
+        ] {
+            let r = Decimal::from_decimal64_bid(d.to_decimal64_bid(mode));
+            assert_eq!(r, Ok(Decimal::new_raw(coeff, 2)), "{mode:?}");
+            let r = Decimal::from_decimal64_dpd(d.to_decimal64_dpd(mode));
+            assert_eq!(r, Ok(Decimal::new_raw(coeff, 2)), "{mode:?}");
+        }
+        let d = Decimal::MAX;
+        let r = Decimal::from_decimal128_dpd(
+            d.to_decimal128_dpd(RoundingMode::RoundDown),
+        )
+        .unwrap();
+        assert_eq!(
+            r.coefficient(),
+            170_141_183_460_469_231_731_687_303_715_884_100_000
+        );
+    }
+
+    #[test]
+    fn test_zero_keeps_exponent() {
+        for n_frac_digits in [0_u8, 1, 7, 18] {
+            let d = Decimal::new_raw(0, n_frac_digits);
+            for r in [
+                Decimal::from_decimal64_bid(d.to_decimal64_bid(MODE)),
+                Decimal::from_decimal64_dpd(d.to_decimal64_dpd(MODE)),
+                Decimal::from_decimal128_bid(d.to_decimal128_bid(MODE)),
+                Decimal::from_decimal128_dpd(d.to_decimal128_dpd(MODE)),
+            ] {
+                let r = r.unwrap();
+                assert_eq!(r.coefficient(), 0);
+                assert_eq!(r.n_frac_digits(), n_frac_digits);
+            }
+        }
+        // -0E-2
+        let d = Decimal::from_decimal64_bid(0xb180000000000000).unwrap();
+        assert_eq!(d.n_frac_digits(), 2);
+        // 0E-398 => exponent clamped to -MAX_N_FRAC_DIGITS
+        let d = Decimal::from_decimal64_bid(0x0000000000000000).unwrap();
+        assert_eq!(d, Decimal::ZERO);
+        assert_eq!(d.n_frac_digits(), MAX_N_FRAC_DIGITS);
+        // 0E+5
+        let d = Decimal::from_decimal64_bid(0x3220000000000000).unwrap();
+        assert_eq!(d.n_frac_digits(), 0);
+    }
+
+    #[test]
+    fn test_to_decimal128() {
+        let d = Decimal::ONE;
+        assert_eq!(
+            d.to_decimal128_bid(MODE),
+            0x30400000000000000000000000000001
+        );
+        assert_eq!(
+            d.to_decimal128_dpd(MODE),
+            0x22080000000000000000000000000001
+        );
+        let d = Decimal::new_raw(-25, 1);
+        assert_eq!(
+            d.to_decimal128_bid(MODE),
+            0xb03e0000000000000000000000000019
+        );
+        assert_eq!(
+            d.to_decimal128_dpd(MODE),
+            0xa207c000000000000000000000000025
+        );
+    }
+
+    #[test]
+    fn test_roundtrip() {
+        for d in [
+            Decimal::ZERO,
+            Decimal::new_raw(1, 18),
+            Decimal::new_raw(-1, 18),
+            Decimal::new_raw(123_456, 3),
+            Decimal::new_raw(-1_000_000, 0),
+            Decimal::new_raw(9_999_999_999_999_999, 7),
+            Decimal::new_raw(8_888_888_888_888_888, 18),
+            Decimal::new_raw(-9_007_199_254_740_992, 2),
+        ] {
+            for (r, s) in [
+                (
+                    Decimal::from_decimal64_bid(d.to_decimal64_bid(MODE)),
+                    "bid64",
+                ),
+                (
+                    Decimal::from_decimal64_dpd(d.to_decimal64_dpd(MODE)),
+                    "dpd64",
+                ),
+                (
+                    Decimal::from_decimal128_bid(d.to_decimal128_bid(MODE)),
+                    "bid128",
+                ),
+                (
+                    Decimal::from_decimal128_dpd(d.to_decimal128_dpd(MODE)),
+                    "dpd128",
+                ),
+            ] {
+                let r = r.unwrap();
+                assert_eq!(r.coefficient(), d.coefficient(), "{s}");
+                assert_eq!(r.n_frac_digits(), d.n_frac_digits(), "{s}");
+            }
+        }
+        for d in [
+            Decimal::new_raw(
+                9_999_999_999_999_999_999_999_999_999_999_999,
+                18,
+            ),
+            Decimal::new_raw(
+                -7_777_777_777_777_777_777_777_777_777_777_777,
+                5,
+            ),
+        ] {
+            let r = Decimal::from_decimal128_bid(d.to_decimal128_bid(MODE));
+            assert_eq!(r, Ok(d));
+            let r = Decimal::from_decimal128_dpd(d.to_decimal128_dpd(MODE));
+            assert_eq!(r, Ok(d));
+        }
+    }
+
+    #[test]
+    fn test_from_decimal64() {
+        let d = Decimal::from_decimal64_bid(0x31c0000000000005).unwrap();
+        assert_eq!(d, Decimal::new_raw(5, 0));
+        let d = Decimal::from_decimal64_dpd(0x2238000000000005).unwrap();
+        assert_eq!(d, Decimal::new_raw(5, 0));
+        // positive exponent
+        let d = Decimal::from_decimal64_bid(0x3200000000000007).unwrap();
+        assert_eq!(d, Decimal::new_raw(700, 0));
+        // negative zero, zero with extreme exponent
+        let d = Decimal::from_decimal64_bid(0x8000000000000000).unwrap();
+        assert_eq!(d, Decimal::ZERO);
+        let d = Decimal::from_decimal64_dpd(0x43fc000000000000).unwrap();
+        assert_eq!(d, Decimal::ZERO);
+        assert_eq!(d.n_frac_digits(), 0);
+        // trailing zeros beyond MAX_N_FRAC_DIGITS
+        let d = Decimal::from_decimal64_bid(0x2f200000000003e8).unwrap();
+        assert_eq!(d.coefficient(), 1);
+        assert_eq!(d.n_frac_digits(), 18);
+    }
+
+    #[test]
+    fn test_from_non_canonical() {
+        // BID coefficient > 10 ^ 16 - 1 => zero
+        let d = Decimal::from_decimal64_bid(0x6c7fffffffffffff).unwrap();
+        assert_eq!(d, Decimal::ZERO);
+        // BID128 in second form => zero
+        let d =
+            Decimal::from_decimal128_bid(0x6000000000000000000000000000000f)
+                .unwrap();
+        assert_eq!(d, Decimal::ZERO);
+        // DPD with non-canonical declets
+        let d = Decimal::from_decimal64_dpd(0x22380000000003ff).unwrap();
+        assert_eq!(d, Decimal::new_raw(999, 0));
+        let d =
+            Decimal::from_decimal128_dpd(0x2208000000000000000000000000036e)
+                .unwrap();
+        assert_eq!(d, Decimal::new_raw(888, 0));
+    }
+
+    #[test]
+    fn test_from_special() {
+        for bits in [0x7800000000000000_u64, 0xf800000000000000] {
+            assert_eq!(
+                Decimal::from_decimal64_bid(bits),
+                Err(DecimalError::InfiniteValue)
+            );
+            assert_eq!(
+                Decimal::from_decimal64_dpd(bits),
+                Err(DecimalError::InfiniteValue)
+            );
+        }
+        for bits in [0x7c00000000000000_u64, 0x7e00000000000001] {
+            assert_eq!(
+                Decimal::from_decimal64_bid(bits),
+                Err(DecimalError::NotANumber)
+            );
+            assert_eq!(
+                Decimal::from_decimal64_dpd(bits),
+                Err(DecimalError::NotANumber)
+            );
+        }
+        let bits = 0x78000000000000000000000000000000_u128;
+        assert_eq!(
+            Decimal::from_decimal128_bid(bits),
+            Err(DecimalError::InfiniteValue)
+        );
+        let bits = 0xfc000000000000000000000000000000_u128;
+        assert_eq!(
+            Decimal::from_decimal128_dpd(bits),
+            Err(DecimalError::NotANumber)
+        );
+    }
+
+    #[test]
+    fn test_from_out_of_range() {
+        // 9999999999999999E369
+        assert_eq!(
+            Decimal::from_decimal64_dpd(0x77fcff3fcff3fcff),
+            Err(DecimalError::InternalOverflow)
+        );
+        // 1E39
+        assert_eq!(
+            Decimal::from_decimal128_bid(0x308e0000000000000000000000000001),
+            Err(DecimalError::InternalOverflow)
+        );
+        // 1E-398
+        assert_eq!(
+            Decimal::from_decimal64_bid(0x0000000000000001),
+            Err(DecimalError::MaxNFracDigitsExceeded)
+        );
+        // 11E-19
+        assert_eq!(
+            Decimal::from_decimal64_bid(0x2f4000000000000b),
+            Err(DecimalError::MaxNFracDigitsExceeded)
+        );
+    }
+}
@@ -0,0 +1,478 @@
+// ---------------------------------------------------------------------------
+// Copyright:   (c) 2026 ff. Michael Amrhein (michael@adrhinum.de)
+// License:     This program is part of a larger application. For license
+//              details please read the file LICENSE.TXT provided together
+//              with the application.
+// ---------------------------------------------------------------------------
+// $Source$
+// $Revision$
+
+use alloc::string::String;
+
+use fpdec_core::MAX_N_FRAC_DIGITS;
+
+use crate::{
+    cobol::{decimal_from_digits, scaled_abs_coeff, zoned_digits},
+    Decimal, FieldCodecError,
+};
+
+/// Convention for the sign of a fixed-width numeric field.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum FieldSign {
+    /// No sign indicator, only non-negative values can be represented.
+    Unsigned,
+    /// Leading 'C' (credit) for positive values or zero and 'D' (debit) for
+    /// negative values, as used in ISO 8583 amount fields.
+    LeadingCreditDebit,
+    /// Leading '+' for positive values or zero and '-' for negative values.
+    LeadingPlusMinus,
+    /// Trailing '+' for positive values or zero and '-' for negative values.
+    TrailingPlusMinus,
+}
+
+impl FieldSign {
+    #[inline]
+    const fn width(self) -> usize {
+        match self {
+            Self::Unsigned => 0,
+            _ => 1,
+        }
+    }
+
+    #[inline]
+    const fn indicator(self, is_negative: bool) -> u8 {
+        match (self, is_negative) {
+            (Self::LeadingCreditDebit, false) => b'C',
+            (Self::LeadingCreditDebit, true) => b'D',
+            (_, false) => b'+',
+            (_, true) => b'-',
+        }
+    }
+
+    #[inline]
+    const fn is_negative(
+        self,
+        indicator: u8,
+    ) -> Result<bool, FieldCodecError> {
+        match (self, indicator) {
+            (Self::LeadingCreditDebit, b'C')
+            | (Self::LeadingPlusMinus | Self::TrailingPlusMinus, b'+') => {
+                Ok(false)
+            }
+            (Self::LeadingCreditDebit, b'D')
+            | (Self::LeadingPlusMinus | Self::TrailingPlusMinus, b'-') => {
+                Ok(true)
+            }
+            _ => Err(FieldCodecError::InvalidSign),
+        }
+    }
+}
+
+/// Padding of the digits of a fixed-width numeric field.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum FieldPadding {
+    /// The digits are padded with leading zeros.
+    Zeros,
+    /// The digits are padded with leading spaces.
+    Spaces,
+}
+
+impl Decimal {
+    /// Converts the fixed-width numeric field `field` with an implied
+    /// decimal point into a `Decimal` with `n_frac_digits` fractional
+    /// digits.
+    ///
+    /// The field must have exactly `width` characters, including the sign
+    /// indicator according to `sign`, if any. The digits must be padded
+    /// according to `padding`; at least one digit is required.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error (wrapped in Result::Err) in the following cases:
+    /// * the length of `field` is not equal to `width` or `width` leaves no
+    ///   room for a digit => `FieldCodecError::InvalidLength`,
+    /// * the sign indicator is missing or invalid =>
+    ///   `FieldCodecError::InvalidSign`,
+    /// * the field contains an invalid character or no digit =>
+    ///   `FieldCodecError::InvalidDigit`,
+    /// * `n_frac_digits` > `MAX_N_FRAC_DIGITS` =>
+    ///   `FieldCodecError::MaxNFracDigitsExceeded`,
+    /// * the value exceeds the range of `Decimal` values =>
+    ///   `FieldCodecError::ValueOutOfRange`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fpdec::{
+    /// #     Dec, Decimal, FieldCodecError, FieldPadding, FieldSign,
+    /// # };
+    /// # fn main() -> Result<(), FieldCodecError> {
+    /// let d = Decimal::from_implied_field(
+    ///     "000000012345",
+    ///     12,
+    ///     2,
+    ///     FieldSign::Unsigned,
+    ///     FieldPadding::Zeros,
+    /// )?;
+    /// assert_eq!(d, Dec!(123.45));
+    /// let d = Decimal::from_implied_field(
+    ///     "D00000012345",
+    ///     12,
+    ///     2,
+    ///     FieldSign::LeadingCreditDebit,
+    ///     FieldPadding::Zeros,
+    /// )?;
+    /// assert_eq!(d, Dec!(-123.45));
+    /// # Ok(()) }
+    /// ```
+    pub fn from_implied_field(
+        field: &str,
+        width: usize,
+        n_frac_digits: u8,
+        sign: FieldSign,
+        padding: FieldPadding,
+    ) -> Result<Self, FieldCodecError> {
+        if n_frac_digits > MAX_N_FRAC_DIGITS {
+            return Err(FieldCodecError::MaxNFracDigitsExceeded);
+        }
+        let bytes = field.as_bytes();
+        if bytes.len() != width || width <= sign.width() {
+            return Err(FieldCodecError::InvalidLength);
+        }
+        let (is_negative, mut digits) = match sign {
+            FieldSign::Unsigned => (false, bytes),
+            FieldSign::LeadingCreditDebit | FieldSign::LeadingPlusMinus => {
+                (sign.is_negative(bytes[0])?, &bytes[1..])
+            }
+            FieldSign::TrailingPlusMinus => {
+                (sign.is_negative(bytes[width - 1])?, &bytes[..width - 1])
+            }
+        };
+        if padding == FieldPadding::Spaces {
+            while let Some((b' ', tail)) = digits.split_first() {
+                digits = tail;
+            }
+        }
+        if digits.is_empty() || !digits.iter().all(u8::is_ascii_digit) {
+            return Err(FieldCodecError::InvalidDigit);
+        }
+        decimal_from_digits(
+            digits.iter().map(|b| b - b'0'),
+            is_negative,
+            n_frac_digits,
+        )
+    }
+
+    /// Converts `self` into a fixed-width numeric field of `width`
+    /// characters with an implied decimal point, holding `n_frac_digits`
+    /// fractional digits.
+    ///
+    /// The field includes the sign indicator according to `sign`, if any.
+    /// The digits are right-aligned and padded according to `padding`. If
+    /// `self` has more than `n_frac_digits` fractional digits, it is
+    /// rounded according to the current default rounding mode.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error (wrapped in Result::Err) in the following cases:
+    /// * `width` leaves no room for a digit =>
+    ///   `FieldCodecError::InvalidLength`,
+    /// * `self` is negative and `sign` is `FieldSign::Unsigned` =>
+    ///   `FieldCodecError::InvalidSign`,
+    /// * `n_frac_digits` > `MAX_N_FRAC_DIGITS` =>
+    ///   `FieldCodecError::MaxNFracDigitsExceeded`,
+    /// * the value does not fit into the field =>
+    ///   `FieldCodecError::FieldOverflow`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fpdec::{
+    /// #     Dec, Decimal, FieldCodecError, FieldPadding, FieldSign,
+    /// # };
+    /// # fn main() -> Result<(), FieldCodecError> {
+    /// let d = Dec!(-123.45);
+    /// let s = d.to_implied_field(
+    ///     12,
+    ///     2,
+    ///     FieldSign::LeadingCreditDebit,
+    ///     FieldPadding::Zeros,
+    /// )?;
+    /// assert_eq!(s, "D00000012345");
+    /// let s = d.to_implied_field(
+    ///     8,
+    ///     3,
+    ///     FieldSign::TrailingPlusMinus,
+    ///     FieldPadding::Spaces,
+    /// )?;
+    /// assert_eq!(s, " 123450-");
+    /// # Ok(()) }
+    /// ```
+    pub fn to_implied_field(
+        self,
+        width: usize,
+        n_frac_digits: u8,
+        sign: FieldSign,
+        padding: FieldPadding,
+    ) -> Result<String, FieldCodecError> {
+        if width <= sign.width() {
+            return Err(FieldCodecError::InvalidLength);
+        }
+        let n_digits = width - sign.width();
+        let (is_negative, abs_coeff) =
+            scaled_abs_coeff(self, n_frac_digits, n_digits)?;
+        let is_negative = is_negative && abs_coeff != 0;
+        if is_negative && sign == FieldSign::Unsigned {
+            return Err(FieldCodecError::InvalidSign);
+        }
+        let mut digits = zoned_digits(abs_coeff, n_digits, b'0');
+        if padding == FieldPadding::Spaces {
+            for digit in digits.iter_mut().take(n_digits - 1) {
+                if *digit != b'0' {
+                    break;
+                }
+                *digit = b' ';
+            }
+        }
+        let mut field = String::with_capacity(width);
+        if matches!(
+            sign,
+            FieldSign::LeadingCreditDebit | FieldSign::LeadingPlusMinus
+        ) {
+            field.push(char::from(sign.indicator(is_negative)));
+        }
+        field.extend(digits.into_iter().map(char::from));
+        if sign == FieldSign::TrailingPlusMinus {
+            field.push(char::from(sign.indicator(is_negative)));
+        }
+        Ok(field)
+    }
+}
+
+#[cfg(test)]
+mod implied_field_tests {
+    use super::*;
+
+    #[test]
+    fn test_from_implied_field() {
+        let d = Decimal::from_implied_field(
+            "000000012345",
+            12,
+            2,
+            FieldSign::Unsigned,
+            FieldPadding::Zeros,
+        )
+        .unwrap();
+        assert_eq!(d.coefficient(), 12_345);
+        assert_eq!(d.n_frac_digits(), 2);
+        let d = Decimal::from_implied_field(
+            "C0000",
+            5,
+            3,
+            FieldSign::LeadingCreditDebit,
+            FieldPadding::Zeros,
+        )
+        .unwrap();
+        assert_eq!(d, Decimal::ZERO);
+        let d = Decimal::from_implied_field(
+            "-  705",
+            6,
+            1,
+            FieldSign::LeadingPlusMinus,
+            FieldPadding::Spaces,
+        )
+        .unwrap();
+        assert_eq!(d, Decimal::new_raw(-705, 1));
+        let d = Decimal::from_implied_field(
+            "0705+",
+            5,
+            0,
+            FieldSign::TrailingPlusMinus,
+            FieldPadding::Spaces,
+        )
+        .unwrap();
+        assert_eq!(d, Decimal::new_raw(705, 0));
+        let d = Decimal::from_implied_field(
+            "99999999999999999999999999999999999999",
+            38,
+            18,
+            FieldSign::Unsigned,
+            FieldPadding::Zeros,
+        )
+        .unwrap();
+        assert_eq!(d.coefficient(), 10_i128.pow(38) - 1);
+    }
+
+    #[test]
+    fn test_from_implied_field_invalid() {
+        let sign = FieldSign::LeadingCreditDebit;
+        let padding = FieldPadding::Zeros;
+        for (field, width, err) in [
+            ("C0012", 4, FieldCodecError::InvalidLength),
+            ("C", 1, FieldCodecError::InvalidLength),
+            ("", 0, FieldCodecError::InvalidLength),
+            ("+0012", 5, FieldCodecError::InvalidSign),
+            ("00012", 5, FieldCodecError::InvalidSign),
+            ("C 012", 5, FieldCodecError::InvalidDigit),
+            ("C00-2", 5, FieldCodecError::InvalidDigit),
+            ("C001½", 6, FieldCodecError::InvalidDigit),
+        ] {
+            assert_eq!(
+                Decimal::from_implied_field(field, width, 2, sign, padding),
+                Err(err)
+            );
+        }
+        let sign = FieldSign::TrailingPlusMinus;
+        let padding = FieldPadding::Spaces;
+        for (field, err) in [
+            ("   -", FieldCodecError::InvalidDigit),
+            (" 1 2+", FieldCodecError::InvalidDigit),
+            ("-123", FieldCodecError::InvalidSign),
+        ] {
+            assert_eq!(
+                Decimal::from_implied_field(
+                    field,
+                    field.len(),
+                    2,
+                    sign,
+                    padding
+                ),
+                Err(err)
+            );
+        }
+        assert_eq!(
+            Decimal::from_implied_field(
+                "12",
+                2,
+                19,
+                FieldSign::Unsigned,
+                padding
+            ),
+            Err(FieldCodecError::MaxNFracDigitsExceeded)
+        );
+        assert_eq!(
+            Decimal::from_implied_field(
+                "999999999999999999999999999999999999999",
+                39,
+                0,
+                FieldSign::Unsigned,
+                padding
+            ),
+            Err(FieldCodecError::ValueOutOfRange)
+        );
+    }
+
+    #[test]
+    fn test_to_implied_field() {
+        let d = Decimal::new_raw(12_345, 2);
+        for (width, n_frac_digits, sign, padding, res) in [
+            (
+                12,
+                2,
+                FieldSign::Unsigned,
+                FieldPadding::Zeros,
+                "000000012345",
+            ),
+            (
+                12,
+                2,
+                FieldSign::LeadingCreditDebit,
+                FieldPadding::Zeros,
+                "C00000012345",
+            ),
+            (
+                8,
+                4,
+                FieldSign::LeadingPlusMinus,
+                FieldPadding::Spaces,
+                "+1234500",
+            ),
+            (
+                8,
+                1,
+                FieldSign::TrailingPlusMinus,
+                FieldPadding::Spaces,
+                "   1234+",
+            ),
+            (5, 0, FieldSign::Unsigned, FieldPadding::Spaces, "  123"),
+        ] {
+            assert_eq!(
+                d.to_implied_field(width, n_frac_digits, sign, padding),
+                Ok(String::from(res))
+            );
+        }
+        let d = Decimal::new_raw(-4, 3);
+        assert_eq!(
+            d.to_implied_field(
+                4,
+                2,
+                FieldSign::LeadingPlusMinus,
+                FieldPadding::Spaces
+            ),
+            Ok(String::from("+  0"))
+        );
+        assert_eq!(
+            d.to_implied_field(
+                3,
+                2,
+                FieldSign::Unsigned,
+                FieldPadding::Zeros
+            ),
+            Ok(String::from("000"))
+        );
+    }
+
+    #[test]
+    fn test_to_implied_field_invalid() {
+        let d = Decimal::new_raw(-12_345, 2);
+        assert_eq!(
+            d.to_implied_field(
+                1,
+                2,
+                FieldSign::LeadingCreditDebit,
+                FieldPadding::Zeros
+            ),
+            Err(FieldCodecError::InvalidLength)
+        );
+        assert_eq!(
+            d.to_implied_field(
+                10,
+                2,
+                FieldSign::Unsigned,
+                FieldPadding::Zeros
+            ),
+            Err(FieldCodecError::InvalidSign)
+        );
+        assert_eq!(
+            d.to_implied_field(
+                5,
+                2,
+                FieldSign::LeadingCreditDebit,
+                FieldPadding::Zeros
+            ),
+            Err(FieldCodecError::FieldOverflow)
+        );
+        assert_eq!(
+            d.to_implied_field(
+                30,
+                19,
+                FieldSign::LeadingCreditDebit,
+                FieldPadding::Zeros
+            ),
+            Err(FieldCodecError::MaxNFracDigitsExceeded)
+        );
+    }
+
+    #[test]
+    fn test_roundtrip() {
+        let sign = FieldSign::LeadingPlusMinus;
+        let padding = FieldPadding::Spaces;
+        for d in [Decimal::MAX, Decimal::MIN] {
+            let s = d.to_implied_field(40, 0, sign, padding).unwrap();
+            assert_eq!(
+                Decimal::from_implied_field(&s, 40, 0, sign, padding),
+                Ok(d)
+            );
+        }
+    }
+}
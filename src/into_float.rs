@@ -9,20 +9,25 @@
 
 use core::mem::size_of;
 
-use crate::Decimal;
+use fpdec_core::i128_div_rounded;
+
+use crate::{Decimal, RoundingMode};
 
 #[inline(always)]
-fn n_signif_bits(v: u128) -> u32 {
+const fn n_signif_bits(v: u128) -> u32 {
     u128::BITS - v.leading_zeros()
 }
 
 trait Float: Sized {
     type B: Sized;
+    #[allow(clippy::cast_possible_truncation)]
     const BITS: u32 = size_of::<Self::B>() as u32 * 8;
     const FRACTION_BITS: u32;
     const EXP_BIAS: i32;
     fn from_bits(bits: u64) -> Self;
 
+    #[allow(clippy::cast_possible_truncation)]
+    #[allow(clippy::integer_division)]
     fn from_decimal(d: Decimal) -> Self {
         const EXTRA_BITS: u32 = 3;
         const MASK_EXTRA_BITS: [u128; 2] = [7, 3];
@@ -70,6 +75,7 @@ impl From<Decimal> for f64 {
     #[doc = "Returns the value as `f64`, rounded to the nearest "]
     #[doc = "value representable as such."]
     #[inline(always)]
+    #[allow(clippy::cast_precision_loss)]
     fn from(d: Decimal) -> Self {
         if d.n_frac_digits == 0 || d.coeff == 0 {
             d.coeff as Self
@@ -175,6 +181,7 @@ impl Float for f32 {
     const EXP_BIAS: i32 = Self::MAX_EXP - 1;
 
     #[inline(always)]
+    #[allow(clippy::cast_possible_truncation)]
     fn from_bits(bits: u64) -> Self {
         Self::from_bits(bits as u32)
     }
@@ -185,6 +192,7 @@ impl From<Decimal> for f32 {
     #[doc = "Returns the value as `f32`, rounded to the nearest "]
     #[doc = "value representable as such."]
     #[inline(always)]
+    #[allow(clippy::cast_precision_loss)]
     fn from(d: Decimal) -> Self {
         if d.n_frac_digits == 0 || d.coeff == 0 {
             d.coeff as Self
@@ -259,3 +267,443 @@ mod tests_into_f32 {
         assert_eq!(f, 170141183460469231731.687303715884105727_f32);
     }
 }
+
+/// Returns `(negative, signif, exp)` so that `signif * 2 ^ exp` is the
+/// absolute value of `d`, rounded according to `mode` to a binary
+/// significand with `prec` bits and an exponent not less than the exponent
+/// of the smallest subnormal value, given by `min_exp`.
+#[allow(clippy::cast_possible_truncation)]
+#[allow(clippy::cast_possible_wrap)]
+#[allow(clippy::cast_sign_loss)]
+#[allow(clippy::integer_division)]
+fn to_binary_rounded(
+    d: Decimal,
+    prec: u32,
+    min_exp: i32,
+    mode: RoundingMode,
+) -> (bool, u128, i32) {
+    let negative = d.coeff < 0;
+    if d.coeff == 0 {
+        return (negative, 0, 0);
+    }
+    // Calculate a quotient with prec + 1 or prec + 2 significant bits, so
+    // that d = (quot + frac) * 2 ^ exp with 0 <= frac < 1. Shifting the
+    // divisor left instead of shifting the divident right keeps the
+    // remainder exact.
+    let add_bits = prec + 1;
+    let mut num = d.coeff.unsigned_abs();
+    let mut den = 10_u128.pow(u32::from(d.n_frac_digits));
+    let num_lz = num.leading_zeros();
+    let den_lz = den.leading_zeros();
+    // den < 2 ^ 60 and add_bits <= 54 => num_shl <= num_lz
+    let num_shl = (num_lz + add_bits).saturating_sub(den_lz);
+    let den_shl = den_lz.saturating_sub(num_lz).saturating_sub(add_bits);
+    num <<= num_shl;
+    den <<= den_shl;
+    let (quot, rem) = (num / den, num % den);
+    let exp = den_shl as i32 - num_shl as i32;
+    // exponent of the least significant bit of the result
+    let lsb_exp =
+        (exp + n_signif_bits(quot) as i32 - prec as i32).max(min_exp);
+    // Round (2 * quot + sticky bit) / 2 ^ (shift + 1), where the sticky bit
+    // makes sure that an inexact quotient is not taken as a tie.
+    let shift = (lsb_exp - exp) as u32 + 1;
+    let mut divident = (quot << 1) | u128::from(rem != 0);
+    // divident < 2 ^ 56, so a smaller shift gives the same result
+    let shift = if shift > 120 {
+        divident = 1;
+        120
+    } else {
+        shift
+    };
+    let divident = if negative {
+        -(divident as i128)
+    } else {
+        divident as i128
+    };
+    let signif = i128_div_rounded(divident, 1_i128 << shift, Some(mode));
+    (negative, signif.unsigned_abs(), lsb_exp)
+}
+
+// Returns `signif * 2 ^ exp` as f64, which must be exactly representable.
+#[allow(clippy::cast_precision_loss)]
+fn compose_f64(negative: bool, signif: u128, exp: i32) -> f64 {
+    let f = signif as f64 * 2_f64.powi(exp);
+    if negative {
+        -f
+    } else {
+        f
+    }
+}
+
+impl Decimal {
+    /// Converts `self` into an `f64`, rounded according to `mode`.
+    ///
+    /// The rounding is done exactly, i.e. the result is the `f64` value
+    /// adjacent to `self` selected by `mode`, if `self` is not exactly
+    /// representable as `f64`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fpdec::{Dec, Decimal, RoundingMode};
+    /// let d = Dec!(0.1);
+    /// let lo = d.to_f64_rounded(RoundingMode::RoundFloor);
+    /// let hi = d.to_f64_rounded(RoundingMode::RoundCeiling);
+    /// assert!(lo < d && d < hi);
+    /// assert_eq!(hi, f64::from(d));
+    /// assert_eq!(lo, 0.09999999999999999);
+    /// ```
+    #[must_use]
+    pub fn to_f64_rounded(self, mode: RoundingMode) -> f64 {
+        let (negative, signif, exp) =
+            to_binary_rounded(self, 53, -1074, mode);
+        compose_f64(negative, signif, exp)
+    }
+
+    /// Converts `self` into an `f32`, rounded according to `mode`.
+    ///
+    /// The rounding is done exactly, i.e. the result is the `f32` value
+    /// adjacent to `self` selected by `mode`, if `self` is not exactly
+    /// representable as `f32`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fpdec::{Dec, Decimal, RoundingMode};
+    /// let d = Dec!(-0.1);
+    /// let lo = d.to_f32_rounded(RoundingMode::RoundFloor);
+    /// let hi = d.to_f32_rounded(RoundingMode::RoundCeiling);
+    /// assert!(lo < d && d < hi);
+    /// assert_eq!(lo, f32::from(d));
+    /// assert_eq!(hi, -0.099999994);
+    /// ```
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn to_f32_rounded(self, mode: RoundingMode) -> f32 {
+        let (negative, signif, exp) = to_binary_rounded(self, 24, -149, mode);
+        // exactly representable as f32
+        compose_f64(negative, signif, exp) as f32
+    }
+}
+
+#[cfg(test)]
+mod tests_into_float_rounded {
+    use super::*;
+
+    const MODES: [RoundingMode; 8] = [
+        RoundingMode::RoundDown,
+        RoundingMode::RoundUp,
+        RoundingMode::RoundFloor,
+        RoundingMode::RoundCeiling,
+        RoundingMode::RoundHalfDown,
+        RoundingMode::RoundHalfUp,
+        RoundingMode::RoundHalfEven,
+        RoundingMode::Round05Up,
+    ];
+
+    #[test]
+    fn test_exact_values() {
+        for d in [
+            Decimal::ZERO,
+            Decimal::NEG_ONE,
+            Decimal::new_raw(-2890625, 4),
+            Decimal::new_raw(3814697265625, 18),
+            Decimal::new_raw(1 << 100, 0),
+        ] {
+            for mode in MODES {
+                assert_eq!(d.to_f64_rounded(mode), f64::from(d));
+                assert_eq!(d.to_f32_rounded(mode), f32::from(d));
+            }
+        }
+    }
+
+    #[test]
+    fn test_directed_rounding() {
+        let d = Decimal::new_raw(1, 1);
+        let lo = 0.09999999999999999_f64;
+        let hi = 0.1_f64;
+        assert_eq!(d.to_f64_rounded(RoundingMode::RoundDown), lo);
+        assert_eq!(d.to_f64_rounded(RoundingMode::RoundFloor), lo);
+        assert_eq!(d.to_f64_rounded(RoundingMode::RoundUp), hi);
+        assert_eq!(d.to_f64_rounded(RoundingMode::RoundCeiling), hi);
+        assert_eq!((-d).to_f64_rounded(RoundingMode::RoundDown), -lo);
+        assert_eq!((-d).to_f64_rounded(RoundingMode::RoundFloor), -hi);
+        assert_eq!((-d).to_f64_rounded(RoundingMode::RoundUp), -hi);
+        assert_eq!((-d).to_f64_rounded(RoundingMode::RoundCeiling), -lo);
+        let d = Decimal::new_raw(i128::MAX, 18);
+        let f = d.to_f32_rounded(RoundingMode::RoundFloor);
+        assert!(f < d);
+        assert_eq!(f, 170141173319264429905852091742258462720e-18_f32);
+        let f = d.to_f32_rounded(RoundingMode::RoundCeiling);
+        assert!(f > d);
+        assert_eq!(f, 170141183460469231731687303715884105728e-18_f32);
+    }
+
+    #[test]
+    fn test_ties() {
+        // 2 ^ 53 + 1 and 2 ^ 53 + 3 are ties between adjacent f64 values
+        let d = Decimal::new_raw(9007199254740993, 0);
+        let lo = 9007199254740992_f64;
+        let hi = 9007199254740994_f64;
+        assert_eq!(d.to_f64_rounded(RoundingMode::RoundHalfDown), lo);
+        assert_eq!(d.to_f64_rounded(RoundingMode::RoundHalfUp), hi);
+        assert_eq!(d.to_f64_rounded(RoundingMode::RoundHalfEven), lo);
+        let d = Decimal::new_raw(9007199254740995, 0);
+        let lo = 9007199254740994_f64;
+        let hi = 9007199254740996_f64;
+        assert_eq!(d.to_f64_rounded(RoundingMode::RoundHalfDown), lo);
+        assert_eq!(d.to_f64_rounded(RoundingMode::RoundHalfUp), hi);
+        assert_eq!(d.to_f64_rounded(RoundingMode::RoundHalfEven), hi);
+        // slightly above the tie
+        let d = Decimal::new_raw(90071992547409930000000000001, 13);
+        assert_eq!(
+            d.to_f64_rounded(RoundingMode::RoundHalfDown),
+            9007199254740994_f64
+        );
+        // 2 ^ 24 + 1 is a tie between adjacent f32 values
+        let d = Decimal::new_raw(-16777217, 0);
+        assert_eq!(d.to_f32_rounded(RoundingMode::RoundHalfUp), -16777218.0);
+        assert_eq!(
+            d.to_f32_rounded(RoundingMode::RoundHalfEven),
+            -16777216.0
+        );
+    }
+
+    // f64::next_up is not available in the minimal supported rust version
+    fn next_up(f: f64) -> f64 {
+        if f >= 0.0 {
+            f64::from_bits(f.to_bits() + 1)
+        } else {
+            f64::from_bits(f.to_bits() - 1)
+        }
+    }
+
+    #[test]
+    fn test_nearest_is_from() {
+        let mut x: i128 = 0x1234_5678_9abc_def0_1357_9bdf_2468_ace0;
+        for i in 0..1000_u32 {
+            x ^= x << 13;
+            x ^= x >> 7;
+            x ^= x << 17;
+            let d = Decimal::new_raw(x >> (i % 100), (i % 19) as u8);
+            let f = d.to_f64_rounded(RoundingMode::RoundHalfEven);
+            assert_eq!(f, f64::from(d));
+            let f = d.to_f32_rounded(RoundingMode::RoundHalfEven);
+            assert_eq!(f, f32::from(d));
+            let lo = d.to_f64_rounded(RoundingMode::RoundFloor);
+            let hi = d.to_f64_rounded(RoundingMode::RoundCeiling);
+            assert!(lo <= d && d <= hi);
+            assert!(lo == hi || next_up(lo) == hi);
+            let lo = d.to_f32_rounded(RoundingMode::RoundFloor);
+            let hi = d.to_f32_rounded(RoundingMode::RoundCeiling);
+            assert!(lo <= d && d <= hi);
+            assert!(lo == hi || f64::from(lo) < f64::from(hi));
+        }
+    }
+}
+
+#[cfg(feature = "half")]
+// Returns `signif * 2 ^ exp` converted by `from_f64`, or - if it exceeds
+// `max` - infinity or `max`, depending on `mode`.
+fn compose_half<T>(
+    (negative, signif, exp): (bool, u128, i32),
+    max: f64,
+    mode: RoundingMode,
+    from_f64: fn(f64) -> T,
+) -> T {
+    let f = compose_f64(negative, signif, exp);
+    if f.abs() <= max {
+        // exactly representable as T
+        return from_f64(f);
+    }
+    // Values beyond `max` are rounded to infinity, unless `mode` rounds
+    // towards zero.
+    let away = if negative {
+        i128_div_rounded(-3, 4, Some(mode)) != 0
+    } else {
+        i128_div_rounded(3, 4, Some(mode)) != 0
+    };
+    match (away, negative) {
+        (true, false) => from_f64(f64::INFINITY),
+        (true, true) => from_f64(f64::NEG_INFINITY),
+        (false, false) => from_f64(max),
+        (false, true) => from_f64(-max),
+    }
+}
+
+#[cfg(feature = "half")]
+impl Decimal {
+    /// Converts `self` into a `half::f16`, rounded according to `mode`.
+    ///
+    /// Values beyond the range of `f16` result in infinity, unless `mode`
+    /// rounds towards zero, in which case the largest finite `f16` value
+    /// (with the sign of `self`) is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fpdec::{Dec, Decimal, RoundingMode};
+    /// # use half::f16;
+    /// let d = Dec!(0.1);
+    /// let f = d.to_f16_rounded(RoundingMode::RoundFloor);
+    /// assert_eq!(f, f16::from_f64(0.0999755859375));
+    /// let d = Dec!(70000);
+    /// let f = d.to_f16_rounded(RoundingMode::RoundDown);
+    /// assert_eq!(f, f16::MAX);
+    /// let f = d.to_f16_rounded(RoundingMode::RoundHalfEven);
+    /// assert_eq!(f, f16::INFINITY);
+    /// ```
+    #[must_use]
+    pub fn to_f16_rounded(self, mode: RoundingMode) -> half::f16 {
+        compose_half(
+            to_binary_rounded(self, 11, -24, mode),
+            half::f16::MAX.to_f64(),
+            mode,
+            half::f16::from_f64,
+        )
+    }
+
+    /// Converts `self` into a `half::bf16`, rounded according to `mode`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fpdec::{Dec, Decimal, RoundingMode};
+    /// # use half::bf16;
+    /// let d = Dec!(0.1);
+    /// let f = d.to_bf16_rounded(RoundingMode::RoundCeiling);
+    /// assert_eq!(f, bf16::from_f64(0.10009765625));
+    /// ```
+    #[must_use]
+    pub fn to_bf16_rounded(self, mode: RoundingMode) -> half::bf16 {
+        compose_half(
+            to_binary_rounded(self, 8, -133, mode),
+            half::bf16::MAX.to_f64(),
+            mode,
+            half::bf16::from_f64,
+        )
+    }
+}
+
+#[cfg(feature = "half")]
+impl From<Decimal> for half::f16 {
+    /// Converts a `Decimal` value `d` into a `half::f16`.
+    ///
+    /// Returns the value as `f16`, rounded to the nearest value
+    /// representable as such, or infinity, if `d` is beyond the range of
+    /// `f16`.
+    #[inline(always)]
+    fn from(d: Decimal) -> Self {
+        d.to_f16_rounded(RoundingMode::RoundHalfEven)
+    }
+}
+
+#[cfg(feature = "half")]
+impl From<Decimal> for half::bf16 {
+    /// Converts a `Decimal` value `d` into a `half::bf16`.
+    ///
+    /// Returns the value as `bf16`, rounded to the nearest value
+    /// representable as such.
+    #[inline(always)]
+    fn from(d: Decimal) -> Self {
+        d.to_bf16_rounded(RoundingMode::RoundHalfEven)
+    }
+}
+
+#[cfg(feature = "half")]
+#[cfg(test)]
+mod tests_into_half {
+    use half::{bf16, f16};
+
+    use super::*;
+
+    #[test]
+    fn test_f16_nearest() {
+        let test_data = [
+            (Decimal::ZERO, f16::ZERO),
+            (Decimal::NEG_ONE, f16::NEG_ONE),
+            (Decimal::new_raw(1, 1), f16::from_f64(0.0999755859375)),
+            (Decimal::new_raw(65504, 0), f16::MAX),
+            (Decimal::new_raw(-65519, 0), f16::MIN),
+            (Decimal::new_raw(65520, 0), f16::INFINITY),
+            (Decimal::new_raw(-1, 4), f16::from_f64(-0.0001000165939331)),
+            // smallest subnormal value is 2 ^ -24 = 0.000000059604644775...
+            (
+                Decimal::new_raw(59604644775, 18),
+                f16::MIN_POSITIVE_SUBNORMAL,
+            ),
+            (
+                Decimal::new_raw(29802322388, 18),
+                f16::MIN_POSITIVE_SUBNORMAL,
+            ),
+            (Decimal::new_raw(29802322387, 18), f16::ZERO),
+            (Decimal::new_raw(-1, 18), f16::NEG_ZERO),
+        ];
+        for (d, f) in test_data {
+            assert_eq!(f16::from(d).to_bits(), f.to_bits(), "{d}");
+        }
+    }
+
+    #[test]
+    fn test_f16_directed() {
+        let d = Decimal::new_raw(1, 18);
+        assert_eq!(d.to_f16_rounded(RoundingMode::RoundDown), f16::ZERO);
+        assert_eq!(
+            d.to_f16_rounded(RoundingMode::RoundUp),
+            f16::MIN_POSITIVE_SUBNORMAL
+        );
+        assert_eq!(
+            (-d).to_f16_rounded(RoundingMode::RoundFloor),
+            -f16::MIN_POSITIVE_SUBNORMAL
+        );
+        let d = Decimal::new_raw(65505, 0);
+        assert_eq!(d.to_f16_rounded(RoundingMode::RoundFloor), f16::MAX);
+        assert_eq!(
+            d.to_f16_rounded(RoundingMode::RoundCeiling),
+            f16::INFINITY
+        );
+        assert_eq!(d.to_f16_rounded(RoundingMode::RoundHalfUp), f16::MAX);
+        assert_eq!((-d).to_f16_rounded(RoundingMode::RoundCeiling), f16::MIN);
+        assert_eq!(
+            (-d).to_f16_rounded(RoundingMode::RoundUp),
+            f16::NEG_INFINITY
+        );
+        let d = Decimal::new_raw(-1, 1);
+        assert_eq!(
+            d.to_f16_rounded(RoundingMode::RoundCeiling),
+            f16::from_f64(-0.0999755859375)
+        );
+        assert_eq!(
+            d.to_f16_rounded(RoundingMode::RoundFloor),
+            f16::from_f64(-0.10003662109375)
+        );
+    }
+
+    #[test]
+    fn test_bf16() {
+        let test_data = [
+            (Decimal::ZERO, bf16::ZERO),
+            (Decimal::new_raw(1, 1), bf16::from_f64(0.10009765625)),
+            (Decimal::new_raw(-257, 0), bf16::from_f64(-256.0)),
+            (Decimal::new_raw(259, 0), bf16::from_f64(260.0)),
+            (Decimal::MAX, bf16::from_f64(1.7014118346046923e38)),
+        ];
+        for (d, f) in test_data {
+            assert_eq!(bf16::from(d), f, "{d}");
+        }
+        let d = Decimal::new_raw(257, 0);
+        assert_eq!(
+            d.to_bf16_rounded(RoundingMode::RoundUp),
+            bf16::from_f64(258.0)
+        );
+        assert_eq!(
+            d.to_bf16_rounded(RoundingMode::RoundHalfUp),
+            bf16::from_f64(258.0)
+        );
+        assert_eq!(
+            d.to_bf16_rounded(RoundingMode::RoundDown),
+            bf16::from_f64(256.0)
+        );
+    }
+}
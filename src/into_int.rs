@@ -7,9 +7,9 @@
 // $Source$
 // $Revision$
 
-use fpdec_core::ten_pow;
+use fpdec_core::{i128_div_rounded, ten_pow};
 
-use crate::{Decimal, TryFromDecimalError};
+use crate::{Decimal, RoundingMode, TryFromDecimalError};
 
 impl TryFrom<Decimal> for i128 {
     type Error = TryFromDecimalError;
@@ -24,6 +24,7 @@ impl TryFrom<Decimal> for i128 {
     ///   `TryFromDecimalError::NotAnIntValue`,
     /// * `d` exceeds the range of `ì128` values =>
     ///   `TryFromDecimalError::ValueOutOfRange`.
+    #[allow(clippy::integer_division)]
     fn try_from(d: Decimal) -> Result<Self, Self::Error> {
         if d.n_frac_digits == 0 || d.coeff == 0 {
             Ok(d.coeff)
@@ -177,3 +178,173 @@ mod tests_into_int {
         assert_eq!(t, 12_u8);
     }
 }
+
+/// Returns `d` rounded to an integral value according to `mode`.
+#[inline]
+fn round_to_i128(d: Decimal, mode: RoundingMode) -> i128 {
+    if d.n_frac_digits == 0 {
+        d.coeff
+    } else {
+        i128_div_rounded(d.coeff, ten_pow(d.n_frac_digits), Some(mode))
+    }
+}
+
+macro_rules! impl_int_from_dec_rounded {
+    () => {
+        impl_int_from_dec_rounded!(
+            u8, to_u8_rounded, to_u8_truncated, to_u8_saturating;
+            i8, to_i8_rounded, to_i8_truncated, to_i8_saturating;
+            u16, to_u16_rounded, to_u16_truncated, to_u16_saturating;
+            i16, to_i16_rounded, to_i16_truncated, to_i16_saturating;
+            u32, to_u32_rounded, to_u32_truncated, to_u32_saturating;
+            i32, to_i32_rounded, to_i32_truncated, to_i32_saturating;
+            u64, to_u64_rounded, to_u64_truncated, to_u64_saturating;
+            i64, to_i64_rounded, to_i64_truncated, to_i64_saturating;
+            u128, to_u128_rounded, to_u128_truncated, to_u128_saturating;
+            i128, to_i128_rounded, to_i128_truncated, to_i128_saturating
+        );
+    };
+    ($($t:ty, $rounded:ident, $truncated:ident, $saturating:ident);*) => {
+        impl Decimal {
+            $(
+            #[doc="Converts `self` into an `"]
+            #[doc=stringify!($t)]
+            #[doc="`, after rounding it to an integral value according to \
+            `mode`.\n\n# Errors\n\nReturns \
+            `TryFromDecimalError::ValueOutOfRange` (wrapped in \
+            Result::Err), if the rounded value exceeds the range of `"]
+            #[doc=stringify!($t)]
+            #[doc="` values."]
+            pub fn $rounded(
+                self,
+                mode: RoundingMode,
+            ) -> Result<$t, TryFromDecimalError> {
+                <$t>::try_from(round_to_i128(self, mode))
+                    .map_err(|_| TryFromDecimalError::ValueOutOfRange)
+            }
+
+            #[doc="Converts `self` into an `"]
+            #[doc=stringify!($t)]
+            #[doc="`, after truncating its fractional part.\n\n# Errors\n\n\
+            Returns `TryFromDecimalError::ValueOutOfRange` (wrapped in \
+            Result::Err), if the truncated value exceeds the range of `"]
+            #[doc=stringify!($t)]
+            #[doc="` values."]
+            #[inline]
+            pub fn $truncated(self) -> Result<$t, TryFromDecimalError> {
+                self.$rounded(RoundingMode::RoundDown)
+            }
+
+            #[doc="Converts `self` into an `"]
+            #[doc=stringify!($t)]
+            #[doc="`, after truncating its fractional part.\n\nReturns `"]
+            #[doc=stringify!($t)]
+            #[doc="::MIN` or `"]
+            #[doc=stringify!($t)]
+            #[doc="::MAX`, if the truncated value exceeds the range of `"]
+            #[doc=stringify!($t)]
+            #[doc="` values."]
+            #[must_use]
+            pub fn $saturating(self) -> $t {
+                let i = round_to_i128(self, RoundingMode::RoundDown);
+                <$t>::try_from(i).unwrap_or(if i < 0 {
+                    <$t>::MIN
+                } else {
+                    <$t>::MAX
+                })
+            }
+            )*
+        }
+    }
+}
+
+impl_int_from_dec_rounded!();
+
+#[cfg(test)]
+mod tests_into_int_rounded {
+    use super::*;
+
+    #[test]
+    fn test_rounded() {
+        let d = Decimal::new_raw(-2545, 2);
+        assert_eq!(d.to_i8_rounded(RoundingMode::RoundHalfEven), Ok(-25));
+        assert_eq!(d.to_i16_rounded(RoundingMode::RoundHalfUp), Ok(-25));
+        assert_eq!(d.to_i32_rounded(RoundingMode::RoundFloor), Ok(-26));
+        assert_eq!(d.to_i64_rounded(RoundingMode::RoundCeiling), Ok(-25));
+        assert_eq!(d.to_i128_rounded(RoundingMode::RoundUp), Ok(-26));
+        assert_eq!(
+            d.to_u8_rounded(RoundingMode::RoundDown),
+            Err(TryFromDecimalError::ValueOutOfRange)
+        );
+        let d = Decimal::new_raw(2550, 2);
+        assert_eq!(d.to_u8_rounded(RoundingMode::RoundHalfEven), Ok(26));
+        assert_eq!(d.to_u16_rounded(RoundingMode::RoundHalfDown), Ok(25));
+        assert_eq!(d.to_u32_rounded(RoundingMode::Round05Up), Ok(26));
+        assert_eq!(d.to_u64_rounded(RoundingMode::RoundDown), Ok(25));
+        assert_eq!(d.to_u128_rounded(RoundingMode::RoundUp), Ok(26));
+        // only a fractional part
+        let d = Decimal::new_raw(-4, 1);
+        assert_eq!(d.to_u8_rounded(RoundingMode::RoundHalfEven), Ok(0));
+        assert_eq!(
+            d.to_u8_rounded(RoundingMode::RoundFloor),
+            Err(TryFromDecimalError::ValueOutOfRange)
+        );
+    }
+
+    #[test]
+    fn test_rounded_out_of_range() {
+        let d = Decimal::new_raw(2555, 1);
+        assert_eq!(d.to_u8_rounded(RoundingMode::RoundDown), Ok(255));
+        assert_eq!(
+            d.to_u8_rounded(RoundingMode::RoundHalfEven),
+            Err(TryFromDecimalError::ValueOutOfRange)
+        );
+        let d = Decimal::new_raw(-1285, 1);
+        assert_eq!(d.to_i8_rounded(RoundingMode::RoundHalfEven), Ok(-128));
+        assert_eq!(
+            d.to_i8_rounded(RoundingMode::RoundHalfUp),
+            Err(TryFromDecimalError::ValueOutOfRange)
+        );
+        assert_eq!(
+            Decimal::MAX.to_i128_rounded(RoundingMode::RoundUp),
+            Ok(i128::MAX)
+        );
+        assert_eq!(
+            Decimal::MIN.to_u128_rounded(RoundingMode::RoundUp),
+            Err(TryFromDecimalError::ValueOutOfRange)
+        );
+    }
+
+    #[test]
+    fn test_truncated() {
+        let d = Decimal::new_raw(-12345, 3);
+        assert_eq!(d.to_i8_truncated(), Ok(-12));
+        assert_eq!(d.to_i64_truncated(), Ok(-12));
+        assert_eq!(
+            d.to_u16_truncated(),
+            Err(TryFromDecimalError::ValueOutOfRange)
+        );
+        assert_eq!(Decimal::new_raw(-9, 1).to_u16_truncated(), Ok(0));
+        // 170141183460469231731.687303715884105727
+        let d = Decimal::new_raw(i128::MAX, 18);
+        assert_eq!(d.to_u128_truncated(), Ok(170141183460469231731));
+        assert_eq!(
+            d.to_u64_truncated(),
+            Err(TryFromDecimalError::ValueOutOfRange)
+        );
+    }
+
+    #[test]
+    fn test_saturating() {
+        let d = Decimal::new_raw(-12345, 3);
+        assert_eq!(d.to_i8_saturating(), -12);
+        assert_eq!(d.to_u32_saturating(), 0);
+        assert_eq!(Decimal::new_raw(25699, 2).to_u8_saturating(), 255);
+        assert_eq!(Decimal::new_raw(-25699, 2).to_i8_saturating(), -128);
+        assert_eq!(Decimal::new_raw(12799, 2).to_i8_saturating(), 127);
+        assert_eq!(Decimal::MAX.to_u64_saturating(), u64::MAX);
+        assert_eq!(Decimal::MIN.to_i64_saturating(), i64::MIN);
+        assert_eq!(Decimal::MIN.to_i128_saturating(), -i128::MAX);
+        assert_eq!(Decimal::MAX.to_u128_saturating(), i128::MAX as u128);
+    }
+}
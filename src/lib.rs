@@ -42,7 +42,6 @@
 #![warn(clippy::print_stdout)]
 #![warn(clippy::semicolon_if_nothing_returned)]
 #![warn(clippy::str_to_string)]
-#![warn(clippy::string_to_string)]
 #![warn(clippy::undocumented_unsafe_blocks)]
 #![warn(clippy::unicode_not_nfc)]
 #![warn(clippy::unimplemented)]
@@ -52,7 +51,22 @@
 #![warn(clippy::use_self)]
 #![warn(clippy::used_underscore_binding)]
 #![warn(clippy::wildcard_imports)]
+// relax some lints in test code
+#![cfg_attr(
+    test,
+    allow(
+        clippy::cast_precision_loss,
+        clippy::cognitive_complexity,
+        clippy::decimal_literal_representation,
+        clippy::excessive_precision,
+        clippy::integer_division,
+        clippy::op_ref,
+        clippy::unseparated_literal_suffix,
+        clippy::useless_conversion
+    )
+)]
 
+#[cfg(any(feature = "alloc", test))]
 extern crate alloc;
 
 #[cfg(feature = "serde-as-str")]
@@ -69,7 +83,20 @@ pub use binops::{
     mul_rounded::MulRounded,
 };
 #[doc(inline)]
+pub use compact::CompactNotation;
+#[doc(inline)]
+pub use decimal_format::{DecimalFormat, Grouping, SignPlacement};
+#[cfg(feature = "alloc")]
+#[doc(inline)]
+pub use decimal_parser::{DecimalParser, ParseOptions};
+#[doc(inline)]
+pub use decimal_ratio::DecimalRatio;
 pub use errors::*;
+#[cfg(feature = "money")]
+#[doc(inline)]
+pub use exchange_rate::ExchangeRate;
+#[doc(inline)]
+pub use format::{DisplayOptions, EngineeringNotation, SigDigitsNotation};
 use fpdec_core::i128_magnitude;
 #[doc(inline)]
 pub use fpdec_core::{
@@ -77,22 +104,54 @@ pub use fpdec_core::{
 };
 #[doc(inline)]
 pub use fpdec_macros::Dec;
+#[cfg(feature = "alloc")]
+#[doc(inline)]
+pub use implied_field::{FieldPadding, FieldSign};
+#[cfg(feature = "money")]
+#[doc(inline)]
+pub use money::{Currency, Money};
 #[doc(inline)]
 pub use quantize::Quantize;
+#[cfg(feature = "alloc")]
+#[doc(inline)]
+pub use to_words::{Language, WordsStyle};
 
 mod as_integer_ratio;
+#[cfg(feature = "bigdecimal")]
+mod bigdecimal;
 mod binops;
+#[cfg(feature = "alloc")]
+mod cobol;
+mod compact;
+mod decimal_format;
+#[cfg(feature = "alloc")]
+mod decimal_parser;
+mod decimal_ratio;
+mod duration;
 mod errors;
+#[cfg(feature = "money")]
+mod exchange_rate;
 mod format;
+mod fraction;
 mod from_float;
 mod from_int;
+mod from_ratio;
 mod from_str;
+mod ieee754;
+#[cfg(feature = "alloc")]
+mod implied_field;
 mod into_float;
 mod into_int;
+#[cfg(feature = "money")]
+mod money;
 #[cfg(feature = "num-traits")]
 mod num_traits;
 mod quantize;
 mod round;
+#[cfg(feature = "rust_decimal")]
+mod rust_decimal;
+#[cfg(feature = "alloc")]
+mod to_words;
 mod unops;
 
 /// Represents a decimal number as a coefficient (`i128`) combined with a
@@ -121,7 +180,7 @@ mod unops;
 )]
 #[cfg_attr(
     all(feature = "bincode", not(feature = "packed")),
-    derive(bincode::Encode, bincode::Decode),
+    derive(bincode::Encode, bincode::Decode)
 )]
 #[cfg_attr(feature = "packed", repr(packed))]
 pub struct Decimal {
@@ -431,6 +490,8 @@ impl<C: ?Sized> rkyv::CheckBytes<C> for ArchivedDecimal {
 #[cfg(feature = "rkyv")]
 #[cfg(test)]
 mod rkyv_tests {
+    use alloc::format;
+
     use rkyv::{self, Deserialize};
 
     use super::*;
@@ -493,4 +554,14 @@ mod rkyv_tests {
         let u = roundtrip(d);
         assert_eq!(d, u);
     }
+
+    #[test]
+    fn test_fmt_exp() {
+        let d = Dec!(-1234.500);
+        let bytes = rkyv::to_bytes::<_, 256>(&d).unwrap();
+        let archived =
+            rkyv::check_archived_root::<Decimal>(&bytes[..]).unwrap();
+        assert_eq!(format!("{:e}", archived), "-1.2345e3");
+        assert_eq!(format!("{:.2E}", archived), "-1.23E3");
+    }
 }
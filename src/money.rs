@@ -0,0 +1,1045 @@
+// ---------------------------------------------------------------------------
+// Copyright:   (c) 2026 ff. Michael Amrhein (michael@adrhinum.de)
+// License:     This program is part of a larger application. For license
+//              details please read the file LICENSE.TXT provided together
+//              with the application.
+// ---------------------------------------------------------------------------
+// $Source$
+// $Revision$
+
+use alloc::string::String;
+use core::{
+    cmp::Ordering,
+    fmt,
+    ops::{Add, Mul, Neg, Sub},
+    str::FromStr,
+};
+
+use crate::{
+    binops::div_rounded::checked_div_rounded,
+    format::{buf_as_str, write_abs_to_buf, MAX_STR_LEN},
+    CheckedAdd, CheckedSub, Decimal, DecimalError, DecimalParser, DivRounded,
+    MoneyError, Quantize, RoundingMode,
+};
+
+/// Currency as defined by ISO 4217, together with the smallest amount used
+/// in cash transactions and a common symbol.
+///
+/// All currencies listed in ISO 4217 as being in use - excluding precious
+/// metals, test and special codes without minor units - are available as
+/// associated constants, e.g. [`Currency::EUR`].
+///
+/// # Examples
+///
+/// ```rust
+/// # use fpdec::{Currency, Dec, Decimal};
+/// let chf = Currency::from_code("CHF").unwrap();
+/// assert_eq!(chf, Currency::CHF);
+/// assert_eq!(chf.numeric_code(), 756);
+/// assert_eq!(chf.minor_units(), 2);
+/// assert_eq!(chf.cash_increment(), Dec!(0.05));
+/// assert_eq!(Currency::from_numeric_code(392), Some(Currency::JPY));
+/// ```
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Currency {
+    code: &'static str,
+    numeric_code: u16,
+    minor_units: u8,
+    cash_increment: u16,
+    symbol: &'static str,
+    name: &'static str,
+}
+
+impl Currency {
+    const fn new(
+        code: &'static str,
+        numeric_code: u16,
+        minor_units: u8,
+        cash_increment: u16,
+        symbol: &'static str,
+        name: &'static str,
+    ) -> Self {
+        Self {
+            code,
+            numeric_code,
+            minor_units,
+            cash_increment,
+            symbol,
+            name,
+        }
+    }
+
+    /// Alphabetic code of `self`.
+    #[must_use]
+    #[inline(always)]
+    pub const fn code(&self) -> &'static str {
+        self.code
+    }
+
+    /// Numeric code of `self`.
+    #[must_use]
+    #[inline(always)]
+    pub const fn numeric_code(&self) -> u16 {
+        self.numeric_code
+    }
+
+    /// Number of fractional digits of the minor unit of `self`.
+    #[must_use]
+    #[inline(always)]
+    pub const fn minor_units(&self) -> u8 {
+        self.minor_units
+    }
+
+    /// Smallest amount of `self` used in cash transactions.
+    #[inline(always)]
+    pub const fn cash_increment(&self) -> Decimal {
+        Decimal::new_raw(self.cash_increment as i128, self.minor_units)
+    }
+
+    /// Common symbol of `self` (the alphabetic code, if there is no
+    /// specific symbol).
+    #[must_use]
+    #[inline(always)]
+    pub const fn symbol(&self) -> &'static str {
+        self.symbol
+    }
+
+    /// Name of `self` as given in ISO 4217.
+    #[must_use]
+    #[inline(always)]
+    pub const fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// Returns all known currencies, ordered by their alphabetic code.
+    #[must_use]
+    pub const fn all() -> &'static [Self] {
+        CURRENCIES
+    }
+
+    /// Returns the currency with the alphabetic code `code`, if any.
+    #[must_use]
+    pub fn from_code(code: &str) -> Option<Self> {
+        CURRENCIES
+            .binary_search_by(|c| c.code.cmp(code))
+            .ok()
+            .map(|idx| CURRENCIES[idx])
+    }
+
+    /// Returns the currency with the numeric code `numeric_code`, if any.
+    #[must_use]
+    pub fn from_numeric_code(numeric_code: u16) -> Option<Self> {
+        CURRENCIES
+            .iter()
+            .find(|c| c.numeric_code == numeric_code)
+            .copied()
+    }
+
+    /// Unit of the minor currency, i.e. 10 ^ -`minor_units`.
+    #[inline(always)]
+    const fn minor_unit(&self) -> Decimal {
+        Decimal::new_raw(1, self.minor_units)
+    }
+}
+
+impl fmt::Display for Currency {
+    fn fmt(&self, form: &mut fmt::Formatter<'_>) -> fmt::Result {
+        form.pad(self.code)
+    }
+}
+
+impl FromStr for Currency {
+    type Err = MoneyError;
+
+    /// Returns the currency with the alphabetic code `s`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_code(s).ok_or(MoneyError::UnknownCurrency)
+    }
+}
+
+macro_rules! currencies {
+    ($(($code:ident, $num:literal, $minor:literal, $cash:literal,
+        $sym:literal, $name:literal)),* $(,)?) => {
+        impl Currency {
+            $(
+                #[doc = concat!($name, " (", stringify!($code), ")")]
+                pub const $code: Self = Self::new(
+                    stringify!($code), $num, $minor, $cash, $sym, $name
+                );
+            )*
+        }
+
+        // ordered by alphabetic code (needed for binary search)
+        const CURRENCIES: &[Currency] = &[$(Currency::$code),*];
+    };
+}
+
+// (code, numeric code, minor units, cash increment in minor units, symbol,
+//  name)
+currencies!(
+    (AED, 784, 2, 1, "AED", "UAE Dirham"),
+    (AFN, 971, 2, 1, "AFN", "Afghani"),
+    (ALL, 8, 2, 1, "ALL", "Lek"),
+    (AMD, 51, 2, 1, "AMD", "Armenian Dram"),
+    (AOA, 973, 2, 1, "AOA", "Kwanza"),
+    (ARS, 32, 2, 1, "ARS", "Argentine Peso"),
+    (AUD, 36, 2, 5, "A$", "Australian Dollar"),
+    (AWG, 533, 2, 1, "AWG", "Aruban Florin"),
+    (AZN, 944, 2, 1, "₼", "Azerbaijan Manat"),
+    (BAM, 977, 2, 1, "BAM", "Convertible Mark"),
+    (BBD, 52, 2, 1, "BBD", "Barbados Dollar"),
+    (BDT, 50, 2, 1, "৳", "Taka"),
+    (BHD, 48, 3, 1, "BHD", "Bahraini Dinar"),
+    (BIF, 108, 0, 1, "BIF", "Burundi Franc"),
+    (BMD, 60, 2, 1, "BMD", "Bermudian Dollar"),
+    (BND, 96, 2, 1, "BND", "Brunei Dollar"),
+    (BOB, 68, 2, 1, "BOB", "Boliviano"),
+    (BOV, 984, 2, 1, "BOV", "Mvdol"),
+    (BRL, 986, 2, 1, "R$", "Brazilian Real"),
+    (BSD, 44, 2, 1, "BSD", "Bahamian Dollar"),
+    (BTN, 64, 2, 1, "BTN", "Ngultrum"),
+    (BWP, 72, 2, 1, "BWP", "Pula"),
+    (BYN, 933, 2, 1, "BYN", "Belarusian Ruble"),
+    (BZD, 84, 2, 1, "BZD", "Belize Dollar"),
+    (CAD, 124, 2, 5, "CA$", "Canadian Dollar"),
+    (CDF, 976, 2, 1, "CDF", "Congolese Franc"),
+    (CHE, 947, 2, 1, "CHE", "WIR Euro"),
+    (CHF, 756, 2, 5, "CHF", "Swiss Franc"),
+    (CHW, 948, 2, 1, "CHW", "WIR Franc"),
+    (CLF, 990, 4, 1, "CLF", "Unidad de Fomento"),
+    (CLP, 152, 0, 1, "CLP", "Chilean Peso"),
+    (CNY, 156, 2, 1, "CN¥", "Yuan Renminbi"),
+    (COP, 170, 2, 1, "COP", "Colombian Peso"),
+    (COU, 970, 2, 1, "COU", "Unidad de Valor Real"),
+    (CRC, 188, 2, 1, "₡", "Costa Rican Colon"),
+    (CUP, 192, 2, 1, "CUP", "Cuban Peso"),
+    (CVE, 132, 2, 1, "CVE", "Cabo Verde Escudo"),
+    (CZK, 203, 2, 100, "CZK", "Czech Koruna"),
+    (DJF, 262, 0, 1, "DJF", "Djibouti Franc"),
+    (DKK, 208, 2, 50, "DKK", "Danish Krone"),
+    (DOP, 214, 2, 1, "DOP", "Dominican Peso"),
+    (DZD, 12, 2, 1, "DZD", "Algerian Dinar"),
+    (EGP, 818, 2, 1, "EGP", "Egyptian Pound"),
+    (ERN, 232, 2, 1, "ERN", "Nakfa"),
+    (ETB, 230, 2, 1, "ETB", "Ethiopian Birr"),
+    (EUR, 978, 2, 1, "€", "Euro"),
+    (FJD, 242, 2, 1, "FJD", "Fiji Dollar"),
+    (FKP, 238, 2, 1, "FKP", "Falkland Islands Pound"),
+    (GBP, 826, 2, 1, "£", "Pound Sterling"),
+    (GEL, 981, 2, 1, "₾", "Lari"),
+    (GHS, 936, 2, 1, "GH₵", "Ghana Cedi"),
+    (GIP, 292, 2, 1, "GIP", "Gibraltar Pound"),
+    (GMD, 270, 2, 1, "GMD", "Dalasi"),
+    (GNF, 324, 0, 1, "GNF", "Guinean Franc"),
+    (GTQ, 320, 2, 1, "GTQ", "Quetzal"),
+    (GYD, 328, 2, 1, "GYD", "Guyana Dollar"),
+    (HKD, 344, 2, 1, "HK$", "Hong Kong Dollar"),
+    (HNL, 340, 2, 1, "HNL", "Lempira"),
+    (HTG, 332, 2, 1, "HTG", "Gourde"),
+    (HUF, 348, 2, 500, "HUF", "Forint"),
+    (IDR, 360, 2, 1, "IDR", "Rupiah"),
+    (ILS, 376, 2, 1, "₪", "New Israeli Sheqel"),
+    (INR, 356, 2, 1, "₹", "Indian Rupee"),
+    (IQD, 368, 3, 1, "IQD", "Iraqi Dinar"),
+    (IRR, 364, 2, 1, "IRR", "Iranian Rial"),
+    (ISK, 352, 0, 1, "ISK", "Iceland Krona"),
+    (JMD, 388, 2, 1, "JMD", "Jamaican Dollar"),
+    (JOD, 400, 3, 1, "JOD", "Jordanian Dinar"),
+    (JPY, 392, 0, 1, "¥", "Yen"),
+    (KES, 404, 2, 1, "KES", "Kenyan Shilling"),
+    (KGS, 417, 2, 1, "KGS", "Som"),
+    (KHR, 116, 2, 1, "៛", "Riel"),
+    (KMF, 174, 0, 1, "KMF", "Comorian Franc"),
+    (KPW, 408, 2, 1, "KPW", "North Korean Won"),
+    (KRW, 410, 0, 1, "₩", "Won"),
+    (KWD, 414, 3, 1, "KWD", "Kuwaiti Dinar"),
+    (KYD, 136, 2, 1, "KYD", "Cayman Islands Dollar"),
+    (KZT, 398, 2, 1, "₸", "Tenge"),
+    (LAK, 418, 2, 1, "₭", "Lao Kip"),
+    (LBP, 422, 2, 1, "LBP", "Lebanese Pound"),
+    (LKR, 144, 2, 1, "LKR", "Sri Lanka Rupee"),
+    (LRD, 430, 2, 1, "LRD", "Liberian Dollar"),
+    (LSL, 426, 2, 1, "LSL", "Loti"),
+    (LYD, 434, 3, 1, "LYD", "Libyan Dinar"),
+    (MAD, 504, 2, 1, "MAD", "Moroccan Dirham"),
+    (MDL, 498, 2, 1, "MDL", "Moldovan Leu"),
+    (MGA, 969, 2, 1, "MGA", "Malagasy Ariary"),
+    (MKD, 807, 2, 1, "MKD", "Denar"),
+    (MMK, 104, 2, 1, "MMK", "Kyat"),
+    (MNT, 496, 2, 1, "₮", "Tugrik"),
+    (MOP, 446, 2, 1, "MOP", "Pataca"),
+    (MRU, 929, 2, 1, "MRU", "Ouguiya"),
+    (MUR, 480, 2, 1, "MUR", "Mauritius Rupee"),
+    (MVR, 462, 2, 1, "MVR", "Rufiyaa"),
+    (MWK, 454, 2, 1, "MWK", "Malawi Kwacha"),
+    (MXN, 484, 2, 1, "MX$", "Mexican Peso"),
+    (MXV, 979, 2, 1, "MXV", "Mexican Unidad de Inversion (UDI)"),
+    (MYR, 458, 2, 1, "MYR", "Malaysian Ringgit"),
+    (MZN, 943, 2, 1, "MZN", "Mozambique Metical"),
+    (NAD, 516, 2, 1, "NAD", "Namibia Dollar"),
+    (NGN, 566, 2, 1, "₦", "Naira"),
+    (NIO, 558, 2, 1, "NIO", "Cordoba Oro"),
+    (NOK, 578, 2, 100, "NOK", "Norwegian Krone"),
+    (NPR, 524, 2, 1, "NPR", "Nepalese Rupee"),
+    (NZD, 554, 2, 10, "NZ$", "New Zealand Dollar"),
+    (OMR, 512, 3, 1, "OMR", "Rial Omani"),
+    (PAB, 590, 2, 1, "PAB", "Balboa"),
+    (PEN, 604, 2, 1, "PEN", "Sol"),
+    (PGK, 598, 2, 1, "PGK", "Kina"),
+    (PHP, 608, 2, 1, "₱", "Philippine Peso"),
+    (PKR, 586, 2, 1, "PKR", "Pakistan Rupee"),
+    (PLN, 985, 2, 1, "PLN", "Zloty"),
+    (PYG, 600, 0, 1, "₲", "Guarani"),
+    (QAR, 634, 2, 1, "QAR", "Qatari Rial"),
+    (RON, 946, 2, 1, "RON", "Romanian Leu"),
+    (RSD, 941, 2, 1, "RSD", "Serbian Dinar"),
+    (RUB, 643, 2, 1, "₽", "Russian Ruble"),
+    (RWF, 646, 0, 1, "RWF", "Rwanda Franc"),
+    (SAR, 682, 2, 1, "SAR", "Saudi Riyal"),
+    (SBD, 90, 2, 1, "SBD", "Solomon Islands Dollar"),
+    (SCR, 690, 2, 1, "SCR", "Seychelles Rupee"),
+    (SDG, 938, 2, 1, "SDG", "Sudanese Pound"),
+    (SEK, 752, 2, 100, "SEK", "Swedish Krona"),
+    (SGD, 702, 2, 1, "SGD", "Singapore Dollar"),
+    (SHP, 654, 2, 1, "SHP", "Saint Helena Pound"),
+    (SLE, 925, 2, 1, "SLE", "Leone"),
+    (SOS, 706, 2, 1, "SOS", "Somali Shilling"),
+    (SRD, 968, 2, 1, "SRD", "Surinam Dollar"),
+    (SSP, 728, 2, 1, "SSP", "South Sudanese Pound"),
+    (STN, 930, 2, 1, "STN", "Dobra"),
+    (SVC, 222, 2, 1, "SVC", "El Salvador Colon"),
+    (SYP, 760, 2, 1, "SYP", "Syrian Pound"),
+    (SZL, 748, 2, 1, "SZL", "Lilangeni"),
+    (THB, 764, 2, 1, "฿", "Baht"),
+    (TJS, 972, 2, 1, "TJS", "Somoni"),
+    (TMT, 934, 2, 1, "TMT", "Turkmenistan New Manat"),
+    (TND, 788, 3, 1, "TND", "Tunisian Dinar"),
+    (TOP, 776, 2, 1, "TOP", "Pa'anga"),
+    (TRY, 949, 2, 1, "₺", "Turkish Lira"),
+    (TTD, 780, 2, 1, "TTD", "Trinidad and Tobago Dollar"),
+    (TWD, 901, 2, 1, "NT$", "New Taiwan Dollar"),
+    (TZS, 834, 2, 1, "TZS", "Tanzanian Shilling"),
+    (UAH, 980, 2, 1, "₴", "Hryvnia"),
+    (UGX, 800, 0, 1, "UGX", "Uganda Shilling"),
+    (USD, 840, 2, 1, "$", "US Dollar"),
+    (USN, 997, 2, 1, "USN", "US Dollar (Next day)"),
+    (
+        UYI,
+        940,
+        0,
+        1,
+        "UYI",
+        "Uruguay Peso en Unidades Indexadas (UI)"
+    ),
+    (UYU, 858, 2, 1, "UYU", "Peso Uruguayo"),
+    (UYW, 927, 4, 1, "UYW", "Unidad Previsional"),
+    (UZS, 860, 2, 1, "UZS", "Uzbekistan Sum"),
+    (VED, 926, 2, 1, "VED", "Bolívar Soberano"),
+    (VES, 928, 2, 1, "VES", "Bolívar Soberano"),
+    (VND, 704, 0, 1, "₫", "Dong"),
+    (VUV, 548, 0, 1, "VUV", "Vatu"),
+    (WST, 882, 2, 1, "WST", "Tala"),
+    (XAF, 950, 0, 1, "FCFA", "CFA Franc BEAC"),
+    (XCD, 951, 2, 1, "EC$", "East Caribbean Dollar"),
+    (XCG, 532, 2, 1, "XCG", "Caribbean Guilder"),
+    (XOF, 952, 0, 1, "F CFA", "CFA Franc BCEAO"),
+    (XPF, 953, 0, 1, "CFPF", "CFP Franc"),
+    (YER, 886, 2, 1, "YER", "Yemeni Rial"),
+    (ZAR, 710, 2, 1, "ZAR", "Rand"),
+    (ZMW, 967, 2, 1, "ZMW", "Zambian Kwacha"),
+    (ZWG, 924, 2, 1, "ZWG", "Zimbabwe Gold"),
+);
+
+/// Returns the integer multiple of `quant` nearest to `amount`, according to
+/// the given rounding mode.
+///
+/// This is `amount.quantize(quant)` with an explicit rounding mode instead of
+/// the current default one; `Quantize` and `DivRounded` don't take a rounding
+/// mode, so the underlying division is used directly.
+///
+/// Panics if the result can not be represented by a `Decimal`.
+fn quantize_rounded(
+    amount: Decimal,
+    quant: Decimal,
+    mode: RoundingMode,
+) -> Decimal {
+    match checked_div_rounded(
+        amount.coeff,
+        amount.n_frac_digits,
+        quant.coeff,
+        quant.n_frac_digits,
+        0,
+        Some(mode),
+    )
+    .and_then(|quot| quot.checked_mul(quant.coeff))
+    {
+        Some(coeff) => Decimal::new_raw(coeff, quant.n_frac_digits),
+        None => panic!("{}", DecimalError::InternalOverflow),
+    }
+}
+
+/// Amount of money in a specific [`Currency`].
+///
+/// Arithmetic operations between `Money` values are only defined for values
+/// in the same currency. The operators `+` and `-` panic when applied to
+/// values in different currencies, whereas `checked_add` and `checked_sub`
+/// return an error.
+///
+/// The amount is not implicitly rounded. Use `round`, `round_with_mode` or
+/// `round_cash` to round it to the minor unit or the cash increment of the
+/// currency.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fpdec::{Currency, Dec, Decimal, Money, MoneyError, RoundingMode};
+/// # fn main() -> Result<(), MoneyError> {
+/// let price: Money = "CHF 19.90".parse()?;
+/// let total = (price * Dec!(3) * Dec!(0.925)).round();
+/// assert_eq!(total.to_string(), "CHF 55.22");
+/// let cash = total.round_cash(RoundingMode::RoundHalfUp);
+/// assert_eq!(cash.amount(), Dec!(55.20));
+/// let fee = Money::new(Dec!(2.5), Currency::EUR);
+/// assert_eq!(total.checked_add(fee), Err(MoneyError::CurrencyMismatch));
+/// # Ok(()) }
+/// ```
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Money {
+    amount: Decimal,
+    currency: Currency,
+}
+
+impl Money {
+    /// Returns a new `Money` value with the given amount and currency.
+    #[must_use]
+    #[inline(always)]
+    pub const fn new(amount: Decimal, currency: Currency) -> Self {
+        Self { amount, currency }
+    }
+
+    /// Returns a new `Money` value from a number of minor units of the given
+    /// currency.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fpdec::{Currency, Money};
+    /// let m = Money::from_minor_units(-12345, Currency::KWD);
+    /// assert_eq!(m.to_string(), "KWD -12.345");
+    /// ```
+    #[must_use]
+    pub const fn from_minor_units(units: i128, currency: Currency) -> Self {
+        Self::new(Decimal::new_raw(units, currency.minor_units), currency)
+    }
+
+    /// Amount of `self`.
+    #[inline(always)]
+    pub const fn amount(&self) -> Decimal {
+        self.amount
+    }
+
+    /// Currency of `self`.
+    #[must_use]
+    #[inline(always)]
+    pub const fn currency(&self) -> Currency {
+        self.currency
+    }
+
+    /// Returns a new `Money` value with the amount of `self` rounded to the
+    /// minor unit of its currency, according to the current
+    /// [RoundingMode](crate::RoundingMode).
+    ///
+    /// # Panics
+    ///
+    /// Panics if the resulting value can not be represented by `Decimal`!
+    #[must_use]
+    pub fn round(self) -> Self {
+        Self::new(
+            self.amount.quantize(self.currency.minor_unit()),
+            self.currency,
+        )
+    }
+
+    /// Returns a new `Money` value with the amount of `self` rounded to the
+    /// minor unit of its currency, according to the given rounding mode.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the resulting value can not be represented by `Decimal`!
+    #[must_use]
+    pub fn round_with_mode(self, mode: RoundingMode) -> Self {
+        Self::new(
+            quantize_rounded(self.amount, self.currency.minor_unit(), mode),
+            self.currency,
+        )
+    }
+
+    /// Returns a new `Money` value with the amount of `self` rounded to the
+    /// cash increment of its currency, according to the given rounding mode.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the resulting value can not be represented by `Decimal`!
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fpdec::{Currency, Dec, Decimal, Money, RoundingMode};
+    /// let m = Money::new(Dec!(7.4), Currency::SEK);
+    /// let r = m.round_cash(RoundingMode::RoundHalfUp);
+    /// assert_eq!(r.to_string(), "SEK 7.00");
+    /// ```
+    #[must_use]
+    pub fn round_cash(self, mode: RoundingMode) -> Self {
+        Self::new(
+            quantize_rounded(
+                self.amount,
+                self.currency.cash_increment(),
+                mode,
+            ),
+            self.currency,
+        )
+    }
+
+    /// Returns `self` + `other`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error (wrapped in Result::Err) in the following cases:
+    /// * the currencies of `self` and `other` differ =>
+    ///   `MoneyError::CurrencyMismatch`,
+    /// * the resulting amount can not be represented by `Decimal` =>
+    ///   `MoneyError::InternalOverflow`.
+    pub fn checked_add(self, other: Self) -> Result<Self, MoneyError> {
+        if self.currency != other.currency {
+            return Err(MoneyError::CurrencyMismatch);
+        }
+        self.amount
+            .checked_add(other.amount)
+            .map(|amount| Self::new(amount, self.currency))
+            .ok_or(MoneyError::InternalOverflow)
+    }
+
+    /// Returns `self` - `other`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error (wrapped in Result::Err) in the following cases:
+    /// * the currencies of `self` and `other` differ =>
+    ///   `MoneyError::CurrencyMismatch`,
+    /// * the resulting amount can not be represented by `Decimal` =>
+    ///   `MoneyError::InternalOverflow`.
+    pub fn checked_sub(self, other: Self) -> Result<Self, MoneyError> {
+        if self.currency != other.currency {
+            return Err(MoneyError::CurrencyMismatch);
+        }
+        self.amount
+            .checked_sub(other.amount)
+            .map(|amount| Self::new(amount, self.currency))
+            .ok_or(MoneyError::InternalOverflow)
+    }
+
+    /// Converts the literal `lit` into a `Money` value, using `parser` to
+    /// parse the amount.
+    ///
+    /// The literal must contain the alphabetic code or the symbol of a
+    /// currency, preceding or following the amount. If more than one
+    /// currency matches, the one with the longest code or symbol is
+    /// selected, e.g. "CA$" takes precedence over "$".
+    ///
+    /// # Errors
+    ///
+    /// Returns an error (wrapped in Result::Err) in the following cases:
+    /// * `lit` does not contain a known currency code or symbol =>
+    ///   `MoneyError::UnknownCurrency`,
+    /// * the remaining amount can not be parsed by `parser` =>
+    ///   `MoneyError::InvalidAmount`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fpdec::{
+    /// #     Currency, Dec, Decimal, DecimalParser, Money, MoneyError,
+    /// #     ParseOptions,
+    /// # };
+    /// # fn main() -> Result<(), MoneyError> {
+    /// let parser = DecimalParser::new(ParseOptions {
+    ///     decimal_separator: ',',
+    ///     grouping_separator: Some('.'),
+    ///     allow_parentheses: true,
+    ///     ..ParseOptions::default()
+    /// });
+    /// let m = Money::parse_with("(1.234,56 €)", &parser)?;
+    /// assert_eq!(m, Money::new(Dec!(-1234.56), Currency::EUR));
+    /// # Ok(()) }
+    /// ```
+    pub fn parse_with(
+        lit: &str,
+        parser: &DecimalParser,
+    ) -> Result<Self, MoneyError> {
+        let mut found: Option<(Currency, usize, usize)> = None;
+        for currency in CURRENCIES {
+            for token in [currency.code, currency.symbol] {
+                if found.map_or(true, |(_, _, len)| token.len() > len) {
+                    if let Some(idx) = lit.find(token) {
+                        found = Some((*currency, idx, token.len()));
+                    }
+                }
+            }
+        }
+        let (currency, idx, len) =
+            found.ok_or(MoneyError::UnknownCurrency)?;
+        let mut amount = String::with_capacity(lit.len() - len);
+        amount.push_str(&lit[..idx]);
+        amount.push_str(&lit[idx + len..]);
+        parser
+            .parse(&amount)
+            .map(|amount| Self::new(amount, currency))
+            .map_err(|_| MoneyError::InvalidAmount)
+    }
+}
+
+/// Writes `s` into `buf` in front of index `start` and returns the index of
+/// its first byte.
+fn prepend_to_buf(s: &str, buf: &mut [u8], start: usize) -> usize {
+    let new_start = start - s.len();
+    buf[new_start..start].copy_from_slice(s.as_bytes());
+    new_start
+}
+
+impl fmt::Display for Money {
+    /// Formats the value using the given formatter.
+    ///
+    /// The amount is preceded by the alphabetic code of the currency or - if
+    /// the alternate flag `#` is given - by its symbol. It is shown with at
+    /// least as many fractional digits as defined by the minor unit of the
+    /// currency, but is not rounded. The result is padded according to the
+    /// width, fill and alignment given by the formatter.
+    ///
+    /// # Examples:
+    ///
+    /// ```rust
+    /// # use fpdec::{Currency, Dec, Decimal, Money};
+    /// let m = Money::new(Dec!(-1234.5), Currency::EUR);
+    /// assert_eq!(format!("{}", m), "EUR -1234.50");
+    /// assert_eq!(format!("{:#}", m), "-€1234.50");
+    /// let m = Money::new(Dec!(0.125), Currency::USD);
+    /// assert_eq!(format!("{:#}", m), "$0.125");
+    /// assert_eq!(format!("{:>#8}", m), "  $0.125");
+    /// ```
+    fn fmt(&self, form: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // room for the amount, its sign and the longest currency symbol
+        let mut buf = [0_u8; MAX_STR_LEN + 8];
+        let prec = self.amount.n_frac_digits.max(self.currency.minor_units);
+        let mut start = write_abs_to_buf(
+            self.amount.coeff,
+            self.amount.n_frac_digits,
+            prec,
+            None,
+            &mut buf,
+        );
+        let sign = if self.amount.coeff < 0 { "-" } else { "" };
+        if form.alternate() {
+            start = prepend_to_buf(self.currency.symbol, &mut buf, start);
+            start = prepend_to_buf(sign, &mut buf, start);
+        } else {
+            start = prepend_to_buf(sign, &mut buf, start);
+            start = prepend_to_buf(" ", &mut buf, start);
+            start = prepend_to_buf(self.currency.code, &mut buf, start);
+        }
+        form.pad(buf_as_str(&buf, start))
+    }
+}
+
+impl FromStr for Money {
+    type Err = MoneyError;
+
+    /// Converts a literal like "EUR 1,234.56", "-$17.50" or "25 CHF" into a
+    /// `Money` value, using the default [`DecimalParser`] to parse the
+    /// amount.
+    ///
+    /// See [`Money::parse_with`] for details.
+    #[inline]
+    fn from_str(lit: &str) -> Result<Self, Self::Err> {
+        Self::parse_with(lit, &DecimalParser::default())
+    }
+}
+
+impl PartialOrd for Money {
+    /// Compares the amounts of `self` and `other`, if both are in the same
+    /// currency; otherwise returns `None`.
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        if self.currency == other.currency {
+            self.amount.partial_cmp(&other.amount)
+        } else {
+            None
+        }
+    }
+}
+
+impl Add for Money {
+    type Output = Self;
+
+    /// Returns `self` + `rhs`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the currencies of `self` and `rhs` differ or the resulting
+    /// amount can not be represented by `Decimal`!
+    fn add(self, rhs: Self) -> Self::Output {
+        match self.checked_add(rhs) {
+            Ok(res) => res,
+            Err(err) => panic!("{}", err),
+        }
+    }
+}
+
+impl Sub for Money {
+    type Output = Self;
+
+    /// Returns `self` - `rhs`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the currencies of `self` and `rhs` differ or the resulting
+    /// amount can not be represented by `Decimal`!
+    fn sub(self, rhs: Self) -> Self::Output {
+        match self.checked_sub(rhs) {
+            Ok(res) => res,
+            Err(err) => panic!("{}", err),
+        }
+    }
+}
+
+impl Neg for Money {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self::new(-self.amount, self.currency)
+    }
+}
+
+impl Mul<Decimal> for Money {
+    type Output = Self;
+
+    /// Returns `self` * `rhs`, without rounding the resulting amount.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the resulting amount can not be represented by `Decimal`!
+    fn mul(self, rhs: Decimal) -> Self::Output {
+        Self::new(self.amount * rhs, self.currency)
+    }
+}
+
+impl Mul<Money> for Decimal {
+    type Output = Money;
+
+    /// Returns `self` * `rhs`, without rounding the resulting amount.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the resulting amount can not be represented by `Decimal`!
+    fn mul(self, rhs: Money) -> Self::Output {
+        rhs * self
+    }
+}
+
+impl DivRounded<Decimal> for Money {
+    type Output = Self;
+
+    /// Returns `self` / `rhs`, with the amount rounded to `n_frac_digits`,
+    /// according to the current [RoundingMode](crate::RoundingMode).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rhs` equals zero or the resulting amount can not be
+    /// represented by `Decimal`!
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fpdec::{Currency, Dec, Decimal, DivRounded, Money};
+    /// let m = Money::new(Dec!(100), Currency::USD);
+    /// let n = m.currency().minor_units();
+    /// assert_eq!(m.div_rounded(Dec!(3), n).to_string(), "USD 33.33");
+    /// ```
+    fn div_rounded(self, rhs: Decimal, n_frac_digits: u8) -> Self::Output {
+        Self::new(self.amount.div_rounded(rhs, n_frac_digits), self.currency)
+    }
+}
+
+#[cfg(test)]
+mod money_tests {
+    use alloc::{format, string::ToString};
+
+    use super::*;
+    use crate::Dec;
+
+    #[test]
+    fn test_currency_table() {
+        assert!(CURRENCIES.windows(2).all(|w| w[0].code < w[1].code));
+        for (i, c) in CURRENCIES.iter().enumerate() {
+            assert_eq!(c.code.len(), 3);
+            assert!(c.code.bytes().all(|b| b.is_ascii_uppercase()));
+            assert!(c.numeric_code < 1000);
+            assert!(c.minor_units <= 4);
+            assert!(CURRENCIES[i + 1..]
+                .iter()
+                .all(|o| o.numeric_code != c.numeric_code));
+            assert_eq!(Currency::from_code(c.code), Some(*c));
+            assert_eq!(Currency::from_numeric_code(c.numeric_code), Some(*c));
+        }
+        assert_eq!(Currency::all().len(), CURRENCIES.len());
+    }
+
+    #[test]
+    fn test_currency_lookup() {
+        assert_eq!(Currency::from_code("EUR"), Some(Currency::EUR));
+        assert_eq!(Currency::from_code("eur"), None);
+        assert_eq!(Currency::from_code("XXX"), None);
+        assert_eq!(Currency::from_numeric_code(8), Some(Currency::ALL));
+        assert_eq!(Currency::from_numeric_code(999), None);
+        assert_eq!("JPY".parse::<Currency>(), Ok(Currency::JPY));
+        assert_eq!(
+            "DEM".parse::<Currency>(),
+            Err(MoneyError::UnknownCurrency)
+        );
+        assert_eq!(Currency::BHD.minor_units(), 3);
+        assert_eq!(Currency::CLF.minor_units(), 4);
+        assert_eq!(Currency::JPY.cash_increment(), Dec!(1));
+        assert_eq!(Currency::EUR.cash_increment(), Dec!(0.01));
+        assert_eq!(Currency::DKK.cash_increment(), Dec!(0.5));
+        assert_eq!(Currency::HUF.cash_increment(), Dec!(5));
+        assert_eq!(Currency::GBP.name(), "Pound Sterling");
+        assert_eq!(Currency::GBP.symbol(), "£");
+        assert_eq!(format!("{:>5}", Currency::GBP), "  GBP");
+    }
+
+    #[test]
+    fn test_round() {
+        let m = Money::new(Dec!(-10.125), Currency::EUR);
+        assert_eq!(m.round().amount(), Dec!(-10.12));
+        assert_eq!(
+            m.round_with_mode(RoundingMode::RoundHalfUp).amount(),
+            Dec!(-10.13)
+        );
+        assert_eq!(
+            m.round_with_mode(RoundingMode::RoundFloor).amount(),
+            Dec!(-10.13)
+        );
+        assert_eq!(
+            m.round_with_mode(RoundingMode::RoundCeiling).amount(),
+            Dec!(-10.12)
+        );
+        let m = Money::new(Dec!(1234.5), Currency::JPY);
+        assert_eq!(m.round().amount(), Dec!(1234));
+        assert_eq!(
+            m.round_with_mode(RoundingMode::RoundUp).amount(),
+            Dec!(1235)
+        );
+        let m = Money::new(Dec!(7), Currency::KWD);
+        assert_eq!(
+            m.round_with_mode(RoundingMode::RoundDown).to_string(),
+            "KWD 7.000"
+        );
+    }
+
+    #[test]
+    fn test_round_cash() {
+        for (amount, currency, mode, res) in [
+            (
+                Dec!(19.97),
+                Currency::CHF,
+                RoundingMode::RoundHalfUp,
+                Dec!(19.95),
+            ),
+            (
+                Dec!(19.975),
+                Currency::CHF,
+                RoundingMode::RoundHalfUp,
+                Dec!(20),
+            ),
+            (
+                Dec!(19.975),
+                Currency::CHF,
+                RoundingMode::RoundDown,
+                Dec!(19.95),
+            ),
+            (
+                Dec!(-0.03),
+                Currency::CAD,
+                RoundingMode::RoundHalfEven,
+                Dec!(-0.05),
+            ),
+            (
+                Dec!(12.25),
+                Currency::DKK,
+                RoundingMode::RoundHalfEven,
+                Dec!(12),
+            ),
+            (
+                Dec!(12.2501),
+                Currency::DKK,
+                RoundingMode::RoundHalfEven,
+                Dec!(12.5),
+            ),
+            (
+                Dec!(12.26),
+                Currency::DKK,
+                RoundingMode::RoundHalfEven,
+                Dec!(12.5),
+            ),
+            (
+                Dec!(1202.5),
+                Currency::HUF,
+                RoundingMode::RoundHalfUp,
+                Dec!(1205),
+            ),
+            (
+                Dec!(0.15),
+                Currency::NZD,
+                RoundingMode::RoundHalfEven,
+                Dec!(0.2),
+            ),
+            (
+                Dec!(3.499),
+                Currency::EUR,
+                RoundingMode::RoundHalfUp,
+                Dec!(3.50),
+            ),
+        ] {
+            let m = Money::new(amount, currency).round_cash(mode);
+            assert_eq!(m.amount(), res, "{amount} {currency} {mode:?}");
+            assert_eq!(m.amount().n_frac_digits(), currency.minor_units());
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_round_overflow() {
+        let m = Money::new(Decimal::MAX, Currency::EUR);
+        let _ = m.round_with_mode(RoundingMode::RoundUp);
+    }
+
+    #[test]
+    fn test_arithmetic() {
+        let a = Money::new(Dec!(10.50), Currency::USD);
+        let b = Money::new(Dec!(0.75), Currency::USD);
+        let c = Money::new(Dec!(0.75), Currency::EUR);
+        assert_eq!(a + b, Money::new(Dec!(11.25), Currency::USD));
+        assert_eq!(a - b, Money::new(Dec!(9.75), Currency::USD));
+        assert_eq!(-b, Money::new(Dec!(-0.75), Currency::USD));
+        assert_eq!(a * Dec!(1.5), Money::new(Dec!(15.75), Currency::USD));
+        assert_eq!(Dec!(2) * b, Money::new(Dec!(1.5), Currency::USD));
+        assert_eq!(a.checked_add(c), Err(MoneyError::CurrencyMismatch));
+        assert_eq!(a.checked_sub(c), Err(MoneyError::CurrencyMismatch));
+        assert_eq!(
+            Money::new(Decimal::MAX, Currency::USD).checked_add(b),
+            Err(MoneyError::InternalOverflow)
+        );
+        assert!(a > b);
+        assert_eq!(b.partial_cmp(&c), None);
+        assert_eq!(
+            a.div_rounded(Dec!(4), 2),
+            Money::new(Dec!(2.62), Currency::USD)
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_add_mixed_currencies() {
+        let _ = Money::new(Dec!(1), Currency::USD)
+            + Money::new(Dec!(1), Currency::EUR);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_sub_mixed_currencies() {
+        let _ = Money::new(Dec!(1), Currency::USD)
+            - Money::new(Dec!(1), Currency::EUR);
+    }
+
+    #[test]
+    fn test_display() {
+        for (m, s, a) in [
+            (Money::new(Dec!(5), Currency::EUR), "EUR 5.00", "€5.00"),
+            (Money::new(Dec!(-5.1), Currency::GBP), "GBP -5.10", "-£5.10"),
+            (
+                Money::new(Dec!(0.0045), Currency::USD),
+                "USD 0.0045",
+                "$0.0045",
+            ),
+            (Money::new(Dec!(1500), Currency::JPY), "JPY 1500", "¥1500"),
+            (Money::new(Dec!(2), Currency::BHD), "BHD 2.000", "BHD2.000"),
+            (
+                Money::from_minor_units(995, Currency::CHF),
+                "CHF 9.95",
+                "CHF9.95",
+            ),
+        ] {
+            assert_eq!(m.to_string(), s);
+            assert_eq!(format!("{m:#}"), a);
+        }
+    }
+
+    #[test]
+    fn test_display_padded() {
+        let m = Money::new(Dec!(-5.1), Currency::GBP);
+        assert_eq!(format!("{m:>12}"), "   GBP -5.10");
+        assert_eq!(format!("{m:*<12}"), "GBP -5.10***");
+        assert_eq!(format!("{m:^#9}"), " -£5.10  ");
+        assert_eq!(format!("{m:3}"), "GBP -5.10");
+        let m = Money::new(Dec!(1000), Currency::XOF);
+        assert_eq!(format!("{m:>#12}"), "   F CFA1000");
+    }
+
+    #[test]
+    fn test_from_str() {
+        for (lit, amount, currency) in [
+            ("EUR 1,234.56", Dec!(1234.56), Currency::EUR),
+            ("1,234.56 EUR", Dec!(1234.56), Currency::EUR),
+            ("EUR -1234.56", Dec!(-1234.56), Currency::EUR),
+            ("-€1234.56", Dec!(-1234.56), Currency::EUR),
+            ("€ 0.5", Dec!(0.5), Currency::EUR),
+            ("$17.50", Dec!(17.50), Currency::USD),
+            ("CA$17.50", Dec!(17.50), Currency::CAD),
+            ("17.50 NZ$", Dec!(17.50), Currency::NZD),
+            ("¥1500", Dec!(1500), Currency::JPY),
+            ("CN¥88", Dec!(88), Currency::CNY),
+            ("25 CHF", Dec!(25), Currency::CHF),
+            ("1000 F CFA", Dec!(1000), Currency::XOF),
+            ("1000 FCFA", Dec!(1000), Currency::XAF),
+        ] {
+            assert_eq!(
+                Money::from_str(lit),
+                Ok(Money::new(amount, currency)),
+                "{lit}"
+            );
+        }
+        for (lit, err) in [
+            ("", MoneyError::UnknownCurrency),
+            ("17.50", MoneyError::UnknownCurrency),
+            ("17.50 DEM", MoneyError::UnknownCurrency),
+            ("EUR", MoneyError::InvalidAmount),
+            ("EUR 17,5x", MoneyError::InvalidAmount),
+            ("EUR 5 USD", MoneyError::InvalidAmount),
+            ("EUR 1.0000000000000000001", MoneyError::InvalidAmount),
+        ] {
+            assert_eq!(Money::from_str(lit), Err(err), "{lit}");
+        }
+    }
+
+    #[test]
+    fn test_display_roundtrip() {
+        for m in [
+            Money::new(Dec!(-1234.5), Currency::EUR),
+            Money::new(Dec!(99.999), Currency::KWD),
+            Money::new(Dec!(7), Currency::KRW),
+            Money::new(Dec!(0.01), Currency::CAD),
+        ] {
+            assert_eq!(m.to_string().parse::<Money>(), Ok(m));
+            assert_eq!(format!("{m:#}").parse::<Money>(), Ok(m));
+        }
+    }
+}
@@ -7,11 +7,19 @@
 // $Source$
 // $Revision$
 
-use core::{ops::Add, str::FromStr};
+use core::{cmp::max, ops::Add, str::FromStr};
 
-use num_traits::{Num, One, Signed, Zero};
+use fpdec_core::{i128_div_rounded, i256_sum_of_prods_div_mod, ten_pow};
+use num_traits::{
+    Bounded, CheckedNeg, FromPrimitive, Inv, MulAdd, MulAddAssign, Num, One,
+    Pow, Saturating, SaturatingAdd, SaturatingMul, SaturatingSub, Signed,
+    ToPrimitive, Zero,
+};
 
-use crate::{Decimal, ParseDecimalError};
+use crate::{
+    binops::mul_rounded::checked_mul_rounded, Decimal, ParseDecimalError,
+    MAX_N_FRAC_DIGITS,
+};
 
 impl Zero for Decimal
 where
@@ -113,7 +121,7 @@ impl Signed for Decimal {
     /// otherwise the difference between `self` and `other` is returned.
     fn abs_sub(&self, other: &Self) -> Self {
         if self <= other {
-            Decimal::ZERO
+            Self::ZERO
         } else {
             self - other
         }
@@ -156,3 +164,708 @@ mod signed_tests {
         assert_eq!(y.abs_sub(&x), Decimal::ZERO);
     }
 }
+
+macro_rules! impl_num_checked_binop {
+    (impl $imp:ident, $method:ident) => {
+        impl num_traits::$imp for Decimal {
+            /// Maps to [crate::$imp].
+            #[inline(always)]
+            fn $method(&self, v: &Self) -> Option<Self> {
+                crate::$imp::$method(*self, *v)
+            }
+        }
+    };
+}
+
+impl_num_checked_binop!(impl CheckedAdd, checked_add);
+impl_num_checked_binop!(impl CheckedSub, checked_sub);
+impl_num_checked_binop!(impl CheckedMul, checked_mul);
+impl_num_checked_binop!(impl CheckedDiv, checked_div);
+impl_num_checked_binop!(impl CheckedRem, checked_rem);
+
+impl CheckedNeg for Decimal {
+    /// Returns `Some(-self)` or `None` if the coefficient of `self` equals
+    /// `i128::MIN`.
+    #[inline(always)]
+    fn checked_neg(&self) -> Option<Self> {
+        Some(Self {
+            coeff: self.coeff.checked_neg()?,
+            n_frac_digits: self.n_frac_digits,
+        })
+    }
+}
+
+#[cfg(test)]
+mod checked_ops_tests {
+    use num_traits::{
+        CheckedAdd, CheckedDiv, CheckedMul, CheckedNeg, CheckedRem,
+        CheckedSub,
+    };
+
+    use super::*;
+
+    fn checked_ops<T>(x: &T, y: &T) -> [Option<T>; 5]
+    where
+        T: CheckedAdd + CheckedSub + CheckedMul + CheckedDiv + CheckedRem,
+    {
+        [
+            x.checked_add(y),
+            x.checked_sub(y),
+            x.checked_mul(y),
+            x.checked_div(y),
+            x.checked_rem(y),
+        ]
+    }
+
+    #[test]
+    fn test_checked_ops() {
+        let x = Decimal::new_raw(175, 1);
+        let y = Decimal::new_raw(-4, 0);
+        assert_eq!(
+            checked_ops(&x, &y),
+            [
+                Some(Decimal::new_raw(135, 1)),
+                Some(Decimal::new_raw(215, 1)),
+                Some(Decimal::new_raw(-700, 1)),
+                Some(Decimal::new_raw(-4375, 3)),
+                Some(Decimal::new_raw(15, 1)),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_checked_ops_fail() {
+        let x = Decimal::MAX;
+        let y = Decimal::TWO;
+        assert_eq!(
+            checked_ops(&x, &y)[..3],
+            [None, Some(Decimal::new_raw(i128::MAX - 2, 0)), None]
+        );
+        let res = checked_ops(&x, &Decimal::ZERO);
+        assert_eq!(res[3], None);
+        assert_eq!(res[4], None);
+        let x = Decimal::new_raw(1, 10);
+        assert_eq!(checked_ops(&x, &x)[2], None);
+    }
+
+    #[test]
+    fn test_checked_neg() {
+        let x = Decimal::new_raw(175, 1);
+        assert_eq!(x.checked_neg(), Some(Decimal::new_raw(-175, 1)));
+        assert_eq!(Decimal::MIN.checked_neg(), Some(Decimal::MAX));
+        let x = Decimal::new_raw(i128::MIN, 2);
+        assert_eq!(x.checked_neg(), None);
+    }
+}
+
+impl Bounded for Decimal {
+    /// Returns `Decimal::MIN`.
+    #[inline(always)]
+    fn min_value() -> Self {
+        Self::MIN
+    }
+
+    /// Returns `Decimal::MAX`.
+    #[inline(always)]
+    fn max_value() -> Self {
+        Self::MAX
+    }
+}
+
+#[cfg(test)]
+mod bounded_tests {
+    use super::*;
+
+    #[test]
+    fn test_bounded() {
+        assert_eq!(<Decimal as Bounded>::min_value(), Decimal::MIN);
+        assert_eq!(<Decimal as Bounded>::max_value(), Decimal::MAX);
+    }
+}
+
+impl FromPrimitive for Decimal {
+    #[inline(always)]
+    fn from_i64(n: i64) -> Option<Self> {
+        Some(Self::from(n))
+    }
+
+    #[inline(always)]
+    fn from_u64(n: u64) -> Option<Self> {
+        Some(Self::from(n))
+    }
+
+    #[inline(always)]
+    fn from_i128(n: i128) -> Option<Self> {
+        Some(Self::from(n))
+    }
+
+    #[inline(always)]
+    fn from_u128(n: u128) -> Option<Self> {
+        Self::try_from(n).ok()
+    }
+
+    /// Converts `n` like `Decimal::try_from(n)`, returning `None` if that
+    /// fails.
+    #[inline(always)]
+    fn from_f32(n: f32) -> Option<Self> {
+        Self::try_from(n).ok()
+    }
+
+    /// Converts `n` like `Decimal::try_from(n)`, returning `None` if that
+    /// fails.
+    #[inline(always)]
+    fn from_f64(n: f64) -> Option<Self> {
+        Self::try_from(n).ok()
+    }
+}
+
+#[cfg(test)]
+mod from_primitive_tests {
+    use super::*;
+
+    #[test]
+    fn test_from_primitive() {
+        assert_eq!(Decimal::from_i8(-7), Some(Decimal::new_raw(-7, 0)));
+        assert_eq!(Decimal::from_u16(7), Some(Decimal::new_raw(7, 0)));
+        assert_eq!(
+            Decimal::from_i64(i64::MIN),
+            Some(Decimal::from(i64::MIN))
+        );
+        assert_eq!(
+            Decimal::from_u64(u64::MAX),
+            Some(Decimal::from(u64::MAX))
+        );
+        assert_eq!(Decimal::from_i128(i128::MAX), Some(Decimal::MAX));
+        assert_eq!(Decimal::from_u128(i128::MAX as u128), Some(Decimal::MAX));
+        assert_eq!(Decimal::from_u128(u128::MAX), None);
+        assert_eq!(
+            Decimal::from_f32(-289.5),
+            Some(Decimal::new_raw(-2895, 1))
+        );
+        assert_eq!(Decimal::from_f64(0.125), Some(Decimal::new_raw(125, 3)));
+        assert_eq!(Decimal::from_f64(f64::NAN), None);
+        assert_eq!(Decimal::from_f64(f64::INFINITY), None);
+        assert_eq!(Decimal::from_f64(1e50), None);
+    }
+}
+
+impl ToPrimitive for Decimal {
+    /// Converts the integral part of `self` to an `i64`, returning `None` if
+    /// it is out of range.
+    #[inline(always)]
+    fn to_i64(&self) -> Option<i64> {
+        i64::try_from(self.trunc()).ok()
+    }
+
+    /// Converts the integral part of `self` to an `u64`, returning `None` if
+    /// it is out of range.
+    #[inline(always)]
+    fn to_u64(&self) -> Option<u64> {
+        u64::try_from(self.trunc()).ok()
+    }
+
+    /// Converts the integral part of `self` to an `i128`.
+    #[inline(always)]
+    fn to_i128(&self) -> Option<i128> {
+        i128::try_from(self.trunc()).ok()
+    }
+
+    /// Converts the integral part of `self` to an `u128`, returning `None`
+    /// if it is negative.
+    #[inline(always)]
+    fn to_u128(&self) -> Option<u128> {
+        u128::try_from(self.trunc()).ok()
+    }
+
+    /// Converts `self` to the nearest `f32`.
+    #[inline(always)]
+    fn to_f32(&self) -> Option<f32> {
+        Some(f32::from(*self))
+    }
+
+    /// Converts `self` to the nearest `f64`.
+    #[inline(always)]
+    fn to_f64(&self) -> Option<f64> {
+        Some(f64::from(*self))
+    }
+}
+
+#[cfg(test)]
+mod to_primitive_tests {
+    use super::*;
+
+    #[test]
+    fn test_to_primitive() {
+        let d = Decimal::new_raw(-2895, 1);
+        assert_eq!(d.to_i8(), None);
+        assert_eq!(d.to_i16(), Some(-289));
+        assert_eq!(d.to_i64(), Some(-289));
+        assert_eq!(d.to_u64(), None);
+        assert_eq!(d.to_i128(), Some(-289));
+        assert_eq!(d.to_u128(), None);
+        assert_eq!(d.to_f32(), Some(-289.5_f32));
+        assert_eq!(d.to_f64(), Some(-289.5_f64));
+        let d = Decimal::new_raw(2559, 1);
+        assert_eq!(d.to_u8(), Some(255));
+        assert_eq!(d.to_u128(), Some(255));
+        assert_eq!(Decimal::MAX.to_i128(), Some(i128::MAX));
+        assert_eq!(Decimal::MAX.to_u128(), Some(i128::MAX as u128));
+        assert_eq!(Decimal::MAX.to_i64(), None);
+    }
+}
+
+impl num_traits::NumCast for Decimal {
+    /// Converts `n` to a `Decimal`, returning `None` if `n` can not be
+    /// represented as a `Decimal`.
+    ///
+    /// Integral values - including all values of native integer types - are
+    /// converted via `i128` or `u128`, so that there is no loss of
+    /// precision.
+    ///
+    /// As `ToPrimitive` does not give access to the exact value of any other
+    /// type, non-integral values are converted via their nearest `f64`,
+    /// taking the shortest decimal which rounds to that `f64` (see
+    /// [Decimal::from_f64_shortest]). Thus, a non-integral `Decimal` with up
+    /// to 15 significant digits is converted without loss of precision.
+    fn from<T: ToPrimitive>(n: T) -> Option<Self> {
+        if let Some(f) = n.to_f64() {
+            if f.is_finite() && f.fract() != 0.0 {
+                return Self::from_f64_shortest(f).ok();
+            }
+        }
+        if let Some(i) = n.to_i128() {
+            return Some(<Self as From<i128>>::from(i));
+        }
+        if let Some(u) = n.to_u128() {
+            return <Self as FromPrimitive>::from_u128(u);
+        }
+        n.to_f64().and_then(<Self as FromPrimitive>::from_f64)
+    }
+}
+
+#[cfg(test)]
+mod num_cast_tests {
+    use super::*;
+
+    #[test]
+    fn test_num_cast() {
+        assert_eq!(
+            <Decimal as num_traits::NumCast>::from(-17_i8),
+            Some(Decimal::new_raw(-17, 0))
+        );
+        assert_eq!(
+            <Decimal as num_traits::NumCast>::from(u64::MAX),
+            Some(Decimal::from(u64::MAX))
+        );
+        assert_eq!(
+            <Decimal as num_traits::NumCast>::from(i128::MAX),
+            Some(Decimal::MAX)
+        );
+        assert_eq!(<Decimal as num_traits::NumCast>::from(u128::MAX), None);
+        assert_eq!(
+            <Decimal as num_traits::NumCast>::from(-2.5_f32),
+            Some(Decimal::new_raw(-25, 1))
+        );
+        assert_eq!(
+            <Decimal as num_traits::NumCast>::from(1e20_f64),
+            Some(Decimal::from(100_000_000_000_000_000_000_i128))
+        );
+        assert_eq!(<Decimal as num_traits::NumCast>::from(f64::NAN), None);
+        let d = Decimal::new_raw(-2895, 1);
+        assert_eq!(
+            <Decimal as num_traits::NumCast>::from(d),
+            Some(Decimal::new_raw(-2895, 1))
+        );
+        for d in [
+            Decimal::new_raw(1, 1),
+            Decimal::new_raw(-123_456_789_012_345, 9),
+            Decimal::new_raw(123_456_789_012_345, 18),
+            Decimal::new_raw(1_000_000_000_000_000_000_000_000_000_000, 0),
+            Decimal::MAX,
+        ] {
+            assert_eq!(<Decimal as num_traits::NumCast>::from(d), Some(d));
+        }
+        assert_eq!(
+            <Decimal as num_traits::NumCast>::from(0.1_f64),
+            Some(Decimal::new_raw(1, 1))
+        );
+        assert_eq!(<i32 as num_traits::NumCast>::from(d), Some(-289));
+    }
+}
+
+impl Pow<u32> for Decimal {
+    type Output = Self;
+
+    /// Returns `self` to the power of `exp`, computed by repeated
+    /// multiplication.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result exceeds the internal representation of
+    /// `Decimal`!
+    fn pow(self, mut exp: u32) -> Self::Output {
+        let mut base = self;
+        let mut res = Self::ONE;
+        while exp > 0 {
+            if exp & 1 == 1 {
+                res *= base;
+            }
+            exp >>= 1;
+            if exp > 0 {
+                base = base * base;
+            }
+        }
+        res
+    }
+}
+
+impl Pow<u32> for &Decimal {
+    type Output = Decimal;
+
+    #[inline(always)]
+    fn pow(self, exp: u32) -> Self::Output {
+        Pow::pow(*self, exp)
+    }
+}
+
+#[cfg(test)]
+mod pow_tests {
+    use super::*;
+
+    #[test]
+    fn test_pow() {
+        let d = Decimal::new_raw(-15, 1);
+        assert_eq!(d.pow(0), Decimal::ONE);
+        assert_eq!(d.pow(1), d);
+        assert_eq!(d.pow(2), Decimal::new_raw(225, 2));
+        assert_eq!((&d).pow(3), Decimal::new_raw(-3375, 3));
+        assert_eq!(Decimal::TWO.pow(126), Decimal::from(1_i128 << 126));
+        let d = Decimal::new_raw(1, 1);
+        assert_eq!(d.pow(18), Decimal::new_raw(1, 18));
+        assert_eq!(d.pow(19), Decimal::ZERO);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_pow_overflow() {
+        let _ = Decimal::TWO.pow(127);
+    }
+}
+
+impl Inv for Decimal {
+    type Output = Self;
+
+    /// Returns `1 / self`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is zero!
+    #[inline(always)]
+    fn inv(self) -> Self::Output {
+        Self::ONE / self
+    }
+}
+
+impl Inv for &Decimal {
+    type Output = Decimal;
+
+    #[inline(always)]
+    fn inv(self) -> Self::Output {
+        Decimal::ONE / self
+    }
+}
+
+#[cfg(test)]
+mod inv_tests {
+    use super::*;
+
+    #[test]
+    fn test_inv() {
+        let d = Decimal::new_raw(-8, 0);
+        assert_eq!(d.inv(), Decimal::new_raw(-125, 3));
+        assert_eq!((&d).inv(), Decimal::new_raw(-125, 3));
+        let d = Decimal::new_raw(3, 0);
+        assert_eq!(d.inv(), Decimal::new_raw(333333333333333333, 18));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_inv_zero() {
+        let _ = Decimal::ZERO.inv();
+    }
+}
+
+impl Decimal {
+    #[inline(always)]
+    const fn saturated(positive: bool) -> Self {
+        if positive {
+            Self::MAX
+        } else {
+            Self::MIN
+        }
+    }
+}
+
+// Returns `x + y`, rounded to the largest number of fractional digits
+// allowing the result to be represented, or saturated at `Decimal::MIN` or
+// `Decimal::MAX` if even its integral part exceeds the range of `Decimal`.
+fn saturating_sum(x: Decimal, y: Decimal) -> Decimal {
+    if let Some(sum) = crate::CheckedAdd::checked_add(x, y) {
+        return sum;
+    }
+    let n_frac_digits = max(x.n_frac_digits, y.n_frac_digits);
+    let x_factor = ten_pow(n_frac_digits - x.n_frac_digits);
+    let y_factor = ten_pow(n_frac_digits - y.n_frac_digits);
+    for shift in 0..=n_frac_digits {
+        let divisor = ten_pow(shift);
+        let (Some(quot), rem) = i256_sum_of_prods_div_mod(
+            x.coeff, x_factor, y.coeff, y_factor, divisor,
+        ) else {
+            continue;
+        };
+        // quot and rem are truncated towards zero, so both have the same
+        // sign; rounding only depends on the last digit of quot and on rem,
+        // and |last_digit * divisor + rem| < 10 ^ 19
+        let last_digit = quot % 10;
+        let coeff = (quot - last_digit).checked_add(i128_div_rounded(
+            last_digit * divisor + rem,
+            divisor,
+            None,
+        ));
+        if let Some(coeff) = coeff.filter(|coeff| *coeff != i128::MIN) {
+            return Decimal::new_raw(coeff, n_frac_digits - shift);
+        }
+    }
+    // |x + y| <= max(|x|, |y|) if x and y have different signs, so x and y
+    // have the same sign here
+    Decimal::saturated(!x.is_negative())
+}
+
+impl SaturatingAdd for Decimal {
+    /// Returns `self + v`, saturating at `Decimal::MIN` or `Decimal::MAX`
+    /// if the integral part of the exact result can not be represented.
+    ///
+    /// If the exact result can not be represented with the number of
+    /// fractional digits of the operands, it is rounded to the largest
+    /// number of fractional digits allowing it to be represented, according
+    /// to the current [RoundingMode](crate::RoundingMode).
+    fn saturating_add(&self, v: &Self) -> Self {
+        saturating_sum(*self, *v)
+    }
+}
+
+impl SaturatingSub for Decimal {
+    /// Returns `self - v`, saturating at `Decimal::MIN` or `Decimal::MAX`
+    /// if the integral part of the exact result can not be represented.
+    ///
+    /// If the exact result can not be represented with the number of
+    /// fractional digits of the operands, it is rounded to the largest
+    /// number of fractional digits allowing it to be represented, according
+    /// to the current [RoundingMode](crate::RoundingMode).
+    fn saturating_sub(&self, v: &Self) -> Self {
+        saturating_sum(*self, -*v)
+    }
+}
+
+impl SaturatingMul for Decimal {
+    /// Returns `self * v`, rounded like `self * v`, saturating at
+    /// `Decimal::MIN` or `Decimal::MAX` if the result can not be
+    /// represented.
+    fn saturating_mul(&self, v: &Self) -> Self {
+        checked_mul_rounded(*self, *v, MAX_N_FRAC_DIGITS).unwrap_or_else(
+            || Self::saturated(self.is_negative() == v.is_negative()),
+        )
+    }
+}
+
+impl Saturating for Decimal {
+    #[inline(always)]
+    fn saturating_add(self, v: Self) -> Self {
+        SaturatingAdd::saturating_add(&self, &v)
+    }
+
+    #[inline(always)]
+    fn saturating_sub(self, v: Self) -> Self {
+        SaturatingSub::saturating_sub(&self, &v)
+    }
+}
+
+#[cfg(test)]
+mod saturating_tests {
+    use super::*;
+
+    #[test]
+    fn test_saturating_add() {
+        let x = Decimal::new_raw(175, 1);
+        let y = Decimal::new_raw(-4, 0);
+        assert_eq!(
+            SaturatingAdd::saturating_add(&x, &y),
+            Decimal::new_raw(135, 1)
+        );
+        assert_eq!(
+            SaturatingAdd::saturating_add(&Decimal::MAX, &x),
+            Decimal::MAX
+        );
+        assert_eq!(
+            SaturatingAdd::saturating_add(&Decimal::MIN, &y),
+            Decimal::MIN
+        );
+        assert_eq!(Saturating::saturating_add(Decimal::MAX, x), Decimal::MAX);
+        // exact result not representable, rounded to less fractional digits
+        let z = Decimal::new_raw(-1, 18);
+        assert_eq!(
+            SaturatingAdd::saturating_add(&Decimal::MAX, &z),
+            Decimal::MAX
+        );
+        assert_eq!(
+            SaturatingAdd::saturating_add(&z, &Decimal::MIN),
+            Decimal::MIN
+        );
+    }
+
+    #[test]
+    fn test_saturating_add_mixed_n_frac_digits() {
+        // aligning the operands overflows, but the result does not
+        let x = Decimal::new_raw(1_000_000_000_000_000_000_000, 0);
+        let z = Decimal::new_raw(1, 18);
+        assert_eq!(
+            SaturatingAdd::saturating_add(&x, &z),
+            Decimal::new_raw(1_000_000_000_000_000_000_000_000_000_000, 9)
+        );
+        assert_eq!(
+            SaturatingSub::saturating_sub(&x, &z),
+            Decimal::new_raw(1_000_000_000_000_000_000_000_000_000_000, 9)
+        );
+        let y = Decimal::new_raw(10_000_000_000_000_000_000_005, 1);
+        assert_eq!(
+            SaturatingAdd::saturating_add(&x, &y),
+            Decimal::new_raw(20_000_000_000_000_000_000_005, 1)
+        );
+        // exact result representable, but not the aligned operand
+        let x = Decimal::new_raw(200_000_000_000_000_000_000, 0);
+        let y = Decimal::new_raw(
+            -150_000_000_000_000_000_000_000_000_000_000_000_000,
+            18,
+        );
+        assert_eq!(
+            SaturatingAdd::saturating_add(&x, &y),
+            Decimal::new_raw(50_000_000_000_000_000_000, 0)
+        );
+        // rounded according to the current rounding mode
+        let x = Decimal::new_raw(100_000_000_000_000_000_000_000_000_000, 0);
+        let y = Decimal::new_raw(-25, 1);
+        assert_eq!(
+            SaturatingSub::saturating_sub(&x, &y),
+            Decimal::new_raw(1_000_000_000_000_000_000_000_000_000_025, 1)
+        );
+        let y = Decimal::new_raw(-25, 2);
+        assert_eq!(
+            SaturatingAdd::saturating_add(&Decimal::MAX, &y),
+            Decimal::MAX
+        );
+        let x = Decimal::new_raw(17 * ten_pow(36), 0);
+        assert_eq!(
+            SaturatingAdd::saturating_add(&x, &y),
+            Decimal::new_raw(17 * ten_pow(37) - 2, 1)
+        );
+    }
+
+    #[test]
+    fn test_saturating_sub() {
+        let x = Decimal::new_raw(175, 1);
+        let y = Decimal::new_raw(-4, 0);
+        assert_eq!(
+            SaturatingSub::saturating_sub(&x, &y),
+            Decimal::new_raw(215, 1)
+        );
+        assert_eq!(
+            SaturatingSub::saturating_sub(&Decimal::MAX, &y),
+            Decimal::MAX
+        );
+        assert_eq!(
+            SaturatingSub::saturating_sub(&Decimal::MIN, &x),
+            Decimal::MIN
+        );
+        assert_eq!(Saturating::saturating_sub(Decimal::MIN, x), Decimal::MIN);
+        let z = Decimal::new_raw(1, 18);
+        assert_eq!(
+            SaturatingSub::saturating_sub(&Decimal::MAX, &z),
+            Decimal::MAX
+        );
+        assert_eq!(
+            SaturatingSub::saturating_sub(&Decimal::MIN, &z),
+            Decimal::MIN
+        );
+    }
+
+    #[test]
+    fn test_saturating_mul() {
+        let x = Decimal::new_raw(175, 1);
+        let y = Decimal::new_raw(-4, 0);
+        assert_eq!(
+            SaturatingMul::saturating_mul(&x, &y),
+            Decimal::new_raw(-70, 0)
+        );
+        assert_eq!(
+            SaturatingMul::saturating_mul(&Decimal::MAX, &x),
+            Decimal::MAX
+        );
+        assert_eq!(
+            SaturatingMul::saturating_mul(&Decimal::MAX, &y),
+            Decimal::MIN
+        );
+        assert_eq!(
+            SaturatingMul::saturating_mul(&Decimal::MIN, &y),
+            Decimal::MAX
+        );
+        let z = Decimal::new_raw(1, 10);
+        assert_eq!(
+            SaturatingMul::saturating_mul(&z, &z),
+            Decimal::new_raw(0, 18)
+        );
+    }
+}
+
+impl MulAdd for Decimal {
+    type Output = Self;
+
+    /// Returns `(self * a) + b`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result exceeds the internal representation of
+    /// `Decimal`!
+    #[inline(always)]
+    fn mul_add(self, a: Self, b: Self) -> Self::Output {
+        self * a + b
+    }
+}
+
+impl MulAddAssign for Decimal {
+    /// Sets `self` to `(self * a) + b`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result exceeds the internal representation of
+    /// `Decimal`!
+    #[inline(always)]
+    fn mul_add_assign(&mut self, a: Self, b: Self) {
+        *self = MulAdd::mul_add(*self, a, b);
+    }
+}
+
+#[cfg(test)]
+mod mul_add_tests {
+    use super::*;
+
+    #[test]
+    fn test_mul_add() {
+        let x = Decimal::new_raw(175, 1);
+        let a = Decimal::new_raw(-4, 0);
+        let b = Decimal::new_raw(5, 2);
+        assert_eq!(x.mul_add(a, b), Decimal::new_raw(-6995, 2));
+        let mut y = x;
+        y.mul_add_assign(a, b);
+        assert_eq!(y, Decimal::new_raw(-6995, 2));
+    }
+}
@@ -9,9 +9,7 @@
 
 use fpdec_core::{i128_div_rounded, ten_pow, Round};
 
-use crate::Decimal;
-#[cfg(doc)]
-use crate::RoundingMode;
+use crate::{Decimal, RoundingMode};
 
 impl Round for Decimal {
     /// Returns a new `Decimal` with its value rounded to `n_frac_digits`
@@ -36,27 +34,17 @@ impl Round for Decimal {
     /// assert_eq!(r.to_string(), "30");
     /// ```
     fn round(self, n_frac_digits: i8) -> Self {
-        if n_frac_digits >= self.n_frac_digits as i8 {
-            self
-        } else if n_frac_digits < self.n_frac_digits as i8 - 38 {
-            Self::ZERO
-        } else {
-            // n_frac_digits < self.n_frac_digits
-            let shift: u8 = (self.n_frac_digits as i8 - n_frac_digits) as u8;
-            let divisor = ten_pow(shift);
-            let coeff = i128_div_rounded(self.coeff, divisor, None);
-            if n_frac_digits >= 0 {
-                Self {
-                    coeff,
-                    n_frac_digits: n_frac_digits as u8,
-                }
-            } else {
-                // shift back
-                Self {
-                    coeff: coeff * ten_pow(-n_frac_digits as u8),
-                    n_frac_digits: 0,
-                }
-            }
+        match self.rounded_coeff(n_frac_digits, None) {
+            (coeff, n_frac_digits) if n_frac_digits >= 0 => Self {
+                coeff,
+                n_frac_digits: n_frac_digits as u8,
+            },
+            (0, _) => Self::ZERO,
+            // shift back
+            (coeff, n_frac_digits) => Self {
+                coeff: coeff * ten_pow(-n_frac_digits as u8),
+                n_frac_digits: 0,
+            },
         }
     }
 
@@ -83,29 +71,46 @@ impl Round for Decimal {
     /// # } f();}
     /// ```
     fn checked_round(self, n_frac_digits: i8) -> Option<Self> {
+        match self.rounded_coeff(n_frac_digits, None) {
+            (coeff, n_frac_digits) if n_frac_digits >= 0 => Some(Self {
+                coeff,
+                n_frac_digits: n_frac_digits as u8,
+            }),
+            (0, _) => Some(Self::ZERO),
+            // shift back
+            (coeff, n_frac_digits) => coeff
+                .checked_mul(ten_pow(-n_frac_digits as u8))
+                .map(|coeff| Self {
+                    coeff,
+                    n_frac_digits: 0,
+                }),
+        }
+    }
+}
+
+impl Decimal {
+    /// Returns the coefficient of `self` rounded to `n_frac_digits`
+    /// fractional digits according to `mode` (or the current [RoundingMode],
+    /// if `mode` is `None`), together with the number of fractional digits
+    /// of the result.
+    ///
+    /// In contrast to [Round::round], a coefficient rounded to a negative
+    /// number of fractional digits is not shifted back, so this can't
+    /// overflow.
+    pub(crate) fn rounded_coeff(
+        self,
+        n_frac_digits: i8,
+        mode: Option<RoundingMode>,
+    ) -> (i128, i8) {
         if n_frac_digits >= self.n_frac_digits as i8 {
-            Some(self)
+            (self.coeff, self.n_frac_digits as i8)
         } else if n_frac_digits < self.n_frac_digits as i8 - 38 {
-            Some(Self::ZERO)
+            (0, n_frac_digits)
         } else {
             // n_frac_digits < self.n_frac_digits
             let shift: u8 = (self.n_frac_digits as i8 - n_frac_digits) as u8;
             let divisor = ten_pow(shift);
-            let coeff = i128_div_rounded(self.coeff, divisor, None);
-            if n_frac_digits >= 0 {
-                Some(Self {
-                    coeff,
-                    n_frac_digits: n_frac_digits as u8,
-                })
-            } else {
-                // shift back
-                coeff.checked_mul(ten_pow(-n_frac_digits as u8)).map(
-                    |coeff| Self {
-                        coeff,
-                        n_frac_digits: 0,
-                    },
-                )
-            }
+            (i128_div_rounded(self.coeff, divisor, mode), n_frac_digits)
         }
     }
 }
@@ -0,0 +1,193 @@
+// ---------------------------------------------------------------------------
+// Copyright:   (c) 2026 ff. Michael Amrhein (michael@adrhinum.de)
+// License:     This program is part of a larger application. For license
+//              details please read the file LICENSE.TXT provided together
+//              with the application.
+// ---------------------------------------------------------------------------
+// $Source$
+// $Revision$
+
+use crate::{normalize, Decimal, DecimalError, MAX_N_FRAC_DIGITS};
+
+impl TryFrom<rust_decimal::Decimal> for Decimal {
+    type Error = DecimalError;
+
+    /// Tries to convert a `rust_decimal::Decimal` value `d` into a
+    /// `Decimal`.
+    ///
+    /// Returns the value as `Decimal`, wrapped in Result::Ok, if it can be
+    /// represented with at most `MAX_N_FRAC_DIGITS` fractional digits.
+    /// Trailing zeros beyond `MAX_N_FRAC_DIGITS` are removed.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DecimalError::MaxNFracDigitsExceeded` (wrapped in
+    /// Result::Err), if the value of `d` needs more than
+    /// `MAX_N_FRAC_DIGITS` fractional digits.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fpdec::{Dec, Decimal, DecimalError};
+    /// # fn main() -> Result<(), DecimalError> {
+    /// let r = rust_decimal::Decimal::from_i128_with_scale(-123456, 4);
+    /// let d = Decimal::try_from(r)?;
+    /// assert_eq!(d, Dec!(-12.3456));
+    /// # Ok(()) }
+    /// ```
+    #[allow(clippy::cast_possible_truncation)]
+    #[allow(clippy::integer_division)]
+    fn try_from(d: rust_decimal::Decimal) -> Result<Self, Self::Error> {
+        // |mantissa| < 2 ^ 96, scale <= 28
+        let mut coeff = d.mantissa();
+        let mut scale = d.scale();
+        while scale > u32::from(MAX_N_FRAC_DIGITS) && coeff % 10 == 0 {
+            coeff /= 10;
+            scale -= 1;
+        }
+        if scale > u32::from(MAX_N_FRAC_DIGITS) {
+            return Err(DecimalError::MaxNFracDigitsExceeded);
+        }
+        Ok(Self {
+            coeff,
+            n_frac_digits: scale as u8,
+        })
+    }
+}
+
+impl TryFrom<Decimal> for rust_decimal::Decimal {
+    type Error = DecimalError;
+
+    /// Tries to convert a `Decimal` value `d` into a
+    /// `rust_decimal::Decimal`.
+    ///
+    /// Returns the value as `rust_decimal::Decimal`, wrapped in Result::Ok,
+    /// if its coefficient - after removing trailing fractional zeros - fits
+    /// into the 96-bit mantissa of a `rust_decimal::Decimal`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DecimalError::InternalOverflow` (wrapped in Result::Err),
+    /// if the value of `d` can not be represented as
+    /// `rust_decimal::Decimal`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fpdec::{Dec, Decimal, DecimalError};
+    /// # fn main() -> Result<(), DecimalError> {
+    /// let r = rust_decimal::Decimal::try_from(Dec!(-12.3456))?;
+    /// assert_eq!(r.to_string(), "-12.3456");
+    /// let res = rust_decimal::Decimal::try_from(Decimal::MAX);
+    /// assert_eq!(res.unwrap_err(), DecimalError::InternalOverflow);
+    /// # Ok(()) }
+    /// ```
+    fn try_from(d: Decimal) -> Result<Self, Self::Error> {
+        let (mut coeff, mut n_frac_digits) = (d.coeff, d.n_frac_digits);
+        if let Ok(res) =
+            Self::try_from_i128_with_scale(coeff, u32::from(n_frac_digits))
+        {
+            return Ok(res);
+        }
+        normalize(&mut coeff, &mut n_frac_digits);
+        Self::try_from_i128_with_scale(coeff, u32::from(n_frac_digits))
+            .map_err(|_| DecimalError::InternalOverflow)
+    }
+}
+
+#[cfg(test)]
+mod rust_decimal_tests {
+    use alloc::string::ToString;
+
+    use super::*;
+
+    #[test]
+    fn test_from_rust_decimal() {
+        let test_data = [
+            (0_i128, 0_u32, 0_i128, 0_u8),
+            (-1, 0, -1, 0),
+            (123456000000, 24, 123456, 18),
+            (-1234560000000000, 28, -123456, 18),
+            (
+                79228162514264337593543950335,
+                0,
+                79228162514264337593543950335,
+                0,
+            ),
+            (
+                -79228162514264337593543950335,
+                18,
+                -79228162514264337593543950335,
+                18,
+            ),
+            (500, 2, 500, 2),
+        ];
+        for (mantissa, scale, coeff, n_frac_digits) in test_data {
+            let r =
+                rust_decimal::Decimal::from_i128_with_scale(mantissa, scale);
+            let d = Decimal::try_from(r).unwrap();
+            assert_eq!(d.coefficient(), coeff);
+            assert_eq!(d.n_frac_digits(), n_frac_digits);
+        }
+    }
+
+    #[test]
+    fn test_from_rust_decimal_frac_limit_exceeded() {
+        for (mantissa, scale) in [(1_i128, 19_u32), (-123456789, 28)] {
+            let r =
+                rust_decimal::Decimal::from_i128_with_scale(mantissa, scale);
+            assert_eq!(
+                Decimal::try_from(r).unwrap_err(),
+                DecimalError::MaxNFracDigitsExceeded
+            );
+        }
+    }
+
+    #[test]
+    fn test_into_rust_decimal() {
+        let test_data = [
+            (Decimal::ZERO, 0_i128, 0_u32),
+            (Decimal::new_raw(-17, 1), -17, 1),
+            (Decimal::new_raw(12345, 18), 12345, 18),
+            (
+                Decimal::new_raw(79228162514264337593543950335, 0),
+                79228162514264337593543950335,
+                0,
+            ),
+            // trailing zeros removed to fit the mantissa
+            (
+                Decimal::new_raw(792281625142643375935439503350000, 5),
+                79228162514264337593543950335,
+                1,
+            ),
+        ];
+        for (d, mantissa, scale) in test_data {
+            let r = rust_decimal::Decimal::try_from(d).unwrap();
+            assert_eq!(r.mantissa(), mantissa);
+            assert_eq!(r.scale(), scale);
+        }
+    }
+
+    #[test]
+    fn test_into_rust_decimal_overflow() {
+        for d in [
+            Decimal::MAX,
+            Decimal::MIN,
+            Decimal::new_raw(79228162514264337593543950336, 0),
+            Decimal::new_raw(792281625142643375935439503351, 1),
+        ] {
+            assert_eq!(
+                rust_decimal::Decimal::try_from(d).unwrap_err(),
+                DecimalError::InternalOverflow
+            );
+        }
+    }
+
+    #[test]
+    fn test_roundtrip() {
+        let d = Decimal::new_raw(-98765432109876543210, 17);
+        let r = rust_decimal::Decimal::try_from(d).unwrap();
+        assert_eq!(r.to_string(), d.to_string());
+        assert_eq!(Decimal::try_from(r).unwrap(), d);
+    }
+}
@@ -0,0 +1,537 @@
+// ---------------------------------------------------------------------------
+// Copyright:   (c) 2026 ff. Michael Amrhein (michael@adrhinum.de)
+// License:     This program is part of a larger application. For license
+//              details please read the file LICENSE.TXT provided together
+//              with the application.
+// ---------------------------------------------------------------------------
+// $Source$
+// $Revision$
+
+use alloc::{string::String, vec::Vec};
+use core::fmt::Write;
+
+use fpdec_core::{i128_div_rounded, ten_pow};
+
+use crate::Decimal;
+
+/// Language used to spell out numbers in words.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Language {
+    /// English (short scale, e.g. "one billion" for 10 ^ 9).
+    English,
+    /// German (long scale, e.g. "eine Milliarde" for 10 ^ 9).
+    German,
+}
+
+/// Style used to express the fractional part of an amount in words.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum WordsStyle {
+    /// Integral part in words, fractional part as hundredths in digits, e.g.
+    /// "One thousand two hundred thirty-four and 56/100".
+    Cheque,
+    /// Integral and fractional part in words, the latter as cents, e.g.
+    /// "One thousand two hundred thirty-four and fifty-six cents".
+    Spelled,
+}
+
+const EN_ONES: [&str; 20] = [
+    "zero",
+    "one",
+    "two",
+    "three",
+    "four",
+    "five",
+    "six",
+    "seven",
+    "eight",
+    "nine",
+    "ten",
+    "eleven",
+    "twelve",
+    "thirteen",
+    "fourteen",
+    "fifteen",
+    "sixteen",
+    "seventeen",
+    "eighteen",
+    "nineteen",
+];
+
+const EN_TENS: [&str; 10] = [
+    "", "", "twenty", "thirty", "forty", "fifty", "sixty", "seventy",
+    "eighty", "ninety",
+];
+
+// 10 ^ (3 * idx), up to 10 ^ 36, which covers the range of u128
+const EN_SCALES: [&str; 13] = [
+    "",
+    "thousand",
+    "million",
+    "billion",
+    "trillion",
+    "quadrillion",
+    "quintillion",
+    "sextillion",
+    "septillion",
+    "octillion",
+    "nonillion",
+    "decillion",
+    "undecillion",
+];
+
+const DE_ONES: [&str; 20] = [
+    "null",
+    "eins",
+    "zwei",
+    "drei",
+    "vier",
+    "fünf",
+    "sechs",
+    "sieben",
+    "acht",
+    "neun",
+    "zehn",
+    "elf",
+    "zwölf",
+    "dreizehn",
+    "vierzehn",
+    "fünfzehn",
+    "sechzehn",
+    "siebzehn",
+    "achtzehn",
+    "neunzehn",
+];
+
+const DE_TENS: [&str; 10] = [
+    "", "", "zwanzig", "dreißig", "vierzig", "fünfzig", "sechzig", "siebzig",
+    "achtzig", "neunzig",
+];
+
+// (singular, plural) of 10 ^ (3 * idx) for idx >= 2, up to 10 ^ 36
+const DE_SCALES: [(&str, &str); 13] = [
+    ("", ""),
+    ("", ""),
+    ("Million", "Millionen"),
+    ("Milliarde", "Milliarden"),
+    ("Billion", "Billionen"),
+    ("Billiarde", "Billiarden"),
+    ("Trillion", "Trillionen"),
+    ("Trilliarde", "Trilliarden"),
+    ("Quadrillion", "Quadrillionen"),
+    ("Quadrilliarde", "Quadrilliarden"),
+    ("Quintillion", "Quintillionen"),
+    ("Quintilliarde", "Quintilliarden"),
+    ("Sextillion", "Sextillionen"),
+];
+
+/// Splits `n` into groups of three digits, least significant group first.
+fn groups(mut n: u128) -> Vec<usize> {
+    let mut groups = Vec::with_capacity(EN_SCALES.len());
+    while n > 0 {
+        // n % 1000 < 1000, so the cast is safe
+        #[allow(clippy::cast_possible_truncation)]
+        groups.push((n % 1000) as usize);
+        #[allow(clippy::integer_division)]
+        {
+            n /= 1000;
+        }
+    }
+    groups
+}
+
+/// Appends the English words for 0 < `n` < 1000 to `out`.
+fn push_en_below_1000(n: usize, out: &mut String) {
+    #[allow(clippy::integer_division)]
+    let (hundreds, rem) = (n / 100, n % 100);
+    if hundreds > 0 {
+        out.push_str(EN_ONES[hundreds]);
+        out.push_str(" hundred");
+        if rem > 0 {
+            out.push(' ');
+        }
+    }
+    if rem >= 20 {
+        #[allow(clippy::integer_division)]
+        out.push_str(EN_TENS[rem / 10]);
+        if rem % 10 > 0 {
+            out.push('-');
+            out.push_str(EN_ONES[rem % 10]);
+        }
+    } else if rem > 0 {
+        out.push_str(EN_ONES[rem]);
+    }
+}
+
+/// Appends the English words for `n` to `out`.
+fn push_en(n: u128, out: &mut String) {
+    if n == 0 {
+        out.push_str(EN_ONES[0]);
+        return;
+    }
+    let groups = groups(n);
+    for (idx, group) in groups.iter().enumerate().rev() {
+        if *group == 0 {
+            continue;
+        }
+        if idx + 1 < groups.len() {
+            out.push(' ');
+        }
+        push_en_below_1000(*group, out);
+        if idx > 0 {
+            out.push(' ');
+            out.push_str(EN_SCALES[idx]);
+        }
+    }
+}
+
+/// Appends the German words for 0 < `n` < 1000 to `out`, using `one` for a
+/// final "1" (i.e. "eins", "ein" or "eine", depending on what follows).
+fn push_de_below_1000(n: usize, one: &str, out: &mut String) {
+    #[allow(clippy::integer_division)]
+    let (hundreds, rem) = (n / 100, n % 100);
+    if hundreds > 0 {
+        out.push_str(if hundreds == 1 {
+            "ein"
+        } else {
+            DE_ONES[hundreds]
+        });
+        out.push_str("hundert");
+    }
+    match rem {
+        0 => {}
+        1 => out.push_str(one),
+        2..=19 => out.push_str(DE_ONES[rem]),
+        _ => {
+            let ones = rem % 10;
+            if ones > 0 {
+                out.push_str(if ones == 1 { "ein" } else { DE_ONES[ones] });
+                out.push_str("und");
+            }
+            #[allow(clippy::integer_division)]
+            out.push_str(DE_TENS[rem / 10]);
+        }
+    }
+}
+
+/// Appends the German words for `n` to `out`, using `one` for a final "1".
+///
+/// Numbers below one million are written as one word, the multiples of
+/// million, milliarde etc. as separate words.
+fn push_de(n: u128, one: &str, out: &mut String) {
+    if n == 0 {
+        out.push_str(DE_ONES[0]);
+        return;
+    }
+    let mut prev_idx = None;
+    for (idx, group) in groups(n).iter().enumerate().rev() {
+        if *group == 0 {
+            continue;
+        }
+        if prev_idx.is_some_and(|prev| prev >= 2) {
+            out.push(' ');
+        }
+        match idx {
+            0 => push_de_below_1000(*group, one, out),
+            1 => {
+                push_de_below_1000(*group, "ein", out);
+                out.push_str("tausend");
+            }
+            _ => {
+                let (singular, plural) = DE_SCALES[idx];
+                if *group == 1 {
+                    out.push_str("eine ");
+                    out.push_str(singular);
+                } else {
+                    push_de_below_1000(*group, "eine", out);
+                    out.push(' ');
+                    out.push_str(plural);
+                }
+            }
+        }
+        prev_idx = Some(idx);
+    }
+}
+
+/// Converts the first character of `s` to upper case.
+fn capitalize(s: &str) -> String {
+    let mut chars = s.chars();
+    chars.next().map_or_else(String::new, |first| {
+        first.to_uppercase().chain(chars).collect()
+    })
+}
+
+impl Decimal {
+    /// Returns `self` spelled out in words in the given language, as used on
+    /// cheques and in legal documents.
+    ///
+    /// The value is rounded to two fractional digits (according to the
+    /// current [RoundingMode](crate::RoundingMode)), the fractional part is
+    /// then expressed in hundredths:
+    ///
+    /// * [WordsStyle::Cheque]: the integral part in words, followed by the
+    ///   fractional part as digits over 100,
+    /// * [WordsStyle::Spelled]: the integral part and the number of cents in
+    ///   words; a zero integral part or zero cents are omitted, unless the
+    ///   whole value is zero.
+    ///
+    /// Negative values are preceded by "minus". The conversion is based
+    /// solely on the coefficient and the number of fractional digits of
+    /// `self` and covers the full range of `Decimal` values.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fpdec::{Dec, Decimal, Language, WordsStyle};
+    /// let d = Dec!(1234.56);
+    /// assert_eq!(
+    ///     d.to_words(Language::English, WordsStyle::Cheque),
+    ///     "One thousand two hundred thirty-four and 56/100"
+    /// );
+    /// assert_eq!(
+    ///     d.to_words(Language::English, WordsStyle::Spelled),
+    ///     "One thousand two hundred thirty-four and fifty-six cents"
+    /// );
+    /// assert_eq!(
+    ///     d.to_words(Language::German, WordsStyle::Cheque),
+    ///     "Eintausendzweihundertvierunddreißig und 56/100"
+    /// );
+    /// assert_eq!(
+    ///     Dec!(-2000001.01).to_words(Language::German, WordsStyle::Spelled),
+    ///     "Minus zwei Millionen eins und ein Cent"
+    /// );
+    /// ```
+    #[must_use]
+    pub fn to_words(self, lang: Language, style: WordsStyle) -> String {
+        let (int, cents, is_negative) = self.int_and_cents();
+        let and = match lang {
+            Language::English => " and ",
+            Language::German => " und ",
+        };
+        let mut words = String::new();
+        if is_negative {
+            words.push_str("minus ");
+        }
+        let push_int = |n: u128, words: &mut String| match lang {
+            Language::English => push_en(n, words),
+            Language::German => push_de(n, "eins", words),
+        };
+        match style {
+            WordsStyle::Cheque => {
+                push_int(int, &mut words);
+                words.push_str(and);
+                // cents < 100, so writing to a String can't fail
+                let _ = write!(words, "{cents:02}/100");
+            }
+            WordsStyle::Spelled => {
+                if int > 0 || cents == 0 {
+                    push_int(int, &mut words);
+                }
+                if cents > 0 {
+                    if int > 0 {
+                        words.push_str(and);
+                    }
+                    match lang {
+                        Language::English => {
+                            push_en(cents, &mut words);
+                            words.push_str(if cents == 1 {
+                                " cent"
+                            } else {
+                                " cents"
+                            });
+                        }
+                        Language::German => {
+                            push_de(cents, "ein", &mut words);
+                            words.push_str(" Cent");
+                        }
+                    }
+                }
+            }
+        }
+        capitalize(&words)
+    }
+
+    /// Returns the absolute integral part and the absolute number of cents
+    /// of `self` rounded to two fractional digits, together with a flag
+    /// indicating whether the rounded value is negative.
+    fn int_and_cents(self) -> (u128, u128, bool) {
+        if self.n_frac_digits > 2 {
+            // dividing by at least 10 can't overflow
+            let cents = i128_div_rounded(
+                self.coeff,
+                ten_pow(self.n_frac_digits - 2),
+                None,
+            );
+            let abs_cents = cents.unsigned_abs();
+            #[allow(clippy::integer_division)]
+            (abs_cents / 100, abs_cents % 100, cents < 0)
+        } else {
+            let abs_coeff = self.coeff.unsigned_abs();
+            let unit = ten_pow(self.n_frac_digits).unsigned_abs();
+            #[allow(clippy::integer_division)]
+            let int = abs_coeff / unit;
+            let cents = (abs_coeff % unit)
+                * ten_pow(2 - self.n_frac_digits).unsigned_abs();
+            (int, cents, self.coeff < 0)
+        }
+    }
+}
+
+#[cfg(test)]
+mod to_words_tests {
+    use super::*;
+    use crate::Dec;
+
+    #[test]
+    fn test_en_cheque() {
+        for (d, words) in [
+            (Dec!(0), "Zero and 00/100"),
+            (Dec!(0.07), "Zero and 07/100"),
+            (Dec!(1), "One and 00/100"),
+            (Dec!(15.5), "Fifteen and 50/100"),
+            (Dec!(40), "Forty and 00/100"),
+            (Dec!(99.99), "Ninety-nine and 99/100"),
+            (Dec!(100), "One hundred and 00/100"),
+            (Dec!(101), "One hundred one and 00/100"),
+            (
+                Dec!(1234.56),
+                "One thousand two hundred thirty-four and 56/100",
+            ),
+            (Dec!(-1000000.1), "Minus one million and 10/100"),
+            (Dec!(2000017), "Two million seventeen and 00/100"),
+            (
+                Dec!(1001001001),
+                "One billion one million one thousand one and 00/100",
+            ),
+        ] {
+            assert_eq!(
+                d.to_words(Language::English, WordsStyle::Cheque),
+                words
+            );
+        }
+    }
+
+    #[test]
+    fn test_en_spelled() {
+        for (d, words) in [
+            (Dec!(0), "Zero"),
+            (Dec!(0.01), "One cent"),
+            (Dec!(-0.56), "Minus fifty-six cents"),
+            (Dec!(1), "One"),
+            (Dec!(21.21), "Twenty-one and twenty-one cents"),
+            (Dec!(300.01), "Three hundred and one cent"),
+            (
+                Dec!(1234.56),
+                "One thousand two hundred thirty-four and fifty-six cents",
+            ),
+            (Dec!(12000000.00), "Twelve million"),
+        ] {
+            assert_eq!(
+                d.to_words(Language::English, WordsStyle::Spelled),
+                words
+            );
+        }
+    }
+
+    #[test]
+    fn test_de_cheque() {
+        for (d, words) in [
+            (Dec!(0), "Null und 00/100"),
+            (Dec!(1), "Eins und 00/100"),
+            (Dec!(16), "Sechzehn und 00/100"),
+            (Dec!(17), "Siebzehn und 00/100"),
+            (Dec!(21), "Einundzwanzig und 00/100"),
+            (Dec!(30), "Dreißig und 00/100"),
+            (Dec!(101), "Einhunderteins und 00/100"),
+            (Dec!(1000), "Eintausend und 00/100"),
+            (Dec!(1001), "Eintausendeins und 00/100"),
+            (Dec!(101000), "Einhunderteintausend und 00/100"),
+            (
+                Dec!(1234.56),
+                "Eintausendzweihundertvierunddreißig und 56/100",
+            ),
+            (Dec!(1000000), "Eine Million und 00/100"),
+            (
+                Dec!(-1234567.89),
+                "Minus eine Million \
+                 zweihundertvierunddreißigtausendfünfhundertsiebenundsechzig \
+                 und 89/100",
+            ),
+            (Dec!(2000000000), "Zwei Milliarden und 00/100"),
+            (
+                Dec!(101001000001),
+                "Einhunderteine Milliarden eine Million eins und 00/100",
+            ),
+        ] {
+            assert_eq!(
+                d.to_words(Language::German, WordsStyle::Cheque),
+                words
+            );
+        }
+    }
+
+    #[test]
+    fn test_de_spelled() {
+        for (d, words) in [
+            (Dec!(0), "Null"),
+            (Dec!(0.01), "Ein Cent"),
+            (Dec!(0.31), "Einunddreißig Cent"),
+            (Dec!(1.5), "Eins und fünfzig Cent"),
+            (Dec!(-2000001.01), "Minus zwei Millionen eins und ein Cent"),
+        ] {
+            assert_eq!(
+                d.to_words(Language::German, WordsStyle::Spelled),
+                words
+            );
+        }
+    }
+
+    #[test]
+    fn test_rounding() {
+        for (d, words) in [
+            (Dec!(1.005), "One and 00/100"),
+            (Dec!(1.015), "One and 02/100"),
+            (Dec!(0.999), "One and 00/100"),
+            (Dec!(-0.004), "Zero and 00/100"),
+            (Dec!(-0.005), "Zero and 00/100"),
+            (Dec!(-0.0051), "Minus zero and 01/100"),
+        ] {
+            assert_eq!(
+                d.to_words(Language::English, WordsStyle::Cheque),
+                words
+            );
+        }
+    }
+
+    #[test]
+    fn test_limits() {
+        assert_eq!(
+            Decimal::MAX.to_words(Language::English, WordsStyle::Cheque),
+            "One hundred seventy undecillion one hundred forty-one decillion \
+             one hundred eighty-three nonillion four hundred sixty octillion \
+             four hundred sixty-nine septillion two hundred thirty-one \
+             sextillion seven hundred thirty-one quintillion six hundred \
+             eighty-seven quadrillion three hundred three trillion seven \
+             hundred fifteen billion eight hundred eighty-four million one \
+             hundred five thousand seven hundred twenty-seven and 00/100"
+        );
+        assert_eq!(
+            Decimal::MIN.to_words(Language::German, WordsStyle::Spelled),
+            "Minus einhundertsiebzig Sextillionen einhunderteinundvierzig \
+             Quintilliarden einhundertdreiundachtzig Quintillionen \
+             vierhundertsechzig Quadrilliarden vierhundertneunundsechzig \
+             Quadrillionen zweihunderteinunddreißig Trilliarden \
+             siebenhunderteinunddreißig Trillionen sechshundertsiebenundachtzig \
+             Billiarden dreihundertdrei Billionen siebenhundertfünfzehn \
+             Milliarden achthundertvierundachtzig Millionen \
+             einhundertfünftausendsiebenhundertsiebenundzwanzig"
+        );
+        let d = Decimal::new_raw(i128::MAX, 18);
+        assert_eq!(
+            d.to_words(Language::English, WordsStyle::Spelled),
+            "One hundred seventy quintillion one hundred forty-one \
+             quadrillion one hundred eighty-three trillion four hundred sixty \
+             billion four hundred sixty-nine million two hundred thirty-one \
+             thousand seven hundred thirty-one and sixty-nine cents"
+        );
+    }
+}